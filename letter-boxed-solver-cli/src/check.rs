@@ -0,0 +1,132 @@
+//! The `check` command, verifying a proposed solution.
+
+use std::io::{self, Write};
+
+use letter_boxed_solver::{BoardRules, LetterBoxed, Violation, WordList};
+
+/// Report to `out` whether `words` are all in `word_list` and solve `board`,
+/// which rules they break if not, and which letters they leave unused, and
+/// how they compare to the board's par, if it has one, and to the best
+/// solution. Under the [NYT rules](BoardRules::nyt), the par is reported as
+/// the NYT app would. Returns whether they're a valid solution.
+pub fn run(
+    out: &mut impl Write,
+    board: &LetterBoxed,
+    word_list: WordList,
+    words: &[&str],
+) -> io::Result<bool> {
+    let mut valid = true;
+    for word in words {
+        if word_list.indices(&[word]).is_some() {
+            writeln!(out, "{word}: in the {} word list", word_list.name())?;
+        } else {
+            writeln!(out, "{word}: not in the {} word list", word_list.name())?;
+            valid = false;
+        }
+    }
+
+    let mut remaining = None;
+    for violation in board.validate_detailed(words) {
+        valid = false;
+        match violation {
+            Violation::Uncovered { letters } => remaining = Some(letters),
+            v => writeln!(out, "{v}")?,
+        }
+    }
+    match remaining {
+        Some(letters) => writeln!(
+            out,
+            "Letters left: {}",
+            letters
+                .iter()
+                .map(|c| c.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        )?,
+        None => writeln!(out, "Letters left: none")?,
+    }
+
+    writeln!(
+        out,
+        "{}",
+        if valid {
+            "Valid solution"
+        } else {
+            "Not a valid solution"
+        }
+    )?;
+    if let (true, Some(par)) = (valid, board.metadata().par) {
+        if let Ok(report) = board.rate_solution(word_list.words(), words, par) {
+            if *board.rules() == BoardRules::nyt() {
+                writeln!(out, "{}", report.nyt_message())?;
+            } else {
+                writeln!(out, "{report}")?;
+            }
+        }
+    }
+    if valid {
+        if let Ok(comparison) = board.compare_solution(word_list.words(), words, 3) {
+            writeln!(out, "{comparison}")?;
+            for alternative in &comparison.alternatives {
+                writeln!(
+                    out,
+                    "Also starting with {}: {}",
+                    words[0],
+                    alternative.join(" - ")
+                )?;
+            }
+        }
+    }
+    Ok(valid)
+}
+
+#[cfg(test)]
+mod tests {
+    use letter_boxed_solver::{BoardMetadata, LetterBoxed, WordList};
+
+    use super::run;
+
+    /// Whether `words` solve the example board, and the report.
+    fn check(board: &LetterBoxed, words: &[&str]) -> (bool, String) {
+        let mut out = vec![];
+        let valid = run(&mut out, board, WordList::default(), words).unwrap();
+        (valid, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn test_valid() {
+        let board = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let (valid, report) = check(&board, &["VEHICULAR", "RITZILY"]);
+        assert!(valid);
+        assert!(report.contains("Letters left: none\nValid solution\n"));
+        assert!(report.contains("Also starting with VEHICULAR: VEHICULAR - RITZY"));
+    }
+
+    #[test]
+    fn test_incomplete() {
+        let board = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let (valid, report) = check(&board, &["VEHICULAR"]);
+        assert!(!valid);
+        assert!(report.ends_with("Letters left: T Y Z\nNot a valid solution\n"));
+    }
+
+    #[test]
+    fn test_not_in_word_list() {
+        let board = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let (valid, report) = check(&board, &["VEHICULAR", "RITZILYX"]);
+        assert!(!valid);
+        assert!(report.contains("RITZILYX: not in the standard word list"));
+    }
+
+    #[test]
+    fn test_par() {
+        let board =
+            LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]).with_metadata(BoardMetadata {
+                par: Some(3),
+                ..BoardMetadata::default()
+            });
+        let (valid, report) = check(&board, &["VEHICULAR", "RITZILY"]);
+        assert!(valid);
+        assert!(report.contains("Valid solution\n2 words, under par of 3\n"));
+    }
+}
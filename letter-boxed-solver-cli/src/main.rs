@@ -0,0 +1,391 @@
+//! The `letter-boxed` command-line tool.
+
+use std::io::{self, Write};
+use std::process::ExitCode;
+
+use letter_boxed_solver::{BoardMetadata, BoardRules, LetterBoxed, WordList};
+
+use solve::Format;
+
+mod check;
+mod play;
+mod solve;
+
+const USAGE: &str = "\
+usage: letter-boxed <command> [options]
+
+commands:
+    check --board <board> <word>...
+        check whether the words solve the board, and which letters remain
+    graph --board <board> [--results <n>]
+        print the graph of words from each start letter to each end letter
+        in Graphviz DOT, listing the best <n> words on each edge
+    play --board <board> [--depth <n>]
+        play the board interactively, one word at a time
+    solve --board <board> [--depth <n>] [--results <n>] [--format <format>]
+          [--explain] [--svg <file>] [<word>...]
+        print solutions of the board, after playing any words given
+
+options:
+    --board <board>     the sides of the board, e.g. ELZ-IVA-RYU-CTH, or a
+                        board code, e.g. ElzIvaRyuCth
+    --par <n>           the number of words the puzzle should be solved in
+    --rules <rules>     the rules to play by: standard, or nyt to also forbid
+                        proper nouns and rate solutions as the NYT app does
+                        (default standard)
+    --date <date>       the date the puzzle was published for
+    --source <source>   where the puzzle was published
+    --depth <n>         the most words to aim for when hinting and solving
+                        (default 3)
+    --word-list <name>  the word list to check and solve with (default
+                        standard)
+    --results <n>       the most solutions (or words per edge of the graph) to
+                        print, or 0 for all (default 10)
+    --format <format>   how to print solutions: plain, json or csv (default
+                        plain)
+    --explain           show which side each letter of a solution is on
+    --svg <file>        write an SVG image of the first solution to the file";
+
+/// The options shared by every command.
+struct Options {
+    board: LetterBoxed,
+    depth: usize,
+    word_list: WordList,
+    results: usize,
+    format: Format,
+    /// Whether to explain how each solution is drawn.
+    explain: bool,
+    /// Where to write an image of the first solution, if anywhere.
+    svg: Option<String>,
+    /// The arguments which aren't options.
+    words: Vec<String>,
+}
+
+impl Options {
+    /// Parse the arguments following the command.
+    fn parse(args: &[String]) -> Result<Options, String> {
+        let mut board = None;
+        let mut metadata = BoardMetadata::default();
+        let mut rules = BoardRules::default();
+        let mut depth = 3;
+        let mut word_list = WordList::default();
+        let mut results = 10;
+        let mut format = Format::default();
+        let mut explain = false;
+        let mut svg = None;
+        let mut words = vec![];
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("{arg} needs a value"));
+            match arg.as_str() {
+                "--board" => board = Some(parse_board(value()?)?),
+                "--par" => {
+                    metadata.par = Some(
+                        value()?
+                            .parse()
+                            .map_err(|_| "--par must be a number".to_string())?,
+                    )
+                }
+                "--rules" => {
+                    rules = match value()?.as_str() {
+                        "standard" => BoardRules::new(),
+                        "nyt" => BoardRules::nyt(),
+                        other => {
+                            return Err(format!("unknown rules {other}, expected standard or nyt"))
+                        }
+                    }
+                }
+                "--date" => metadata.date = Some(value()?.clone()),
+                "--source" => metadata.source = Some(value()?.clone()),
+                "--depth" => {
+                    depth = value()?
+                        .parse()
+                        .map_err(|_| "--depth must be a number".to_string())?
+                }
+                "--word-list" => {
+                    let name = value()?;
+                    word_list = WordList::from_name(name).ok_or_else(|| {
+                        let names = WordList::ALL.iter().map(|l| l.name()).collect::<Vec<_>>();
+                        format!("unknown word list {name}, expected {}", names.join(" or "))
+                    })?
+                }
+                "--results" => {
+                    results = value()?
+                        .parse()
+                        .map_err(|_| "--results must be a number".to_string())?
+                }
+                "--format" => format = value()?.parse()?,
+                "--explain" => explain = true,
+                "--svg" => svg = Some(value()?.clone()),
+                _ if arg.starts_with("--") => return Err(format!("unexpected argument {arg}")),
+                _ => words.push(arg.to_uppercase()),
+            }
+        }
+
+        Ok(Options {
+            board: board
+                .ok_or("--board is required")?
+                .with_metadata(metadata)
+                .with_rules(rules),
+            depth,
+            word_list,
+            results,
+            format,
+            explain,
+            svg,
+            words,
+        })
+    }
+
+    /// The words given as arguments, which must all be in the word list.
+    fn prior_words(&self) -> Result<Vec<&str>, String> {
+        let words = self.words.iter().map(|w| w.as_str()).collect::<Vec<_>>();
+        match words
+            .iter()
+            .find(|w| self.word_list.indices(&[w]).is_none())
+        {
+            Some(word) => Err(format!("{word} isn't in the word list")),
+            None => Ok(words),
+        }
+    }
+}
+
+/// Describe where `board` came from, if anything is known.
+fn describe_metadata(board: &LetterBoxed) -> Option<String> {
+    let metadata = board.metadata();
+    let mut parts = vec![];
+    if let Some(date) = &metadata.date {
+        parts.push(date.clone());
+    }
+    if let Some(par) = metadata.par {
+        parts.push(format!("par {par}"));
+    }
+    if let Some(source) = &metadata.source {
+        parts.push(source.clone());
+    }
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+/// Read a board given as its sides, or as a code from
+/// [`LetterBoxed::to_code`].
+fn parse_board(board: &str) -> Result<LetterBoxed, String> {
+    board
+        .parse::<LetterBoxed>()
+        .or_else(|e| LetterBoxed::from_code(board).map_err(|_| e))
+        .map_err(|e| format!("invalid board: {e}"))
+}
+
+/// Run the command in `args`, writing its output to `out`. Returns whether
+/// the command succeeded, or an error to print along with the usage.
+fn run(args: &[String], out: &mut impl Write) -> Result<bool, String> {
+    match args.split_first() {
+        Some((command, rest)) if command == "check" => {
+            let o = Options::parse(rest)?;
+            if o.words.is_empty() {
+                return Err("check needs some words to check".to_string());
+            }
+            let words = o.words.iter().map(|w| w.as_str()).collect::<Vec<_>>();
+            check::run(out, &o.board, o.word_list, &words).map_err(write_error)
+        }
+        Some((command, rest)) if command == "graph" => {
+            let o = Options::parse(rest)?;
+            if let Some(word) = o.words.first() {
+                return Err(format!("unexpected argument {word}"));
+            }
+            write!(
+                out,
+                "{}",
+                o.board.word_graph_dot(o.word_list.words(), o.results)
+            )
+            .map_err(write_error)?;
+            Ok(true)
+        }
+        Some((command, rest)) if command == "play" => {
+            let o = Options::parse(rest)?;
+            if let Some(word) = o.words.first() {
+                return Err(format!("unexpected argument {word}"));
+            }
+            play::run(&o.board, o.word_list, o.depth);
+            Ok(true)
+        }
+        Some((command, rest)) if command == "solve" => {
+            let o = Options::parse(rest)?;
+            let prior_words = o.prior_words()?;
+            let first = solve::run(
+                out,
+                &o.board,
+                o.word_list,
+                &prior_words,
+                o.depth,
+                o.results,
+                o.format,
+                o.explain,
+            )
+            .map_err(write_error)?;
+            if let Some(path) = &o.svg {
+                // Prior words which aren't playable can't be drawn.
+                let svg = o
+                    .board
+                    .to_svg(&first)
+                    .ok_or_else(|| format!("can't draw {} on the board", first.join(" - ")))?;
+                std::fs::write(path, svg).map_err(|e| format!("couldn't write {path}: {e}"))?;
+            }
+            Ok(true)
+        }
+        Some((command, _)) if command != "help" && command != "--help" => {
+            Err(format!("unknown command {command}"))
+        }
+        _ => {
+            writeln!(out, "{USAGE}").map_err(write_error)?;
+            Ok(true)
+        }
+    }
+}
+
+/// Describe a failure to write a command's output.
+fn write_error(e: io::Error) -> String {
+    format!("couldn't write output: {e}")
+}
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    match run(&args, &mut io::stdout()) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("{e}\n\n{USAGE}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{run, Options};
+    use crate::solve::Format;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let o = Options::parse(&args(&[
+            "--board",
+            "ELZ-IVA-RYU-CTH",
+            "--depth",
+            "2",
+            "--results",
+            "0",
+            "--format",
+            "json",
+            "--explain",
+            "--par",
+            "4",
+            "vehicular",
+        ]))
+        .unwrap();
+        assert_eq!(o.board.num_letters(), 12);
+        assert_eq!(o.board.metadata().par, Some(4));
+        assert_eq!(o.depth, 2);
+        assert_eq!(o.results, 0);
+        assert_eq!(o.format, Format::Json);
+        assert!(o.explain);
+        assert_eq!(o.svg, None);
+        assert_eq!(o.words, ["VEHICULAR"]);
+
+        let code = Options::parse(&args(&["--board", "ElzIvaRyuCth"])).unwrap();
+        assert_eq!(code.board.to_code(), o.board.to_code());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |a: &[&str]| Options::parse(&args(a)).err().unwrap();
+        assert_eq!(error(&[]), "--board is required");
+        assert_eq!(error(&["--board"]), "--board needs a value");
+        assert!(error(&["--board", "ELZ--RYU"]).starts_with("invalid board"));
+        assert_eq!(
+            error(&["--board", "ELZ-IVA-RYU-CTH", "--depth", "three"]),
+            "--depth must be a number"
+        );
+        assert_eq!(
+            error(&["--board", "ELZ-IVA-RYU-CTH", "--rules", "scrabble"]),
+            "unknown rules scrabble, expected standard or nyt"
+        );
+        assert!(
+            error(&["--board", "ELZ-IVA-RYU-CTH", "--word-list", "klingon"])
+                .starts_with("unknown word list klingon")
+        );
+        assert_eq!(
+            error(&["--board", "ELZ-IVA-RYU-CTH", "--verbose"]),
+            "unexpected argument --verbose"
+        );
+    }
+
+    #[test]
+    fn test_prior_words() {
+        let o = Options::parse(&args(&["--board", "ELZ-IVA-RYU-CTH", "VEHICULAR"])).unwrap();
+        assert_eq!(o.prior_words().unwrap(), ["VEHICULAR"]);
+        let o = Options::parse(&args(&["--board", "ELZ-IVA-RYU-CTH", "RITZILYX"])).unwrap();
+        assert_eq!(
+            o.prior_words().unwrap_err(),
+            "RITZILYX isn't in the word list"
+        );
+    }
+
+    #[test]
+    fn test_run() {
+        let mut out = vec![];
+        assert_eq!(run(&args(&["help"]), &mut out), Ok(true));
+        assert!(String::from_utf8(out).unwrap().starts_with("usage:"));
+
+        assert_eq!(
+            run(&args(&["unsolve"]), &mut vec![]),
+            Err("unknown command unsolve".to_string())
+        );
+        assert_eq!(
+            run(&args(&["check", "--board", "ELZ-IVA-RYU-CTH"]), &mut vec![]),
+            Err("check needs some words to check".to_string())
+        );
+        assert_eq!(
+            run(
+                &args(&["check", "--board", "ELZ-IVA-RYU-CTH", "VEHICULAR"]),
+                &mut vec![]
+            ),
+            Ok(false)
+        );
+        assert_eq!(
+            run(
+                &args(&["graph", "--board", "ELZ-IVA-RYU-CTH", "VEHICULAR"]),
+                &mut vec![]
+            ),
+            Err("unexpected argument VEHICULAR".to_string())
+        );
+    }
+
+    #[test]
+    fn test_svg() {
+        let path = std::env::temp_dir().join(format!("letter-boxed-{}.svg", std::process::id()));
+        let path_arg = path.to_str().unwrap();
+
+        let mut out = vec![];
+        let solve = ["solve", "--board", "ELZ-IVA-RYU-CTH", "--svg", path_arg];
+        assert_eq!(run(&args(&solve), &mut out), Ok(true));
+        assert!(std::fs::read_to_string(&path).unwrap().starts_with("<svg"));
+        std::fs::remove_file(&path).unwrap();
+
+        // QUIZ is a word, but can't be played on this board.
+        let solve = [
+            "solve",
+            "--board",
+            "ELZ-IVA-RYU-CTH",
+            "--svg",
+            path_arg,
+            "QUIZ",
+        ];
+        let error = run(&args(&solve), &mut out).unwrap_err();
+        assert!(error.starts_with("can't draw QUIZ - "));
+        assert!(!path.exists());
+    }
+}
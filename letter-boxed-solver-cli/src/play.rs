@@ -0,0 +1,293 @@
+//! The `play` command: a terminal version of the game.
+
+use std::io::{self, BufRead, Write};
+
+use letter_boxed_solver::{LetterBoxed, Violation, WordList};
+
+const HELP: &str = "\
+Enter a word to play it, or one of:
+    :hint     suggest the next word
+    :undo     take back the last word
+    :solve    show solutions from here
+    :board    show the board again
+    :quit     give up";
+
+/// The number of solutions shown by the `:solve` command.
+const SOLUTIONS_SHOWN: usize = 5;
+
+/// Play `board` interactively on stdin and stdout with the words in
+/// `word_list`, hinting and solving towards solutions of at most `depth`
+/// words.
+pub fn run(board: &LetterBoxed, word_list: WordList, depth: usize) {
+    let mut game = Game::new(board, word_list, depth);
+    if let Some(metadata) = crate::describe_metadata(board) {
+        println!("{metadata}\n");
+    }
+    println!("{board}\n\n{HELP}");
+
+    let stdin = io::stdin();
+    loop {
+        print!("\n{}> ", game.played.join(" - "));
+        // If stdout is gone, there's no one to play with.
+        if io::stdout().flush().is_err() {
+            return;
+        }
+        let mut line = String::new();
+        match stdin.lock().read_line(&mut line) {
+            Ok(0) | Err(_) => return,
+            Ok(_) => {}
+        }
+        match game.handle(&line, &mut io::stdout()) {
+            Ok(true) => {}
+            Ok(false) | Err(_) => return,
+        }
+    }
+}
+
+/// A game in progress: the board and the words played on it so far.
+struct Game<'a> {
+    board: &'a LetterBoxed,
+    word_list: WordList,
+    depth: usize,
+    played: Vec<&'static str>,
+}
+
+impl<'a> Game<'a> {
+    fn new(board: &'a LetterBoxed, word_list: WordList, depth: usize) -> Self {
+        Game {
+            board,
+            word_list,
+            depth,
+            played: vec![],
+        }
+    }
+
+    /// Handle a line of input, either a command starting with `:` or a word
+    /// to play, writing the response to `out`. Returns whether the game goes
+    /// on, i.e. the player neither quit nor solved the board.
+    ///
+    /// Commands are prefixed so that words like HINT can still be played.
+    fn handle(&mut self, line: &str, out: &mut impl Write) -> io::Result<bool> {
+        let line = line.trim().to_lowercase();
+        let Some(command) = line.strip_prefix(':') else {
+            if line.is_empty() {
+                return Ok(true);
+            }
+            if self.play(&line, out)? {
+                writeln!(out, "Solved in {} words!", self.played.len())?;
+                return Ok(false);
+            }
+            return Ok(true);
+        };
+
+        match command {
+            "quit" | "exit" => return Ok(false),
+            "help" => writeln!(out, "{HELP}")?,
+            "board" => writeln!(out, "{}", self.board)?,
+            "undo" => match self.played.pop() {
+                Some(word) => writeln!(out, "Took back {word}.")?,
+                None => writeln!(out, "There's nothing to take back.")?,
+            },
+            "hint" => match self
+                .board
+                .hint(self.word_list.words(), &self.indices(), self.depth)
+                .first()
+            {
+                Some(hint) => writeln!(
+                    out,
+                    "Try {}, which uses {} new letters.",
+                    hint.word, hint.new_letters
+                )?,
+                None => writeln!(out, "There's no word to play next.")?,
+            },
+            "solve" => {
+                let solutions = self.board.solve(
+                    self.word_list.words(),
+                    &self.indices(),
+                    self.depth,
+                    SOLUTIONS_SHOWN,
+                );
+                match solutions.first() {
+                    Some((_, score)) if *score == self.board.num_letters() => {
+                        for (words, _) in solutions {
+                            writeln!(out, "{}", words.join(" - "))?;
+                        }
+                    }
+                    _ => writeln!(
+                        out,
+                        "There's no solution in {} words from here.",
+                        self.depth
+                    )?,
+                }
+            }
+            command => writeln!(out, "Unknown command :{command}. Enter :help for help.")?,
+        }
+        Ok(true)
+    }
+
+    /// Try to play `word`, explaining why not if it can't be played. Returns
+    /// whether the board is now solved.
+    fn play(&mut self, word: &str, out: &mut impl Write) -> io::Result<bool> {
+        let Some(idx) = self.word_list.indices(&[word]).map(|i| i[0]) else {
+            writeln!(out, "{} isn't in the word list.", word.to_uppercase())?;
+            return Ok(false);
+        };
+        let word = self.word_list.words()[idx];
+
+        let mut chain = self.played.clone();
+        chain.push(word);
+        let mut remaining = None;
+        let mut problems = vec![];
+        for v in self.board.validate_detailed(&chain) {
+            match v {
+                Violation::Uncovered { letters } => remaining = Some(letters),
+                v => problems.push(v),
+            }
+        }
+        if !problems.is_empty() {
+            for problem in problems {
+                writeln!(out, "{problem}")?;
+            }
+            return Ok(false);
+        }
+
+        self.played = chain;
+        match remaining {
+            Some(letters) => {
+                writeln!(
+                    out,
+                    "Letters left: {}",
+                    letters
+                        .iter()
+                        .map(|c| c.to_string())
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                )?;
+                Ok(false)
+            }
+            None => Ok(true),
+        }
+    }
+
+    /// The indices of the words played so far in the word list, which they
+    /// were all taken from.
+    fn indices(&self) -> Vec<usize> {
+        self.word_list
+            .indices(&self.played)
+            .expect("played words are in the word list")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use letter_boxed_solver::{LetterBoxed, WordList};
+
+    use super::Game;
+
+    /// Send `line` to `game`, returning whether the game goes on and the
+    /// response.
+    fn handle(game: &mut Game<'_>, line: &str) -> (bool, String) {
+        let mut out = vec![];
+        let going = game.handle(line, &mut out).unwrap();
+        (going, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn test_play_to_the_end() {
+        let board = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let mut game = Game::new(&board, WordList::default(), 3);
+        assert_eq!(
+            handle(&mut game, "vehicular\n"),
+            (true, "Letters left: T Y Z\n".to_string())
+        );
+        assert_eq!(
+            handle(&mut game, "ritzily\n"),
+            (false, "Solved in 2 words!\n".to_string())
+        );
+        assert_eq!(game.played, ["VEHICULAR", "RITZILY"]);
+    }
+
+    #[test]
+    fn test_rejected_words() {
+        let board = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let mut game = Game::new(&board, WordList::default(), 3);
+        assert_eq!(
+            handle(&mut game, "vehicularx"),
+            (true, "VEHICULARX isn't in the word list.\n".to_string())
+        );
+        let (going, response) = handle(&mut game, "zither");
+        assert!(going);
+        assert!(!response.is_empty());
+        assert!(game.played.is_empty());
+        assert_eq!(handle(&mut game, "  "), (true, String::new()));
+    }
+
+    #[test]
+    fn test_words_named_like_commands() {
+        // HINT, UNDO and QUIT can all be played on this board.
+        let board = LetterBoxed::load_board(&["HNQ", "IDA", "TUB", "OLE"]);
+        let mut game = Game::new(&board, WordList::default(), 3);
+        for word in ["hint", "quit", "undo"] {
+            let (going, _) = handle(&mut game, word);
+            assert!(going);
+            assert_eq!(game.played.last(), Some(&word.to_uppercase().as_str()));
+            handle(&mut game, ":undo");
+        }
+        assert_eq!(handle(&mut game, ":quit"), (false, String::new()));
+    }
+
+    #[test]
+    fn test_undo() {
+        let board = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let mut game = Game::new(&board, WordList::default(), 3);
+        handle(&mut game, "vehicular");
+        handle(&mut game, "ritz");
+        assert_eq!(game.played, ["VEHICULAR", "RITZ"]);
+        assert_eq!(
+            handle(&mut game, ":undo"),
+            (true, "Took back RITZ.\n".to_string())
+        );
+        assert_eq!(
+            handle(&mut game, ":undo"),
+            (true, "Took back VEHICULAR.\n".to_string())
+        );
+        assert!(game.played.is_empty());
+        assert_eq!(
+            handle(&mut game, ":UNDO"),
+            (true, "There's nothing to take back.\n".to_string())
+        );
+        assert!(game.played.is_empty());
+    }
+
+    #[test]
+    fn test_hint_and_solve() {
+        let board = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let mut game = Game::new(&board, WordList::default(), 2);
+        handle(&mut game, "vehicular");
+        let (going, hint) = handle(&mut game, ":hint");
+        assert!(going);
+        assert!(hint.starts_with("Try R"), "{hint}");
+        let (_, solutions) = handle(&mut game, ":solve");
+        assert!(solutions.contains("VEHICULAR - RITZILY\n"), "{solutions}");
+
+        // Nothing can follow a finished board.
+        game.played.push("RITZILY");
+        assert_eq!(
+            handle(&mut game, ":hint"),
+            (true, "There's no word to play next.\n".to_string())
+        );
+    }
+
+    #[test]
+    fn test_unknown_command() {
+        let board = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let mut game = Game::new(&board, WordList::default(), 3);
+        assert_eq!(
+            handle(&mut game, ":fly"),
+            (
+                true,
+                "Unknown command :fly. Enter :help for help.\n".to_string()
+            )
+        );
+    }
+}
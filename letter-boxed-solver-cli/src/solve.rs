@@ -0,0 +1,224 @@
+//! The `solve` command, printing solutions in a choice of formats.
+
+use std::io::{self, Write};
+use std::str::FromStr;
+
+use letter_boxed_solver::{LetterBoxed, WordList};
+use serde::Serialize;
+
+/// How to print solutions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// One solution per line, for people.
+    #[default]
+    Plain,
+    /// A JSON array of solution objects.
+    Json,
+    /// A header row, then one row per solution.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "plain" => Ok(Format::Plain),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("unknown format {s}, expected plain, json or csv")),
+        }
+    }
+}
+
+/// A solution, as printed.
+#[derive(Serialize)]
+struct SolutionRow<'word> {
+    /// The words of the solution, in the order they are played.
+    words: Vec<&'word str>,
+    /// The number of distinct board letters the words cover.
+    letters_covered: usize,
+    /// The number of letters on the board.
+    total_letters: usize,
+    /// Whether every letter on the board is covered.
+    complete: bool,
+    /// Which side each letter is on, if asked for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    explanation: Option<String>,
+}
+
+/// Write up to `max_results` (or, if it is 0, all) solutions of `board` of at
+/// most `depth` words from `word_list` after `prior_words`, which must be in
+/// the list, in `format`, explaining which side each letter is on if
+/// `explain` is set, to `out`. Returns the words of the first solution, or
+/// of the best partial solution if there are none.
+#[allow(clippy::too_many_arguments)]
+pub fn run(
+    out: &mut impl Write,
+    board: &LetterBoxed,
+    word_list: WordList,
+    prior_words: &[&str],
+    depth: usize,
+    max_results: usize,
+    format: Format,
+    explain: bool,
+) -> io::Result<Vec<&'static str>> {
+    let total_letters = board.num_letters();
+    let prior_words_indices = word_list
+        .indices(prior_words)
+        .expect("prior words are in the word list");
+    let rows = board
+        .solve(word_list.words(), &prior_words_indices, depth, max_results)
+        .into_iter()
+        .map(|(words, letters_covered)| SolutionRow {
+            explanation: explain
+                .then(|| board.explain(&words))
+                .flatten()
+                .map(|e| e.to_string()),
+            words,
+            letters_covered,
+            total_letters,
+            complete: letters_covered == total_letters,
+        })
+        .collect::<Vec<_>>();
+
+    if rows.iter().all(|row| !row.complete) {
+        let dead_letters = board.dead_letters(word_list.words());
+        if !dead_letters.is_empty() {
+            eprintln!(
+                "No word in the {} word list can play {}",
+                word_list.name(),
+                dead_letters.iter().collect::<String>()
+            );
+        }
+    }
+
+    let first = rows
+        .first()
+        .map(|row| row.words.clone())
+        .unwrap_or_default();
+    match format {
+        Format::Plain => {
+            for row in rows {
+                writeln!(
+                    out,
+                    "{} ({}/{} letters{})",
+                    row.words.join(" - "),
+                    row.letters_covered,
+                    row.total_letters,
+                    if row.complete { "" } else { ", incomplete" }
+                )?;
+                if let Some(explanation) = &row.explanation {
+                    writeln!(out, "    {explanation}")?;
+                }
+            }
+        }
+        Format::Json => writeln!(
+            out,
+            "{}",
+            serde_json::to_string_pretty(&rows).expect("solutions are serializable")
+        )?,
+        Format::Csv => {
+            // Words are only ever letters, so nothing needs quoting.
+            write!(out, "words,letters_covered,total_letters,complete")?;
+            writeln!(out, "{}", if explain { ",explanation" } else { "" })?;
+            for row in rows {
+                write!(
+                    out,
+                    "{},{},{},{}",
+                    row.words.join(" "),
+                    row.letters_covered,
+                    row.total_letters,
+                    row.complete
+                )?;
+                match &row.explanation {
+                    Some(explanation) => writeln!(out, ",{explanation}")?,
+                    None if explain => writeln!(out, ",")?,
+                    None => writeln!(out)?,
+                }
+            }
+        }
+    }
+    Ok(first)
+}
+
+#[cfg(test)]
+mod tests {
+    use letter_boxed_solver::{LetterBoxed, WordList};
+
+    use super::{run, Format};
+
+    /// The output of solving the example board after VEHICULAR in `format`.
+    fn solve(format: Format, max_results: usize, explain: bool) -> String {
+        let board = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let mut out = vec![];
+        let first = run(
+            &mut out,
+            &board,
+            WordList::default(),
+            &["VEHICULAR"],
+            2,
+            max_results,
+            format,
+            explain,
+        )
+        .unwrap();
+        assert_eq!(first, ["VEHICULAR", "RITZILY"]);
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_format_parse() {
+        assert_eq!("plain".parse(), Ok(Format::Plain));
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("csv".parse(), Ok(Format::Csv));
+        assert!("xml"
+            .parse::<Format>()
+            .unwrap_err()
+            .contains("unknown format xml"));
+    }
+
+    #[test]
+    fn test_plain() {
+        assert_eq!(
+            solve(Format::Plain, 2, false),
+            "VEHICULAR - RITZILY (12/12 letters)\nVEHICULAR - RITZY (12/12 letters)\n"
+        );
+        assert!(solve(Format::Plain, 1, true).contains("\n    V(left)→E(top)→"));
+    }
+
+    #[test]
+    fn test_json() {
+        let rows: serde_json::Value = serde_json::from_str(&solve(Format::Json, 2, false)).unwrap();
+        assert_eq!(
+            rows[0],
+            serde_json::json!({
+                "words": ["VEHICULAR", "RITZILY"],
+                "letters_covered": 12,
+                "total_letters": 12,
+                "complete": true,
+            })
+        );
+        assert_eq!(rows.as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            solve(Format::Csv, 2, false),
+            "words,letters_covered,total_letters,complete\n\
+             VEHICULAR RITZILY,12,12,true\n\
+             VEHICULAR RITZY,12,12,true\n"
+        );
+        let explained = solve(Format::Csv, 1, true);
+        let mut lines = explained.lines();
+        assert_eq!(
+            lines.next(),
+            Some("words,letters_covered,total_letters,complete,explanation")
+        );
+        assert!(lines
+            .next()
+            .unwrap()
+            .starts_with("VEHICULAR RITZILY,12,12,true,V(left)"));
+    }
+}
@@ -0,0 +1,144 @@
+//! Python bindings for the Letter Boxed solver.
+//!
+//! ```python
+//! import letter_boxed_solver as lbs
+//!
+//! board = lbs.load_board(["ELZ", "IVA", "RYU", "CTH"])
+//! board.solve(max_depth=2)
+//! ```
+
+use letter_boxed_solver::LetterBoxed;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// A word list to solve with, instead of the builtin one.
+#[pyclass]
+struct Dictionary {
+    words: Vec<String>,
+}
+
+#[pymethods]
+impl Dictionary {
+    #[new]
+    fn new(words: Vec<String>) -> Self {
+        Dictionary { words }
+    }
+
+    /// Load a dictionary from text with one word per line.
+    #[staticmethod]
+    fn from_text(text: &str) -> Self {
+        Dictionary {
+            words: text.split_whitespace().map(|w| w.to_string()).collect(),
+        }
+    }
+
+    fn __len__(&self) -> usize {
+        self.words.len()
+    }
+}
+
+/// A Letter Boxed board.
+#[pyclass]
+struct Board {
+    board: LetterBoxed,
+}
+
+#[pymethods]
+impl Board {
+    /// The sides of the board, in the order they were loaded.
+    #[getter]
+    fn sides(&self) -> Vec<String> {
+        self.board.sides().map(|s| s.to_string()).collect()
+    }
+
+    /// Solve the board, returning up to `max_results` solutions (or all of
+    /// them, if it is 0) of at most `max_depth` words as
+    /// `(words, letters_covered)` pairs, shortest first.
+    ///
+    /// If no complete solution is found, the best partial one is returned.
+    /// Raises `ValueError` if any of `prior_words` is not in the dictionary.
+    #[pyo3(signature = (prior_words = vec![], max_depth = 2, max_results = 25, dictionary = None))]
+    fn solve(
+        &self,
+        prior_words: Vec<String>,
+        max_depth: usize,
+        max_results: usize,
+        dictionary: Option<&Dictionary>,
+    ) -> PyResult<Vec<(Vec<String>, usize)>> {
+        let prior_words = prior_words.iter().map(|w| w.as_str()).collect::<Vec<_>>();
+        let results = match dictionary {
+            Some(dictionary) => {
+                let words = dictionary
+                    .words
+                    .iter()
+                    .map(|w| w.as_str())
+                    .collect::<Vec<_>>();
+                self.board
+                    .solve_with_word_list(&words, &prior_words, max_depth, max_results)
+                    .map(to_owned)
+            }
+            None => {
+                let words = letter_boxed_solver::WordList::default().words();
+                self.board
+                    .solve_with_word_list(words, &prior_words, max_depth, max_results)
+                    .map(to_owned)
+            }
+        };
+        results.ok_or_else(|| PyValueError::new_err("prior words must be in the dictionary"))
+    }
+
+    /// Check a chain of words against the board, returning a description of
+    /// every rule it breaks. An empty list means the solution is valid and
+    /// complete.
+    fn validate(&self, words: Vec<String>) -> Vec<String> {
+        let words = words.iter().map(|w| w.as_str()).collect::<Vec<_>>();
+        self.board
+            .validate_detailed(&words)
+            .iter()
+            .map(|v| v.to_string())
+            .collect()
+    }
+
+    fn __str__(&self) -> String {
+        self.board.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Board({:?})", self.sides())
+    }
+}
+
+/// Convert borrowed solutions into owned ones to hand to Python.
+fn to_owned(results: Vec<(Vec<&str>, usize)>) -> Vec<(Vec<String>, usize)> {
+    results
+        .into_iter()
+        .map(|(words, covered)| (words.into_iter().map(|w| w.to_string()).collect(), covered))
+        .collect()
+}
+
+/// Load a board from its sides, e.g. `["ELZ", "IVA", "RYU", "CTH"]`, or from
+/// a compact string such as `"ELZ-IVA-RYU-CTH"`.
+///
+/// Raises `ValueError` if the board is malformed.
+#[pyfunction]
+fn load_board(sides: &Bound<'_, PyAny>) -> PyResult<Board> {
+    let board = if let Ok(s) = sides.extract::<String>() {
+        s.parse::<LetterBoxed>()
+    } else {
+        let sides = sides.extract::<Vec<String>>()?;
+        let sides = sides.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+        LetterBoxed::try_load_board(&sides)
+    };
+    board
+        .map(|board| Board { board })
+        .map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+#[pymodule]
+#[pyo3(name = "letter_boxed_solver")]
+fn py_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Board>()?;
+    m.add_class::<Dictionary>()?;
+    m.add_function(wrap_pyfunction!(load_board, m)?)?;
+    Ok(())
+}
@@ -1,6 +1,13 @@
 const path = require('path')
 const WasmPackPlugin = require('@wasm-tool/wasm-pack-plugin')
 
+// Whether to build the solver with multithreading, which needs nightly Rust.
+const threads = Boolean(process.env.WASM_THREADS)
+if (threads) {
+  process.env.RUSTUP_TOOLCHAIN = 'nightly'
+  process.env.RUSTFLAGS = '-C target-feature=+atomics,+bulk-memory,+mutable-globals'
+}
+
 module.exports = {
   // The Webpack config to use when compiling your react app for development or production.
   webpack: function (config, env) {
@@ -28,8 +35,27 @@ module.exports = {
     config.plugins.push(new WasmPackPlugin({
       crateDirectory: path.resolve(__dirname, 'letter-boxed-solver-wasm'),
       withTypeScript: true,
+      // Build with threads if requested; see the README.
+      ...(threads && {
+        extraArgs: '-- --features parallel -Z build-std=panic_abort,std',
+      }),
     }));
 
     return config;
   },
+
+  // Workers can only share memory if the page is cross-origin isolated.
+  devServer: function (configFunction) {
+    return function (proxy, allowedHost) {
+      const config = configFunction(proxy, allowedHost);
+      if (threads) {
+        config.headers = {
+          ...config.headers,
+          'Cross-Origin-Opener-Policy': 'same-origin',
+          'Cross-Origin-Embedder-Policy': 'require-corp',
+        };
+      }
+      return config;
+    };
+  },
 };
@@ -1,9 +1,18 @@
-use std::io::Write;
-
-use letter_boxed_solver::LetterBoxed;
+use letter_boxed_solver::{LetterBoxed, SolutionIter, SolveOptions, Statistics, WordList};
+use serde::{Deserialize, Serialize};
 
+mod prior_words;
+mod session;
+mod solver;
 mod utils;
 
+pub use session::Session;
+pub use solver::Solver;
+/// With the `parallel` feature, this must be awaited from JS (as
+/// `initThreadPool(navigator.hardwareConcurrency)`) before solving.
+#[cfg(feature = "parallel")]
+pub use wasm_bindgen_rayon::init_thread_pool;
+
 use wasm_bindgen::prelude::*;
 
 // When the `wee_alloc` feature is enabled, use `wee_alloc` as the global
@@ -17,34 +26,658 @@ extern "C" {
     fn alert(s: &str);
 }
 
+/// A single solution, as returned to JS.
+#[derive(Serialize)]
+struct SolutionResult<'word> {
+    /// The words of the solution, in the order they are played.
+    words: Vec<&'word str>,
+    /// The number of distinct board letters the words cover.
+    score: usize,
+    /// The number of letters on the board.
+    total: usize,
+    /// Whether every letter on the board is covered.
+    complete: bool,
+}
+
+/// Statistics about a solve, as returned to JS.
+#[derive(Serialize)]
+struct StatisticsResult {
+    /// The time spent searching, in milliseconds.
+    elapsed_ms: f64,
+    /// The number of search states expanded.
+    states_expanded: usize,
+    /// The number of words in the word list.
+    dictionary_words: usize,
+    /// The number of words in the word list which can be played on the
+    /// board.
+    playable_words: usize,
+}
+
+impl StatisticsResult {
+    fn new(statistics: &Statistics, elapsed_ms: f64, dictionary_words: usize) -> Self {
+        StatisticsResult {
+            elapsed_ms,
+            states_expanded: statistics.states_expanded,
+            dictionary_words,
+            playable_words: statistics.graph_words,
+        }
+    }
+}
+
+/// The solutions of a board and statistics about finding them, as returned
+/// to JS.
+#[derive(Serialize)]
+struct SolveResult<'word> {
+    solutions: Vec<SolutionResult<'word>>,
+    statistics: StatisticsResult,
+}
+
+/// The number of solutions passed to the callback by [`solve_streaming`] and
+/// statistics about finding them, as returned to JS.
+#[derive(Serialize)]
+struct StreamingResult {
+    found: usize,
+    statistics: StatisticsResult,
+}
+
+/// Solve a board given as a compact string of sides, e.g. `"ELZ-IVA-RYU-CTH"`
+/// or `"ELZ IVA RYU CTH"`. Boards may have any number of sides.
+///
+/// `prior_words` and `excluded_words` are whitespace-separated lists of words
+/// which have already been played, and which must not be used, respectively.
+///
+/// Returns a `{ solutions, statistics }` object, where `solutions` is an
+/// array of `{ words, score, total, complete }` objects and `statistics` is
+/// an `{ elapsed_ms, states_expanded, dictionary_words, playable_words }`
+/// object. Throws an error message if the board is invalid, or a
+/// `{ message, unknown_words }` object if any of `prior_words` is not in the
+/// word list, where each of `unknown_words` is a `{ word, suggestions }`
+/// object.
 #[wasm_bindgen]
 pub fn solve(
-    side_1: &str,
-    side_2: &str,
-    side_3: &str,
-    side_4: &str,
+    sides: &str,
     prior_words: &str,
+    excluded_words: &str,
     depth: usize,
-) -> String {
-    let b = LetterBoxed::load_board(&[side_1, side_2, side_3, side_4]);
+) -> Result<JsValue, JsValue> {
+    utils::set_panic_hook();
+
+    let b = parse_board(sides)?;
 
     let prior_words = prior_words.split_ascii_whitespace().collect::<Vec<_>>();
+    let excluded_words = excluded_words.split_ascii_whitespace().collect::<Vec<_>>();
+    let words = WordList::default().words();
+    prior_words::check(words, &prior_words)?;
+
+    let mut iter = b
+        .solve_iter_with_builtin_list(&prior_words, depth)
+        .with_excluded(&excluded_words);
+    solve_to_js(&b, &mut iter, words.len())
+}
 
-    let mut out = vec![];
-    for (result, score) in b.solve_with_builtin_list(&prior_words, depth, 25) {
-        write!(
-            &mut out,
-            "{}/{}",
-            score,
-            side_1.len() + side_2.len() + side_3.len() + side_4.len()
-        )
-        .unwrap();
-        for word in result {
-            write!(&mut out, " {}", word).unwrap();
+/// Solve a board as in [`solve`], calling `on_result` with each solution as
+/// it is found, as a `{ words, score, total, complete }` object, rather than
+/// returning them all at the end. There is no limit on the number of
+/// solutions: the search only stops early if `on_result` returns `false`. If
+/// no complete solution is found, `on_result` is called once with the best
+/// partial solution.
+///
+/// The callback runs synchronously during the search, so this is most useful
+/// from a web worker, which can post each solution to the page as it
+/// arrives. See `Solver` for solving on the main thread.
+///
+/// Returns a `{ found, statistics }` object, where `found` is the number of
+/// solutions passed to `on_result` and `statistics` is as in [`solve`].
+/// Throws the same errors as [`solve`], or whatever `on_result` throws.
+#[wasm_bindgen]
+pub fn solve_streaming(
+    sides: &str,
+    prior_words: &str,
+    excluded_words: &str,
+    depth: usize,
+    on_result: &js_sys::Function,
+) -> Result<JsValue, JsValue> {
+    utils::set_panic_hook();
+
+    let b = parse_board(sides)?;
+
+    let prior_words = prior_words.split_ascii_whitespace().collect::<Vec<_>>();
+    let excluded_words = excluded_words.split_ascii_whitespace().collect::<Vec<_>>();
+    prior_words::check(WordList::default().words(), &prior_words)?;
+
+    let mut iter = b
+        .solve_iter_with_builtin_list(&prior_words, depth)
+        .with_excluded(&excluded_words);
+    let mut found = 0;
+    // Time only the search, not the callbacks.
+    let mut elapsed_ms = 0.0;
+    loop {
+        let start = js_sys::Date::now();
+        let next = iter.next();
+        elapsed_ms += js_sys::Date::now() - start;
+        let Some(s) = next else {
+            break;
+        };
+        found += 1;
+        let result = solution_result(&b, s.words, s.letters_covered);
+        let keep_going =
+            on_result.call1(&JsValue::NULL, &serde_wasm_bindgen::to_value(&result)?)?;
+        if keep_going == JsValue::FALSE {
+            break;
         }
-        writeln!(&mut out).unwrap();
-        writeln!(&mut out).unwrap();
     }
+    if found == 0 {
+        let best = iter.best_partial();
+        let result = solution_result(&b, best.words, best.letters_covered);
+        on_result.call1(&JsValue::NULL, &serde_wasm_bindgen::to_value(&result)?)?;
+        found = 1;
+    }
+    Ok(serde_wasm_bindgen::to_value(&StreamingResult {
+        found,
+        statistics: StatisticsResult::new(
+            &iter.statistics(),
+            elapsed_ms,
+            WordList::default().words().len(),
+        ),
+    })?)
+}
+
+/// A user-supplied word list, e.g. for another language or house rules.
+#[wasm_bindgen]
+pub struct Dictionary {
+    dictionary: letter_boxed_solver::Dictionary,
+    dropped: usize,
+}
+
+#[wasm_bindgen]
+impl Dictionary {
+    /// Load a word list from text with one word per line (or separated by
+    /// any whitespace), e.g. pasted by the user or fetched from a file.
+    ///
+    /// Words are uppercased, and any word with something other than letters
+    /// in it (e.g. an apostrophe) or which repeats an earlier word is
+    /// dropped.
+    #[wasm_bindgen(constructor)]
+    pub fn new(text: &str) -> Dictionary {
+        let (dictionary, dropped) = letter_boxed_solver::Dictionary::clean(text);
+        Dictionary {
+            dictionary,
+            dropped: dropped.len(),
+        }
+    }
+
+    /// The number of words in the dictionary.
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.dictionary.len()
+    }
+
+    /// The number of words which were dropped when loading the dictionary.
+    #[wasm_bindgen(getter)]
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+}
+
+/// Solve a board as in [`solve`], using `dictionary` instead of the builtin
+/// word list.
+///
+/// Throws an error message if the board is invalid, or an error as in
+/// [`solve`] if any of `prior_words` is not in the dictionary.
+#[wasm_bindgen]
+pub fn solve_with_dictionary(
+    dictionary: &Dictionary,
+    sides: &str,
+    prior_words: &str,
+    excluded_words: &str,
+    depth: usize,
+) -> Result<JsValue, JsValue> {
+    utils::set_panic_hook();
+
+    let b = parse_board(sides)?;
+
+    let prior_words = prior_words.split_ascii_whitespace().collect::<Vec<_>>();
+    let excluded_words = excluded_words.split_ascii_whitespace().collect::<Vec<_>>();
+    let words = dictionary.dictionary.words();
+    prior_words::check(&words, &prior_words)?;
+
+    let options = SolveOptions::new()
+        .max_depth(depth)
+        .prior_words(&prior_words);
+    let mut iter = b
+        .solve_iter_with_options(&words, &options)
+        .expect("prior words were checked above")
+        .with_excluded(&excluded_words);
+    solve_to_js(&b, &mut iter, words.len())
+}
+
+/// Check a chain of words, given as a whitespace-separated list, against a
+/// board given as in [`solve`].
+///
+/// Returns an array of messages describing every rule the chain breaks, which
+/// is empty if it is a valid and complete solution. Throws an error message
+/// if the board is invalid.
+#[wasm_bindgen]
+pub fn validate(sides: &str, words: &str) -> Result<JsValue, JsValue> {
+    utils::set_panic_hook();
+
+    let b = parse_board(sides)?;
+
+    let words = words.split_ascii_whitespace().collect::<Vec<_>>();
+    let messages = b
+        .validate_detailed(&words)
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>();
+
+    Ok(serde_wasm_bindgen::to_value(&messages)?)
+}
+
+/// The state of a game in progress, as returned to JS.
+#[derive(Serialize)]
+struct GameStateResult {
+    /// Whether the words played so far break no rules.
+    valid: bool,
+    /// Whether the words played so far are a valid and complete solution.
+    complete: bool,
+    /// Messages describing every rule the words break.
+    problems: Vec<String>,
+    /// The board letters used so far.
+    covered: String,
+    /// The board letters which still have to be played.
+    remaining: String,
+    /// The letter the next word must start with, if any word was played.
+    next_letter: Option<char>,
+}
+
+/// Check the words of a game in progress, given as a whitespace-separated
+/// list, against a board given as in [`solve`]. Unlike [`validate`], the
+/// words don't have to cover every letter.
+///
+/// Returns a `{ valid, complete, problems, covered, remaining, next_letter }`
+/// object, where `covered` and `remaining` are strings of letters. Throws an
+/// error message if the board is invalid.
+#[wasm_bindgen]
+pub fn validate_prefix(sides: &str, words: &str) -> Result<JsValue, JsValue> {
+    utils::set_panic_hook();
+
+    let b = parse_board(sides)?;
+
+    let words = words.split_ascii_whitespace().collect::<Vec<_>>();
+    let state = b.validate_prefix(&words);
+
+    Ok(serde_wasm_bindgen::to_value(&GameStateResult {
+        valid: state.is_valid(),
+        complete: state.is_complete(),
+        problems: state.violations.iter().map(|v| v.to_string()).collect(),
+        covered: state.covered.iter().collect(),
+        remaining: state.remaining.iter().collect(),
+        next_letter: state.next_letter,
+    })?)
+}
+
+/// The words of a solution, as passed in from JS. Any other fields are
+/// ignored.
+#[derive(Deserialize)]
+struct SolutionWords {
+    words: Vec<String>,
+}
+
+/// Solutions which start with the same word, as returned to JS.
+#[derive(Serialize)]
+struct SolutionGroupResult<'a> {
+    /// The word every solution in the group starts with.
+    word: &'a str,
+    /// The number of solutions in the group.
+    count: usize,
+    /// The positions of the group's solutions in the array passed in.
+    indices: &'a [usize],
+}
+
+/// Group an array of solutions, as returned by [`solve`], by their first
+/// word.
+///
+/// Returns an array of `{ word, count, indices }` objects in the order each
+/// word first appears, where `indices` are positions in `solutions`. Throws
+/// an error message if `solutions` isn't an array of objects with `words`.
+#[wasm_bindgen]
+pub fn group_by_first_word(solutions: JsValue) -> Result<JsValue, JsValue> {
+    utils::set_panic_hook();
+
+    let solutions = serde_wasm_bindgen::from_value::<Vec<SolutionWords>>(solutions)?;
+    let words = solutions
+        .iter()
+        .map(|s| s.words.iter().map(|w| w.as_str()).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let groups = letter_boxed_solver::group_by_first_word(words.iter().map(|w| &w[..]));
+
+    Ok(serde_wasm_bindgen::to_value(
+        &groups
+            .iter()
+            .map(|g| SolutionGroupResult {
+                word: g.first_word,
+                count: g.len(),
+                indices: &g.indices,
+            })
+            .collect::<Vec<_>>(),
+    )?)
+}
+
+/// A puzzle, as returned to JS.
+#[derive(Serialize)]
+struct PuzzleResult<'a> {
+    /// The sides of the board.
+    sides: Vec<&'a str>,
+    /// The date the puzzle was published for, if known.
+    date: Option<&'a str>,
+    /// The number of words the puzzle should be solved in, if known.
+    par: Option<usize>,
+    /// Where the puzzle was published, if known.
+    source: Option<&'a str>,
+}
+
+/// Load a puzzle from JSON, as either a list of sides, a compact string of
+/// sides as in [`solve`], or an object with those as its `sides` along with
+/// an optional `date`, `par` and `source`.
+///
+/// Returns a `{ sides, date, par, source }` object. Throws an error message
+/// if the JSON isn't a valid board.
+#[wasm_bindgen]
+pub fn load_puzzle(json: &str) -> Result<JsValue, JsValue> {
+    utils::set_panic_hook();
+
+    let b =
+        serde_json::from_str::<LetterBoxed>(json).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let metadata = b.metadata();
+
+    Ok(serde_wasm_bindgen::to_value(&PuzzleResult {
+        sides: b.sides().collect(),
+        date: metadata.date.as_deref(),
+        par: metadata.par,
+        source: metadata.source.as_deref(),
+    })?)
+}
+
+/// How a solution compares to the puzzle's par, as returned to JS.
+#[derive(Serialize)]
+struct ParResult {
+    /// The number of words in the solution.
+    words: usize,
+    /// The par the solution was rated against.
+    par: usize,
+    /// `"under par"`, `"at par"` or `"over par"`.
+    rating: String,
+    /// The fewest words the board can be solved in, if known.
+    optimal: Option<usize>,
+    /// A sentence summarizing the above.
+    message: String,
+}
+
+/// Rate a solution, given as a whitespace-separated list of words, against a
+/// `par` for a board given as in [`solve`].
+///
+/// Returns a `{ words, par, rating, optimal, message }` object. Throws an
+/// error message if the board is invalid, or if the words aren't a valid
+/// and complete solution.
+#[wasm_bindgen]
+pub fn rate_solution(sides: &str, words: &str, par: usize) -> Result<JsValue, JsValue> {
+    utils::set_panic_hook();
+
+    let b = parse_board(sides)?;
+
+    let words = words.split_ascii_whitespace().collect::<Vec<_>>();
+    let report = b
+        .rate_solution_with_builtin_list(&words, par)
+        .map_err(|violations| {
+            let messages = violations.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+            JsValue::from_str(&messages.join("; "))
+        })?;
+
+    Ok(serde_wasm_bindgen::to_value(&ParResult {
+        words: report.words,
+        par: report.par,
+        rating: report.rating.to_string(),
+        optimal: report.optimal,
+        message: report.to_string(),
+    })?)
+}
+
+/// How a solution compares to the best achievable, as returned to JS.
+#[derive(Serialize)]
+struct ComparisonResult<'word> {
+    /// How many more words the solution used than it needed to.
+    word_delta: usize,
+    /// A solution with the fewest words, if known.
+    optimal: Option<Vec<&'word str>>,
+    /// How many letters were played more than once.
+    letters_wasted: usize,
+    /// Other solutions starting with the same word.
+    alternatives: Vec<Vec<&'word str>>,
+    /// A sentence summarizing the above.
+    message: String,
+}
+
+/// Compare a solution, given as a whitespace-separated list of words, to the
+/// best achievable on a board given as in [`solve`] with the builtin word
+/// list, including up to 3 other solutions starting with the same word.
+///
+/// Returns a `{ word_delta, optimal, letters_wasted, alternatives, message }`
+/// object. Throws an error message if the board is invalid, or if the words
+/// aren't a valid and complete solution.
+#[wasm_bindgen]
+pub fn compare_solution(sides: &str, words: &str) -> Result<JsValue, JsValue> {
+    utils::set_panic_hook();
+
+    let b = parse_board(sides)?;
+
+    let words = words.split_ascii_whitespace().collect::<Vec<_>>();
+    let comparison = b
+        .compare_solution_with_builtin_list(&words, 3)
+        .map_err(|violations| {
+            let messages = violations.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+            JsValue::from_str(&messages.join("; "))
+        })?;
+
+    Ok(serde_wasm_bindgen::to_value(&ComparisonResult {
+        word_delta: comparison.word_delta(),
+        letters_wasted: comparison.letters_wasted,
+        message: comparison.to_string(),
+        optimal: comparison.optimal,
+        alternatives: comparison.alternatives,
+    })?)
+}
+
+/// A letter's position on the board, as returned to JS.
+#[derive(Serialize)]
+struct PositionResult {
+    /// The index of the side, in the order the sides were given.
+    side: usize,
+    /// The index of the letter along its side.
+    index: usize,
+}
+
+/// Trace a chain of words, given as a whitespace-separated list, on a board
+/// given as in [`solve`], e.g. to animate drawing it.
+///
+/// Returns an array of `{ side, index }` objects, one for each letter in the
+/// order it is drawn (where the letter shared by consecutive words is only
+/// drawn once), or `null` if the chain can't be traced on the board. Throws
+/// an error message if the board is invalid.
+#[wasm_bindgen]
+pub fn trace(sides: &str, words: &str) -> Result<JsValue, JsValue> {
+    utils::set_panic_hook();
+
+    let b = parse_board(sides)?;
+
+    let words = words.split_ascii_whitespace().collect::<Vec<_>>();
+    let trace = b.trace(&words).map(|positions| {
+        positions
+            .into_iter()
+            .map(|p| PositionResult {
+                side: p.side,
+                index: p.index,
+            })
+            .collect::<Vec<_>>()
+    });
+
+    Ok(serde_wasm_bindgen::to_value(&trace)?)
+}
+
+/// Explain how a chain of words, given as a whitespace-separated list, is
+/// drawn on a board given as in [`solve`], e.g.
+/// `"V(left)→E(top)→H(right)→…"`.
+///
+/// Returns `null` if the chain can't be traced on the board. Throws an error
+/// message if the board is invalid.
+#[wasm_bindgen]
+pub fn explain(sides: &str, words: &str) -> Result<Option<String>, JsValue> {
+    utils::set_panic_hook();
+
+    let b = parse_board(sides)?;
+
+    let words = words.split_ascii_whitespace().collect::<Vec<_>>();
+    Ok(b.explain(&words).map(|e| e.to_string()))
+}
+
+/// Render a board given as in [`solve`] as an SVG image, with a chain of
+/// words, given as a whitespace-separated list, drawn across it. Pass no
+/// words to render just the board.
+///
+/// Returns the SVG as a string, for embedding directly in the page, or
+/// `null` if the chain can't be traced on the board. Throws an error message
+/// if the board is invalid.
+#[wasm_bindgen]
+pub fn render_svg(sides: &str, words: &str) -> Result<Option<String>, JsValue> {
+    utils::set_panic_hook();
+
+    let b = parse_board(sides)?;
+
+    let words = words.split_ascii_whitespace().collect::<Vec<_>>();
+    Ok(b.to_svg(&words))
+}
+
+/// A short, URL-safe code for a board given as in [`solve`], e.g.
+/// `"ElzIvaRyuCth"`, to share it as a permalink.
+///
+/// Returns `null` if the board has letters other than `A` to `Z`. Throws an
+/// error message if the board is invalid.
+#[wasm_bindgen]
+pub fn board_code(sides: &str) -> Result<JsValue, JsValue> {
+    utils::set_panic_hook();
+
+    let b = parse_board(sides)?;
+
+    Ok(serde_wasm_bindgen::to_value(&b.to_code())?)
+}
+
+/// Read a board from a code returned by [`board_code`].
+///
+/// Returns the array of sides. Throws an error message if the code is
+/// invalid.
+#[wasm_bindgen]
+pub fn board_from_code(code: &str) -> Result<JsValue, JsValue> {
+    utils::set_panic_hook();
+
+    let b = LetterBoxed::from_code(code).map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+    Ok(serde_wasm_bindgen::to_value(
+        &b.sides().collect::<Vec<_>>(),
+    )?)
+}
+
+/// A suggested next word, as returned to JS.
+#[derive(Serialize)]
+struct HintResult<'word> {
+    /// The suggested word.
+    word: &'word str,
+    /// How many letters the word covers which haven't been used yet.
+    new_letters: usize,
+    /// How many complete solutions start by playing this word next.
+    completions: usize,
+}
+
+/// Suggest a single next word to play on a board given as in [`solve`],
+/// after the whitespace-separated `prior_words`, aiming for a solution of at
+/// most `depth` words.
+///
+/// Returns a `{ word, new_letters, completions }` object, or `null` if there
+/// is no playable word. Throws an error message if the board is invalid, or
+/// an error as in [`solve`] if any of `prior_words` is not in the word list.
+#[wasm_bindgen]
+pub fn hint(sides: &str, prior_words: &str, depth: usize) -> Result<JsValue, JsValue> {
+    utils::set_panic_hook();
+
+    let b = parse_board(sides)?;
+
+    let prior_words = prior_words.split_ascii_whitespace().collect::<Vec<_>>();
+    prior_words::check(WordList::default().words(), &prior_words)?;
+    let hint = b
+        .hint_with_builtin_list(&prior_words, depth)
+        .into_iter()
+        .next()
+        .map(|h| HintResult {
+            word: h.word,
+            new_letters: h.new_letters,
+            completions: h.completions,
+        });
+
+    Ok(serde_wasm_bindgen::to_value(&hint)?)
+}
+
+/// Describe a solution of `b` for JS.
+fn solution_result<'word>(
+    b: &LetterBoxed,
+    words: Vec<&'word str>,
+    score: usize,
+) -> SolutionResult<'word> {
+    SolutionResult {
+        words,
+        score,
+        total: b.num_letters(),
+        complete: score == b.num_letters(),
+    }
+}
+
+/// Find up to 25 solutions with `iter` (or the best partial solution, if
+/// there are none), returning them as a [`SolveResult`]. `dictionary_words`
+/// is the length of the word list being searched.
+fn solve_to_js(
+    b: &LetterBoxed,
+    iter: &mut SolutionIter<'_, '_>,
+    dictionary_words: usize,
+) -> Result<JsValue, JsValue> {
+    let start = js_sys::Date::now();
+    let mut solutions = iter
+        .by_ref()
+        .take(25)
+        .map(|s| solution_result(b, s.words, s.letters_covered))
+        .collect::<Vec<_>>();
+    if solutions.is_empty() {
+        let best = iter.best_partial();
+        solutions.push(solution_result(b, best.words, best.letters_covered));
+    }
+    let elapsed_ms = js_sys::Date::now() - start;
+
+    Ok(serde_wasm_bindgen::to_value(&SolveResult {
+        solutions,
+        statistics: StatisticsResult::new(&iter.statistics(), elapsed_ms, dictionary_words),
+    })?)
+}
+
+/// Convert solutions into an array of [`SolutionResult`]s.
+fn to_js(b: &LetterBoxed, results: Vec<(Vec<&str>, usize)>) -> Result<JsValue, JsValue> {
+    let results = results
+        .into_iter()
+        .map(|(words, score)| solution_result(b, words, score))
+        .collect::<Vec<_>>();
+
+    Ok(serde_wasm_bindgen::to_value(&results)?)
+}
 
-    String::from_utf8_lossy(&out).to_string()
+/// Parse a board given as its sides, as in [`solve`], throwing an error
+/// message if it is invalid.
+fn parse_board(sides: &str) -> Result<LetterBoxed, JsValue> {
+    sides
+        .parse::<LetterBoxed>()
+        .map_err(|e| JsValue::from_str(&e.to_string()))
 }
@@ -0,0 +1,97 @@
+use letter_boxed_solver::find_word;
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+/// The most suggestions to offer for each unknown word.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// The most edits a suggestion may be from the unknown word.
+const MAX_DISTANCE: usize = 2;
+
+/// A prior word which isn't in the word list, as reported to JS.
+#[derive(Serialize)]
+struct UnknownWord {
+    /// The word as it was entered.
+    word: String,
+    /// The closest words which are in the word list, best first.
+    suggestions: Vec<String>,
+}
+
+/// The error thrown to JS when some prior words aren't in the word list.
+#[derive(Serialize)]
+struct UnknownWordsError {
+    message: String,
+    unknown_words: Vec<UnknownWord>,
+}
+
+/// Check that every one of `prior_words` is in `words`, matched as the
+/// solver matches them (ignoring case and Unicode normalization), since the
+/// solver can't start from a word it doesn't know.
+///
+/// Otherwise, throws a `{ message, unknown_words }` object, where each of
+/// `unknown_words` is a `{ word, suggestions }` object.
+pub(crate) fn check(words: &[&str], prior_words: &[&str]) -> Result<(), JsValue> {
+    let unknown_words = prior_words
+        .iter()
+        .filter(|w| find_word(words, w).is_none())
+        .map(|w| UnknownWord {
+            word: w.to_string(),
+            suggestions: suggestions(words, w),
+        })
+        .collect::<Vec<_>>();
+    if unknown_words.is_empty() {
+        return Ok(());
+    }
+
+    let message = format!(
+        "not in the word list: {}",
+        unknown_words
+            .iter()
+            .map(|u| u.word.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    Err(serde_wasm_bindgen::to_value(&UnknownWordsError {
+        message,
+        unknown_words,
+    })?)
+}
+
+/// The words in `words` closest to `word`, by edit distance and then the
+/// order of the list.
+fn suggestions(words: &[&str], word: &str) -> Vec<String> {
+    let word = word.to_uppercase().chars().collect::<Vec<_>>();
+    let mut close = words
+        .iter()
+        .filter_map(|w| {
+            let candidate = w.to_uppercase().chars().collect::<Vec<_>>();
+            if candidate.len().abs_diff(word.len()) > MAX_DISTANCE {
+                return None;
+            }
+            let distance = edit_distance(&word, &candidate);
+            (distance <= MAX_DISTANCE).then_some((distance, w.to_uppercase()))
+        })
+        .collect::<Vec<_>>();
+    // Sorting is stable, so ties stay in list order.
+    close.sort_by_key(|(distance, _)| *distance);
+    close
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, w)| w)
+        .collect()
+}
+
+/// The Levenshtein distance between `a` and `b`.
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, ca) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitute = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitute.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
@@ -0,0 +1,108 @@
+use letter_boxed_solver::{OwnedWordGraph, WordGraph, WordList};
+use wasm_bindgen::prelude::*;
+
+use crate::{parse_board, prior_words, solve_to_js, utils, Dictionary, HintResult};
+
+/// A board and a word list compiled for it, kept in memory between calls so
+/// that the word list isn't read and filtered again for every solve or hint.
+///
+/// Create a new session whenever the board or word list changes, and call
+/// `free` on the old one.
+#[wasm_bindgen]
+pub struct Session {
+    graph: OwnedWordGraph,
+}
+
+#[wasm_bindgen]
+impl Session {
+    /// Compile the builtin word list for a board given as in `solve`. Throws
+    /// an error message if the board is invalid.
+    #[wasm_bindgen(constructor)]
+    pub fn new(sides: &str) -> Result<Session, JsValue> {
+        utils::set_panic_hook();
+
+        let b = parse_board(sides)?;
+        Ok(Session {
+            graph: OwnedWordGraph::new(b, WordList::default().words()),
+        })
+    }
+
+    /// Compile `dictionary` for a board given as in `solve`, instead of the
+    /// builtin word list. Throws an error message if the board is invalid.
+    pub fn with_dictionary(sides: &str, dictionary: &Dictionary) -> Result<Session, JsValue> {
+        utils::set_panic_hook();
+
+        let b = parse_board(sides)?;
+        Ok(Session {
+            graph: OwnedWordGraph::from_dictionary(b, dictionary.dictionary.clone()),
+        })
+    }
+
+    /// Solve the board as in `solve`, with the same arguments (other than
+    /// the board) and errors.
+    pub fn solve(
+        &self,
+        prior_words: &str,
+        excluded_words: &str,
+        depth: usize,
+    ) -> Result<JsValue, JsValue> {
+        let graph = self.graph.graph();
+        let excluded_words = excluded_words.split_ascii_whitespace().collect::<Vec<_>>();
+        let prior_words_indices = prior_words_indices(graph, prior_words)?;
+        let mut iter = graph
+            .solve_iter(&prior_words_indices, depth)
+            .with_excluded(&excluded_words);
+        solve_to_js(graph.board(), &mut iter, graph.words().len())
+    }
+
+    /// Suggest a single next word to play as in `hint`, with the same
+    /// arguments (other than the board) and errors.
+    pub fn hint(&self, prior_words: &str, depth: usize) -> Result<JsValue, JsValue> {
+        let graph = self.graph.graph();
+        let prior_words_indices = prior_words_indices(graph, prior_words)?;
+        let hint = graph
+            .hint(&prior_words_indices, depth)
+            .into_iter()
+            .next()
+            .map(|h| HintResult {
+                word: h.word,
+                new_letters: h.new_letters,
+                completions: h.completions,
+            });
+
+        Ok(serde_wasm_bindgen::to_value(&hint)?)
+    }
+
+    /// The number of words in the word list which can be played on the
+    /// board.
+    #[wasm_bindgen(getter)]
+    pub fn playable_words(&self) -> usize {
+        self.graph.graph().playable_words().count()
+    }
+
+    /// The letters which no playable word covers, e.g. "QX", which is empty
+    /// unless the board can't be solved with the word list.
+    #[wasm_bindgen(getter)]
+    pub fn dead_letters(&self) -> String {
+        self.graph.graph().dead_letters().iter().collect()
+    }
+}
+
+/// Look up the indices of the whitespace-separated `prior_words` in the word
+/// list `graph` was compiled from, throwing as in `solve` if any of them
+/// aren't in it.
+fn prior_words_indices(
+    graph: &WordGraph<'_, '_>,
+    prior_words: &str,
+) -> Result<Vec<usize>, JsValue> {
+    let prior_words = prior_words.split_ascii_whitespace().collect::<Vec<_>>();
+    match graph.indices(&prior_words) {
+        Some(indices) => Ok(indices),
+        None => {
+            prior_words::check(graph.words(), &prior_words)?;
+            Err(JsValue::from_str(
+                "some of the prior words aren't in the word list",
+            ))
+        }
+    }
+}
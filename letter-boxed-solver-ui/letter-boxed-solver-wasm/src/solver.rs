@@ -0,0 +1,141 @@
+use letter_boxed_solver::{LetterBoxed, SolverSession, WordGraph, WordList};
+use wasm_bindgen::prelude::*;
+
+use crate::{parse_board, prior_words, to_js, utils, StatisticsResult};
+
+/// The most solutions a [`Solver`] will find, as with `solve`.
+const MAX_RESULTS: usize = 25;
+
+/// A solve which runs a little at a time, so that the page can stay
+/// responsive in between steps.
+///
+/// From JS, call `step` repeatedly, yielding to the event loop in between
+/// (e.g. with `setTimeout`), until `finished` is set. Call `free` when done.
+#[wasm_bindgen]
+pub struct Solver {
+    /// The search, which owns its own copy of the board.
+    session: SolverSession<'static, 'static>,
+    board: LetterBoxed,
+    found: usize,
+    /// The time spent in `step` so far, in milliseconds.
+    elapsed_ms: f64,
+}
+
+#[wasm_bindgen]
+impl Solver {
+    /// Start solving a board using the builtin word list, with the same
+    /// arguments (and errors) as `solve`, finding at most
+    /// `max_per_start_word` solutions (or, if it is 0, any number) starting
+    /// with each word after the prior words. Nothing is searched until
+    /// `step` is called.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        sides: &str,
+        prior_words: &str,
+        excluded_words: &str,
+        depth: usize,
+        max_per_start_word: usize,
+    ) -> Result<Solver, JsValue> {
+        utils::set_panic_hook();
+
+        let b = parse_board(sides)?;
+        let words = WordList::default().words();
+        let prior_words = prior_words.split_ascii_whitespace().collect::<Vec<_>>();
+        prior_words::check(words, &prior_words)?;
+        let prior_words_indices = WordList::default()
+            .indices(&prior_words)
+            .ok_or_else(|| JsValue::from_str("some of the prior words aren't in the word list"))?;
+
+        let excluded_words = excluded_words.split_ascii_whitespace().collect::<Vec<_>>();
+        let mut iter = WordGraph::with_owned_board(b.clone(), words)
+            .into_solve_iter(&prior_words_indices, depth)
+            .with_excluded(&excluded_words);
+        if max_per_start_word > 0 {
+            iter = iter.with_max_per_start_word(max_per_start_word);
+        }
+
+        Ok(Solver {
+            session: SolverSession::new(iter),
+            board: b,
+            found: 0,
+            elapsed_ms: 0.0,
+        })
+    }
+
+    /// Continue solving for at most `max_expansions` search states,
+    /// returning any solutions found in this step, in the same format as
+    /// `solve`.
+    pub fn step(&mut self, max_expansions: usize) -> Result<JsValue, JsValue> {
+        // `Instant` isn't available on wasm32-unknown-unknown, so use JS's
+        // clock.
+        let start = js_sys::Date::now();
+        let mut results = vec![];
+        if !self.finished() {
+            for s in self.session.run(max_expansions) {
+                if self.found + results.len() < MAX_RESULTS {
+                    results.push((s.words.clone(), s.letters_covered));
+                }
+            }
+        }
+        self.found += results.len();
+        self.elapsed_ms += js_sys::Date::now() - start;
+        to_js(&self.board, results)
+    }
+
+    /// Whether the search is done, either because it was exhausted or because
+    /// enough solutions were found.
+    #[wasm_bindgen(getter)]
+    pub fn finished(&self) -> bool {
+        self.session.is_finished() || self.found >= MAX_RESULTS
+    }
+
+    /// Fetch up to `limit` solutions starting from the `offset`th, in the
+    /// same format as `step`, searching further if needed, e.g. to show the
+    /// next page of results after `finished` is set. Unlike `step`, this
+    /// isn't limited to the first 25 solutions.
+    ///
+    /// Returns fewer than `limit` solutions once `exhausted` is set.
+    pub fn page(&mut self, offset: usize, limit: usize) -> Result<JsValue, JsValue> {
+        let start = js_sys::Date::now();
+        let results = self
+            .session
+            .page(offset, limit)
+            .iter()
+            .map(|s| (s.words.clone(), s.letters_covered))
+            .collect::<Vec<_>>();
+        self.elapsed_ms += js_sys::Date::now() - start;
+        to_js(&self.board, results)
+    }
+
+    /// Whether every solution has been found, so that `page` won't return
+    /// any more.
+    #[wasm_bindgen(getter)]
+    pub fn exhausted(&self) -> bool {
+        self.session.is_finished()
+    }
+
+    /// Statistics about the search so far, as an `{ elapsed_ms,
+    /// states_expanded, dictionary_words, playable_words }` object.
+    pub fn statistics(&self) -> Result<JsValue, JsValue> {
+        Ok(serde_wasm_bindgen::to_value(&StatisticsResult::new(
+            &self.session.statistics(),
+            self.elapsed_ms,
+            WordList::default().words().len(),
+        ))?)
+    }
+
+    /// The best partial solution found so far, in the same format as the
+    /// solutions returned by `step`. This is useful if no complete solution
+    /// was found.
+    pub fn best_partial(&self) -> Result<JsValue, JsValue> {
+        let best = self.session.best_partial();
+        to_js(&self.board, vec![(best.words, best.letters_covered)])
+    }
+
+    /// The letters which no playable word covers, e.g. "QX", which is empty
+    /// unless the board can't be solved with the builtin word list.
+    #[wasm_bindgen(getter)]
+    pub fn dead_letters(&self) -> String {
+        self.session.dead_letters().iter().collect()
+    }
+}
@@ -6,15 +6,130 @@ import "./App.css";
 
 await wasm;
 
+// If the solver was built with threads, start its workers before solving.
+if (wasm.initThreadPool) {
+  await wasm.initThreadPool(navigator.hardwareConcurrency);
+}
+
+// Describe an error thrown by the solver, suggesting corrections for any
+// prior words which aren't in the word list.
+function describeError(e) {
+  if (e && e.unknown_words) {
+    return e.unknown_words
+      .map(({ word, suggestions }) =>
+        suggestions.length > 0
+          ? `${word} isn't in the word list (did you mean ${suggestions.join(
+              " or "
+            )}?)`
+          : `${word} isn't in the word list`
+      )
+      .join("; ");
+  }
+  return String(e);
+}
+
+// Describe a game in progress, for feedback as the prior words are typed.
+function describeProgress(sides, priorWords) {
+  if (sides.some((side) => side.length === 0) || !priorWords.trim()) {
+    return "";
+  }
+  try {
+    const state = wasm.validate_prefix(sides.join(" "), priorWords);
+    if (!state.valid) {
+      return state.problems.join("; ");
+    }
+    if (state.complete) {
+      return "Those words solve the board!";
+    }
+    return `${state.remaining.length} letters left (${state.remaining}), next word starts with ${state.next_letter}`;
+  } catch (e) {
+    return "";
+  }
+}
+
+// Describe a solution returned by the solver.
+function describeSolution(s) {
+  return `${s.words.join(" ")} (${s.score}/${s.total}${
+    s.complete ? "" : ", incomplete"
+  })`;
+}
+
+// The sides of the board shared in the page's `?board=` permalink, if any.
+function initialSides() {
+  const code = new URLSearchParams(window.location.search).get("board");
+  if (code) {
+    try {
+      return wasm.board_from_code(code);
+    } catch (e) {
+      // Fall back to an empty board if the link is broken.
+    }
+  }
+  return ["", "", "", ""];
+}
+
 function App() {
   const [solving, setSolving] = React.useState(false);
-  const [solution, setSolution] = React.useState("");
+  const [solutions, setSolutions] = React.useState([]);
+  const [error, setError] = React.useState("");
+  const [message, setMessage] = React.useState("");
   const [priorWords, setPriorWords] = React.useState("");
-  const [side1, setSide1] = React.useState("");
-  const [side2, setSide2] = React.useState("");
-  const [side3, setSide3] = React.useState("");
-  const [side4, setSide4] = React.useState("");
+  const [excludedWords, setExcludedWords] = React.useState("");
+  const [sides, setSides] = React.useState(initialSides);
   const [depth, setDepth] = React.useState(2);
+  const [dictionary, setDictionary] = React.useState(null);
+  const sessionRef = React.useRef(null);
+  // The last solve, if it may have more solutions to show.
+  const [moreSolver, setMoreSolver] = React.useState(null);
+  const [grouped, setGrouped] = React.useState(false);
+  const [explained, setExplained] = React.useState(false);
+  const [maxPerStartWord, setMaxPerStartWord] = React.useState(0);
+
+  // Show a solution, along with how it's drawn if asked for.
+  function showSolution(s) {
+    if (!explained) {
+      return describeSolution(s);
+    }
+    let explanation = null;
+    try {
+      explanation = wasm.explain(sides.join(" "), s.words.join(" "));
+    } catch (e) {
+      // The board was edited since solving.
+    }
+    return (
+      <>
+        {describeSolution(s)}
+        {explanation && <div>{explanation}</div>}
+      </>
+    );
+  }
+
+  // An SVG image of a solution drawn across the board, which is empty if the
+  // board was edited since solving.
+  function drawSolution(s) {
+    try {
+      return wasm.render_svg(sides.join(" "), s.words.join(" ")) || "";
+    } catch (e) {
+      return "";
+    }
+  }
+
+  // The board and word list compiled for solving and hints, which is only
+  // rebuilt when one of them changes. Throws if the board is invalid.
+  function getSession() {
+    const key = sides.join(" ");
+    const cached = sessionRef.current;
+    if (cached && cached.key === key && cached.dictionary === dictionary) {
+      return cached.session;
+    }
+    const session = dictionary
+      ? wasm.Session.with_dictionary(key, dictionary)
+      : new wasm.Session(key);
+    if (cached) {
+      cached.session.free();
+    }
+    sessionRef.current = { key, dictionary, session };
+    return session;
+  }
 
   return (
     <div className="App">
@@ -29,34 +144,32 @@ function App() {
         </a>
       </p>
       <p>
-        Type the four sides of the Letter Boxed Puzzle here, and the maximum
-        number of words
+        Type the sides of the Letter Boxed Puzzle here (usually four, but any
+        number of sides works), and the maximum number of words
       </p>
       <p>
-        <input
-          type="text"
-          placeholder="top"
-          value={side1}
-          onChange={(evt) => setSide1(evt.target.value.toUpperCase())}
-        />
-        <input
-          type="text"
-          placeholder="right"
-          value={side2}
-          onChange={(evt) => setSide2(evt.target.value.toUpperCase())}
-        />
-        <input
-          type="text"
-          placeholder="bottom"
-          value={side3}
-          onChange={(evt) => setSide3(evt.target.value.toUpperCase())}
-        />
-        <input
-          type="text"
-          placeholder="left"
-          value={side4}
-          onChange={(evt) => setSide4(evt.target.value.toUpperCase())}
-        />
+        {sides.map((side, idx) => (
+          <input
+            key={idx}
+            type="text"
+            placeholder={`side ${idx + 1}`}
+            value={side}
+            onChange={(evt) =>
+              setSides(
+                sides.map((s, i) =>
+                  i === idx ? evt.target.value.toUpperCase() : s
+                )
+              )
+            }
+          />
+        ))}
+        <button onClick={() => setSides([...sides, ""])}>+</button>
+        <button
+          onClick={() => setSides(sides.slice(0, -1))}
+          disabled={sides.length <= 2}
+        >
+          -
+        </button>
         <input
           type="number"
           value={depth}
@@ -72,30 +185,280 @@ function App() {
           value={priorWords}
           onChange={(evt) => setPriorWords(evt.target.value.toUpperCase())}
         />
+        &nbsp;{describeProgress(sides, priorWords)}
+      </p>
+      <p>
+        If there are words the solver should never use, enter them
+        here:&nbsp;
+        <input
+          type="text"
+          placeholder=""
+          value={excludedWords}
+          onChange={(evt) =>
+            setExcludedWords(evt.target.value.toUpperCase())
+          }
+        />
+      </p>
+      <p>
+        To see a variety of openings, show at most this many solutions
+        starting with each word (0 for no limit):&nbsp;
+        <input
+          type="number"
+          min="0"
+          value={maxPerStartWord}
+          onChange={(evt) =>
+            setMaxPerStartWord(Math.max(0, parseInt(evt.target.value, 10) || 0))
+          }
+        />
+      </p>
+      <p>
+        To use your own word list instead of the NYT one, choose a file with
+        one word per line, or paste it here:&nbsp;
+        <input
+          type="file"
+          accept=".txt,text/plain"
+          onChange={async (evt) => {
+            const file = evt.target.files[0];
+            setDictionary(file ? new wasm.Dictionary(await file.text()) : null);
+          }}
+        />
+        <textarea
+          placeholder="one word per line"
+          onChange={(evt) =>
+            setDictionary(
+              evt.target.value.trim()
+                ? new wasm.Dictionary(evt.target.value)
+                : null
+            )
+          }
+        />
+        {dictionary &&
+          ` (${dictionary.length} words${
+            dictionary.dropped > 0
+              ? `, ${dictionary.dropped} skipped for punctuation, digits or repeats`
+              : ""
+          })`}
       </p>
       <p>
         <button
-          onClick={() => {
+          onClick={async () => {
             setSolving(true);
-            setTimeout(() => {
-              setSolution(
-                wasm.solve(side1, side2, side3, side4, priorWords, depth)
+            setSolutions([]);
+            setError("");
+            setMessage("");
+            if (moreSolver) {
+              moreSolver.free();
+              setMoreSolver(null);
+            }
+            if (dictionary) {
+              try {
+                const result = getSession().solve(
+                  priorWords,
+                  excludedWords,
+                  depth
+                );
+                setSolutions(result.solutions);
+                setMessage(
+                  `Searched ${result.statistics.states_expanded.toLocaleString()} states ` +
+                    `of ${result.statistics.playable_words.toLocaleString()} playable words ` +
+                    `in ${Math.round(result.statistics.elapsed_ms)} ms.`
+                );
+              } catch (e) {
+                setError(describeError(e));
+              }
+              setSolving(false);
+              return;
+            }
+
+            // Solve a step at a time, yielding to the browser in between so
+            // that the page stays responsive.
+            let solver;
+            try {
+              solver = new wasm.Solver(
+                sides.join(" "),
+                priorWords,
+                excludedWords,
+                depth,
+                maxPerStartWord
               );
+            } catch (e) {
+              setError(describeError(e));
               setSolving(false);
-            }, 0);
+              return;
+            }
+            let found = [];
+            while (!solver.finished) {
+              found = [...found, ...solver.step(50000)];
+              setSolutions(found);
+              await new Promise((resolve) => setTimeout(resolve, 0));
+            }
+            const statistics = solver.statistics();
+            setMessage(
+              `Searched ${statistics.states_expanded.toLocaleString()} states ` +
+                `of ${statistics.playable_words.toLocaleString()} playable words ` +
+                `in ${Math.round(statistics.elapsed_ms)} ms.`
+            );
+            if (found.length === 0) {
+              setSolutions(solver.best_partial());
+              if (solver.dead_letters) {
+                setMessage(
+                  `No word in the word list can play ${solver.dead_letters}.`
+                );
+              }
+            }
+            if (found.length > 0 && !solver.exhausted) {
+              setMoreSolver(solver);
+            } else {
+              solver.free();
+            }
+            setSolving(false);
           }}
-          disabled={
-            side1.length === 0 ||
-            side2.length === 0 ||
-            side3.length === 0 ||
-            side4.length === 0 ||
-            solving
-          }
+          disabled={sides.some((side) => side.length === 0) || solving}
         >
           Solve
         </button>
+        <button
+          onClick={() => {
+            try {
+              const problems = wasm.validate(sides.join(" "), priorWords);
+              if (problems.length > 0) {
+                setMessage(problems.join("; "));
+                return;
+              }
+              // Rate the solution against the maximum number of words.
+              const rating = wasm.rate_solution(
+                sides.join(" "),
+                priorWords,
+                depth
+              );
+              const comparison = wasm.compare_solution(
+                sides.join(" "),
+                priorWords
+              );
+              const alternatives = comparison.alternatives
+                .map((words) => words.join(" - "))
+                .join(", ");
+              setMessage(
+                `That's a valid solution: ${rating.message}! ` +
+                  `${comparison.message}.` +
+                  (alternatives ? ` You could also have played ${alternatives}.` : "")
+              );
+            } catch (e) {
+              setMessage(describeError(e));
+            }
+          }}
+          disabled={sides.some((side) => side.length === 0) || !priorWords}
+        >
+          Check my words
+        </button>
+        <button
+          onClick={() => {
+            try {
+              const hint = getSession().hint(priorWords, depth);
+              setMessage(
+                hint
+                  ? `Try ${hint.word}, which uses ${hint.new_letters} new letters`
+                  : "There's no word to play next."
+              );
+            } catch (e) {
+              setMessage(describeError(e));
+            }
+          }}
+          disabled={sides.some((side) => side.length === 0)}
+        >
+          Give me a hint
+        </button>
+        <button
+          onClick={async () => {
+            try {
+              const code = wasm.board_code(sides.join(" "));
+              if (!code) {
+                setMessage("Only boards of the letters A to Z can be shared.");
+                return;
+              }
+              const url = new URL(window.location.href);
+              url.searchParams.set("board", code);
+              window.history.replaceState(null, "", url);
+              await navigator.clipboard.writeText(url.toString());
+              setMessage("Copied a link to this board.");
+            } catch (e) {
+              setMessage(describeError(e));
+            }
+          }}
+          disabled={sides.some((side) => side.length === 0)}
+        >
+          Share board
+        </button>
+      </p>
+      {message && <p>{message}</p>}
+      {error && <p className="error">{error}</p>}
+      <p>
+        <label>
+          <input
+            type="checkbox"
+            checked={grouped}
+            onChange={(evt) => setGrouped(evt.target.checked)}
+          />
+          Group solutions by first word
+        </label>
+        <label>
+          <input
+            type="checkbox"
+            checked={explained}
+            onChange={(evt) => setExplained(evt.target.checked)}
+          />
+          Explain which side each letter is on
+        </label>
       </p>
-      <pre>{solution}</pre>
+      {grouped ? (
+        <ul>
+          {wasm.group_by_first_word(solutions).map((group) => (
+            <li key={group.word}>
+              <details>
+                <summary>
+                  {group.word} → {group.count}{" "}
+                  {group.count === 1 ? "solution" : "solutions"}
+                </summary>
+                <ol>
+                  {group.indices.map((idx) => (
+                    <li key={idx}>{showSolution(solutions[idx])}</li>
+                  ))}
+                </ol>
+              </details>
+            </li>
+          ))}
+        </ul>
+      ) : (
+        <ol>
+          {solutions.map((s, idx) => (
+            <li key={idx}>{showSolution(s)}</li>
+          ))}
+        </ol>
+      )}
+      {solutions.length > 0 && (
+        <div
+          className="board"
+          dangerouslySetInnerHTML={{ __html: drawSolution(solutions[0]) }}
+        />
+      )}
+      {moreSolver && (
+        <p>
+          <button
+            onClick={() => {
+              setSolutions([
+                ...solutions,
+                ...moreSolver.page(solutions.length, 25),
+              ]);
+              if (moreSolver.exhausted) {
+                moreSolver.free();
+                setMoreSolver(null);
+              }
+            }}
+          >
+            More solutions
+          </button>
+        </p>
+      )}
     </div>
   );
 }
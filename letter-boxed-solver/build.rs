@@ -0,0 +1,24 @@
+//! Compresses the builtin word list into an FST at compile time, so that the
+//! library (and in particular the wasm binary) doesn't embed the raw text.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/words.txt");
+
+    let text = fs::read_to_string("src/words.txt").expect("failed to read src/words.txt");
+    let mut words = text
+        .lines()
+        .map(|w| w.trim())
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>();
+    // FSTs must be built from sorted, deduplicated keys.
+    words.sort_unstable();
+    words.dedup();
+
+    let set = fst::Set::from_iter(words).expect("failed to build word FST");
+    let out = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("words.fst");
+    fs::write(out, set.as_fst().as_bytes()).expect("failed to write word FST");
+}
@@ -0,0 +1,97 @@
+#ifndef LETTER_BOXED_SOLVER_H
+#define LETTER_BOXED_SOLVER_H
+
+/* Generated by cbindgen from src/capi.rs. Do not edit by hand. */
+
+#include <stddef.h>
+
+// A basic solver for the New York Times "Letter Boxed" puzzle.
+//
+// The puzzle is set up as a square, where there are letters on each side of
+// the square. Letter transitions within a word must cross from one side of the
+// square to another, and the first letter of the next word must match the last
+// letter of the current word.
+//
+// Boards don't have to be square: any polygon with two or more sides (e.g. a
+// triangle or a hexagon) follows the same rules.
+//
+// The goal of the game is to use all of the letters with the fewest number of
+// words.
+//
+// For example:
+//
+//   E L Z
+// I       C
+// V       T
+// A       H
+//   R Y U
+//
+// has a valid solution of "VEHICULAR" followed by "RITZILY".
+//
+typedef struct LetterBoxed LetterBoxed;
+
+// Create a board from a string of sides, e.g. `"ELZ-IVA-RYU-CTH"`, as in
+// [`LetterBoxed::from_str`](std::str::FromStr::from_str). Returns null if
+// the board is malformed.
+//
+// The board must be freed with [`lbs_board_free`].
+//
+// # Safety
+//
+// `sides` must be a valid NUL-terminated string.
+struct LetterBoxed *lbs_board_new(const char *sides);
+
+// Free a board created by [`lbs_board_new`].
+//
+// # Safety
+//
+// `board` must be null, or a board returned by [`lbs_board_new`] which
+// hasn't already been freed.
+void lbs_board_free(struct LetterBoxed *board);
+
+// Solve `board` using the builtin word list, writing the solutions into
+// `buf` as described in the [module documentation](self).
+//
+// `prior_words` are whitespace-separated words which have already been
+// played, and may be null. At most `buf_len` bytes are written, including a
+// NUL terminator, truncating the output if necessary.
+//
+// Returns the length of the full output, not including the NUL terminator,
+// so the output was truncated if this is at least `buf_len`. Returns -1 if
+// any argument is invalid, or any of `prior_words` is not in the word list.
+//
+// # Safety
+//
+// `board` must be a valid board, `prior_words` must be null or a valid
+// NUL-terminated string, and `buf` must be valid for writes of `buf_len`
+// bytes.
+ptrdiff_t lbs_solve(const struct LetterBoxed *board,
+                    const char *prior_words,
+                    size_t max_depth,
+                    size_t max_results,
+                    char *buf,
+                    size_t buf_len);
+
+// Solve `board` as in [`lbs_solve`], returning the output as a newly
+// allocated NUL-terminated string, or null on error.
+//
+// The result must be freed with [`lbs_string_free`].
+//
+// # Safety
+//
+// `board` must be a valid board, and `prior_words` must be null or a valid
+// NUL-terminated string.
+char *lbs_solve_alloc(const struct LetterBoxed *board,
+                      const char *prior_words,
+                      size_t max_depth,
+                      size_t max_results);
+
+// Free a string returned by [`lbs_solve_alloc`].
+//
+// # Safety
+//
+// `s` must be null, or a string returned by [`lbs_solve_alloc`] which hasn't
+// already been freed.
+void lbs_string_free(char *s);
+
+#endif  /* LETTER_BOXED_SOLVER_H */
@@ -0,0 +1,98 @@
+use alloc::collections::BTreeMap;
+use core::fmt;
+
+use crate::{LetterBoxed, WordGraph};
+
+/// Statistics about how a dictionary fits a board, as reported by
+/// [`LetterBoxed::analytics`], e.g. to compare dictionaries on the same board
+/// or as inputs to a difficulty score.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DictionaryAnalytics {
+    /// The number of words which can be played on the board.
+    pub playable_words: usize,
+    /// The number of playable words starting with each board letter,
+    /// including those no word starts with.
+    pub words_per_start_letter: BTreeMap<char, usize>,
+    /// How many playable words cover each number of distinct board letters.
+    pub letters_covered: BTreeMap<usize, usize>,
+    /// The number of playable words which use every letter on the board,
+    /// i.e. the one-word solutions.
+    pub pangrams: usize,
+}
+
+impl DictionaryAnalytics {
+    /// The average number of distinct letters a playable word covers, or 0
+    /// if no word is playable.
+    pub fn mean_letters_covered(&self) -> f64 {
+        if self.playable_words == 0 {
+            return 0.0;
+        }
+        let total = self
+            .letters_covered
+            .iter()
+            .map(|(letters, words)| letters * words)
+            .sum::<usize>();
+        total as f64 / self.playable_words as f64
+    }
+}
+
+impl fmt::Display for DictionaryAnalytics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} playable words covering {:.1} letters on average, {} pangrams",
+            self.playable_words,
+            self.mean_letters_covered(),
+            self.pangrams
+        )
+    }
+}
+
+impl WordGraph<'_, '_> {
+    /// Summarize the playable words, as in [`LetterBoxed::analytics`].
+    pub fn analytics(&self) -> DictionaryAnalytics {
+        let mut words_per_start_letter = self
+            .board
+            .letters
+            .iter()
+            .map(|l| (*l, 0))
+            .collect::<BTreeMap<_, _>>();
+        for (start, ends) in &self.graph {
+            *words_per_start_letter.entry(*start).or_default() +=
+                ends.values().map(|words| words.len()).sum::<usize>();
+        }
+
+        let mut letters_covered = BTreeMap::new();
+        for letters in self.word_letters.values() {
+            *letters_covered.entry(letters.len()).or_default() += 1;
+        }
+
+        DictionaryAnalytics {
+            playable_words: self.word_letters.len(),
+            words_per_start_letter,
+            letters_covered,
+            pangrams: self
+                .word_letters
+                .keys()
+                .filter(|idx| self.is_pangram(**idx))
+                .count(),
+        }
+    }
+}
+
+impl LetterBoxed {
+    /// Summarize how the builtin word list fits the board. See
+    /// [`LetterBoxed::analytics`].
+    #[cfg(feature = "std")]
+    pub fn analytics_with_builtin_list(&self) -> DictionaryAnalytics {
+        self.analytics(crate::builtin_words())
+    }
+
+    /// Summarize how `words` fits the board: how many words are playable,
+    /// how they're spread over starting letters and over the number of
+    /// letters they cover, and how many are pangrams. This only compiles the
+    /// word list, without searching for solutions.
+    pub fn analytics(&self, words: &[&str]) -> DictionaryAnalytics {
+        self.word_graph(words).analytics()
+    }
+}
@@ -0,0 +1,71 @@
+//! Generating random boards and words for property tests and fuzzing, with
+//! the [`arbitrary`] crate.
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::LetterBoxed;
+
+/// The most sides an arbitrary board has.
+const MAX_SIDES: usize = 6;
+
+/// The most letters an arbitrary board has on each side.
+const MAX_LETTERS_PER_SIDE: usize = 4;
+
+/// The most letters an arbitrary word has beyond the board's minimum word
+/// length.
+const MAX_EXTRA_LETTERS: usize = 6;
+
+/// Arbitrary boards are always well-formed, as checked by
+/// [`LetterBoxed::try_load_board`]: they have between 2 and 6 sides of
+/// between 1 and 4 letters each, and no letter is repeated. Letters are
+/// chosen from `A` to `Z`, so boards aren't necessarily solvable.
+impl<'a> Arbitrary<'a> for LetterBoxed {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let sides = u.int_in_range(2..=MAX_SIDES)?;
+        let letters_per_side = u.int_in_range(1..=MAX_LETTERS_PER_SIDE)?;
+
+        let mut alphabet = ('A'..='Z').collect::<Vec<_>>();
+        let mut board = vec![String::new(); sides];
+        for side in &mut board {
+            for _ in 0..letters_per_side {
+                let idx = u.choose_index(alphabet.len())?;
+                side.push(alphabet.swap_remove(idx));
+            }
+        }
+
+        let board = board.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+        Ok(LetterBoxed::load_board(&board))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (2, None)
+    }
+}
+
+impl LetterBoxed {
+    /// Generate a word which can be traced on this board, as checked by
+    /// [`LetterBoxed::is_playable`]. The word is a random walk over the
+    /// board's letters, so it usually isn't in any word list.
+    ///
+    /// Fails if no letter on the board can follow another, e.g. if the board
+    /// only has one side.
+    pub fn arbitrary_word(&self, u: &mut Unstructured<'_>) -> Result<String> {
+        let letters = self.letters.iter().copied().collect::<Vec<_>>();
+        let length = self.min_word_length.max(1) + u.int_in_range(0..=MAX_EXTRA_LETTERS)?;
+
+        let mut word = vec![*u.choose(&letters)?];
+        while word.len() < length {
+            let cur = word[word.len() - 1];
+            let next = letters
+                .iter()
+                .copied()
+                .filter(|c| !self.nonadjacent.contains(&(cur, *c)))
+                .collect::<Vec<_>>();
+            if next.is_empty() {
+                return Err(arbitrary::Error::IncorrectFormat);
+            }
+            word.push(*u.choose(&next)?);
+        }
+        Ok(word.into_iter().collect())
+    }
+}
@@ -0,0 +1,102 @@
+//! An archive of past New York Times puzzles, e.g. to benchmark the word
+//! list against real boards or to replay an old puzzle.
+
+use std::sync::OnceLock;
+
+use crate::{BoardError, BoardMetadata, LetterBoxed};
+
+/// A past puzzle from the archive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchivedPuzzle {
+    /// The date the puzzle was published for, e.g. `"2024-01-31"`.
+    pub date: String,
+    /// The number of words the puzzle should be solved in.
+    pub par: usize,
+    /// The letters on each side of the board.
+    pub sides: Vec<String>,
+    /// The words of the official solution, if known.
+    pub solution: Vec<String>,
+}
+
+impl ArchivedPuzzle {
+    /// The board, ready to solve, with the puzzle's date and par as its
+    /// metadata.
+    pub fn board(&self) -> Result<LetterBoxed, BoardError> {
+        let sides = self.sides.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+        Ok(
+            LetterBoxed::try_load_board(&sides)?.with_metadata(BoardMetadata {
+                date: Some(self.date.clone()),
+                par: Some(self.par),
+                source: None,
+            }),
+        )
+    }
+
+    /// The puzzle as a line of the archive, which [`parse`] reads back.
+    pub fn to_line(&self) -> String {
+        let mut line = format!("{} {} {}", self.date, self.par, self.sides.join("-"));
+        for word in &self.solution {
+            line.push(' ');
+            line.push_str(word);
+        }
+        line
+    }
+}
+
+/// Every puzzle in the builtin archive, oldest first.
+pub fn puzzles() -> impl Iterator<Item = &'static ArchivedPuzzle> {
+    static PUZZLES: OnceLock<Vec<ArchivedPuzzle>> = OnceLock::new();
+    PUZZLES
+        .get_or_init(|| parse(ARCHIVE).expect("the builtin archive is malformed"))
+        .iter()
+}
+
+/// The puzzle published for `date` (e.g. `"2024-01-31"`), if it's in the
+/// builtin archive.
+pub fn on(date: &str) -> Option<&'static ArchivedPuzzle> {
+    puzzles().find(|p| p.date == date)
+}
+
+/// Parse puzzles in the format of the builtin archive: one puzzle per line,
+/// as the date, the par, the sides joined by `-`, and then any words of the
+/// official solution, separated by whitespace. Blank lines and lines
+/// starting with `#` are skipped.
+///
+/// Returns the (1-based) number of the first line which can't be parsed.
+pub fn parse(text: &str) -> Result<Vec<ArchivedPuzzle>, usize> {
+    let mut puzzles = vec![];
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.split_whitespace();
+        let mut next = || fields.next().ok_or(i + 1);
+        let date = next()?.to_string();
+        let par = next()?.parse().map_err(|_| i + 1)?;
+        let sides = next()?.split('-').map(|s| s.to_string()).collect();
+        puzzles.push(ArchivedPuzzle {
+            date,
+            par,
+            sides,
+            solution: fields.map(|w| w.to_string()).collect(),
+        });
+    }
+    Ok(puzzles)
+}
+
+#[cfg(feature = "nyt")]
+impl crate::nyt::DailyPuzzle {
+    /// The puzzle as a line to append to the archive.
+    pub fn to_archive_line(&self) -> String {
+        ArchivedPuzzle {
+            date: self.date.clone(),
+            par: self.par,
+            sides: self.board.sides.clone(),
+            solution: self.solution.clone(),
+        }
+        .to_line()
+    }
+}
+
+const ARCHIVE: &str = include_str!("archive.txt");
@@ -0,0 +1,156 @@
+//! A C-compatible interface to the solver, for embedding it in other
+//! languages and platforms.
+//!
+//! Build a static or dynamic library with e.g.
+//! `cargo rustc --release --features capi --crate-type staticlib`. The
+//! declarations are in `include/letter_boxed_solver.h`, which is generated
+//! with `cbindgen --config cbindgen.toml --output include/letter_boxed_solver.h`.
+//!
+//! Solutions are written as text, one per line, as the number of letters
+//! covered followed by the words, separated by spaces, e.g.
+//! `12 VEHICULAR RITZILY`.
+
+use std::ffi::{c_char, CStr, CString};
+use std::fmt::Write;
+use std::ptr;
+
+use crate::{builtin_words, LetterBoxed};
+
+/// Create a board from a string of sides, e.g. `"ELZ-IVA-RYU-CTH"`, as in
+/// [`LetterBoxed::from_str`](std::str::FromStr::from_str). Returns null if
+/// the board is malformed.
+///
+/// The board must be freed with [`lbs_board_free`].
+///
+/// # Safety
+///
+/// `sides` must be a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn lbs_board_new(sides: *const c_char) -> *mut LetterBoxed {
+    if sides.is_null() {
+        return ptr::null_mut();
+    }
+    let Ok(sides) = CStr::from_ptr(sides).to_str() else {
+        return ptr::null_mut();
+    };
+    match sides.parse::<LetterBoxed>() {
+        Ok(board) => Box::into_raw(Box::new(board)),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Free a board created by [`lbs_board_new`].
+///
+/// # Safety
+///
+/// `board` must be null, or a board returned by [`lbs_board_new`] which
+/// hasn't already been freed.
+#[no_mangle]
+pub unsafe extern "C" fn lbs_board_free(board: *mut LetterBoxed) {
+    if !board.is_null() {
+        drop(Box::from_raw(board));
+    }
+}
+
+/// Solve `board` using the builtin word list, writing the solutions into
+/// `buf` as described in the [module documentation](self).
+///
+/// `prior_words` are whitespace-separated words which have already been
+/// played, and may be null. At most `buf_len` bytes are written, including a
+/// NUL terminator, truncating the output if necessary.
+///
+/// Returns the length of the full output, not including the NUL terminator,
+/// so the output was truncated if this is at least `buf_len`. Returns -1 if
+/// any argument is invalid, or any of `prior_words` is not in the word list.
+///
+/// # Safety
+///
+/// `board` must be a valid board, `prior_words` must be null or a valid
+/// NUL-terminated string, and `buf` must be valid for writes of `buf_len`
+/// bytes.
+#[no_mangle]
+pub unsafe extern "C" fn lbs_solve(
+    board: *const LetterBoxed,
+    prior_words: *const c_char,
+    max_depth: usize,
+    max_results: usize,
+    buf: *mut c_char,
+    buf_len: usize,
+) -> isize {
+    if buf.is_null() && buf_len > 0 {
+        return -1;
+    }
+    let Some(out) = solve_to_string(board, prior_words, max_depth, max_results) else {
+        return -1;
+    };
+
+    if buf_len > 0 {
+        let n = out.len().min(buf_len - 1);
+        ptr::copy_nonoverlapping(out.as_ptr(), buf.cast::<u8>(), n);
+        *buf.add(n) = 0;
+    }
+    out.len() as isize
+}
+
+/// Solve `board` as in [`lbs_solve`], returning the output as a newly
+/// allocated NUL-terminated string, or null on error.
+///
+/// The result must be freed with [`lbs_string_free`].
+///
+/// # Safety
+///
+/// `board` must be a valid board, and `prior_words` must be null or a valid
+/// NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn lbs_solve_alloc(
+    board: *const LetterBoxed,
+    prior_words: *const c_char,
+    max_depth: usize,
+    max_results: usize,
+) -> *mut c_char {
+    solve_to_string(board, prior_words, max_depth, max_results)
+        .and_then(|out| CString::new(out).ok())
+        .map_or(ptr::null_mut(), CString::into_raw)
+}
+
+/// Free a string returned by [`lbs_solve_alloc`].
+///
+/// # Safety
+///
+/// `s` must be null, or a string returned by [`lbs_solve_alloc`] which hasn't
+/// already been freed.
+#[no_mangle]
+pub unsafe extern "C" fn lbs_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+/// Solve and format the results, or `None` if any argument is invalid.
+unsafe fn solve_to_string(
+    board: *const LetterBoxed,
+    prior_words: *const c_char,
+    max_depth: usize,
+    max_results: usize,
+) -> Option<String> {
+    let board = board.as_ref()?;
+    let prior_words = if prior_words.is_null() {
+        ""
+    } else {
+        CStr::from_ptr(prior_words).to_str().ok()?
+    };
+    let prior_words = prior_words.split_whitespace().collect::<Vec<_>>();
+
+    let results =
+        board.solve_with_word_list(builtin_words(), &prior_words, max_depth, max_results)?;
+
+    let mut out = String::new();
+    for (words, covered) in results {
+        write!(out, "{covered}").unwrap();
+        for word in words {
+            write!(out, " {word}").unwrap();
+        }
+        out.push('\n');
+    }
+    Some(out)
+}
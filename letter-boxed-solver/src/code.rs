@@ -0,0 +1,53 @@
+use crate::prelude::*;
+use crate::{BoardError, LetterBoxed};
+
+impl LetterBoxed {
+    /// A short, URL-safe code for the board, e.g. `"ElzIvaRyuCth"` for the
+    /// sides `ELZ`, `IVA`, `RYU` and `CTH`, which [`LetterBoxed::from_code`]
+    /// reads back. This is useful for sharing a board as a permalink.
+    ///
+    /// Each side is written in lowercase, except for its first letter, which
+    /// marks where the side starts. Returns `None` if any letter on the board
+    /// isn't one of `A` to `Z`, since those can't be written this way. The
+    /// rules and minimum word length of the board aren't included.
+    pub fn to_code(&self) -> Option<String> {
+        let mut code = String::new();
+        for side in &self.sides {
+            for (i, c) in side.chars().enumerate() {
+                if !c.is_ascii_alphabetic() {
+                    return None;
+                }
+                code.push(if i == 0 { c } else { c.to_ascii_lowercase() });
+            }
+        }
+        Some(code)
+    }
+
+    /// Read a board from a code written by [`LetterBoxed::to_code`], where
+    /// every uppercase letter starts a new side.
+    ///
+    /// As with [`LetterBoxed::load_board`], a letter may appear on more than
+    /// one side, but the code must only contain the letters `A` to `Z` and
+    /// describe at least two sides.
+    pub fn from_code(code: &str) -> Result<LetterBoxed, BoardError> {
+        let mut sides: Vec<String> = vec![];
+        for c in code.trim().chars() {
+            if !c.is_ascii_alphabetic() {
+                return Err(BoardError::NonAlphabetic {
+                    character: c,
+                    side: sides.len().saturating_sub(1),
+                });
+            }
+            match sides.last_mut() {
+                Some(side) if c.is_ascii_lowercase() => side.push(c),
+                _ => sides.push(c.to_string()),
+            }
+        }
+        if sides.len() < 2 {
+            return Err(BoardError::TooFewSides { found: sides.len() });
+        }
+
+        let sides = sides.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+        Ok(LetterBoxed::load_board(&sides))
+    }
+}
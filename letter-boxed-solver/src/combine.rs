@@ -0,0 +1,63 @@
+use alloc::collections::BTreeSet;
+
+use crate::prelude::*;
+use crate::{letters, LetterBoxed, Solution, Violation};
+
+/// Whether a handful of words can be chained together to solve a board, as
+/// reported by [`LetterBoxed::combine`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Combination<'word> {
+    /// Candidates which can't be played on the board at all, and the first
+    /// rule each of them breaks.
+    pub unplayable: Vec<(&'word str, Violation)>,
+    /// Every chain of the candidates which solves the board, fewest words
+    /// first.
+    pub solutions: Vec<Solution<'word>>,
+    /// The chain covering the most letters with the fewest words, which is
+    /// the first solution if there are any.
+    pub best: Solution<'word>,
+    /// The letters `best` doesn't cover, which is empty if the board can be
+    /// solved.
+    pub missing: BTreeSet<char>,
+}
+
+impl LetterBoxed {
+    /// Check whether any chain of `candidates` (in any case), e.g. a handful
+    /// of words spotted by the player, solves the board, and if not, which
+    /// letters the best chain is missing.
+    ///
+    /// Unlike solving with a full word list, this only ever plays the
+    /// candidates, each at most once and in any order. As when solving, every
+    /// word in a chain must cover a letter which the words before it don't.
+    pub fn combine<'word>(&self, candidates: &[&'word str]) -> Combination<'word> {
+        let mut unplayable = vec![];
+        let mut words: Vec<&'word str> = vec![];
+        for word in candidates {
+            if let Err(v) = self.check_word(word) {
+                unplayable.push((*word, v));
+            } else if !words.iter().any(|w| letters(w).eq(letters(word))) {
+                words.push(word);
+            }
+        }
+
+        let mut iter = self.solve_iter(&words, &[], words.len());
+        let solutions = iter.by_ref().collect::<Vec<_>>();
+        let best = match solutions.first() {
+            Some(first) => first.clone(),
+            None => iter.best_partial(),
+        };
+        let covered = best
+            .words
+            .iter()
+            .flat_map(|w| letters(w))
+            .collect::<BTreeSet<_>>();
+        let missing = self.letters.difference(&covered).copied().collect();
+
+        Combination {
+            unplayable,
+            solutions,
+            best,
+            missing,
+        }
+    }
+}
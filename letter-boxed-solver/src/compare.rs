@@ -0,0 +1,123 @@
+use core::fmt;
+
+use crate::fewest_letters::letters_drawn;
+use crate::prelude::*;
+use crate::{find_word, letters, result_limit, LetterBoxed, Violation};
+
+/// How a valid solution compares to the best achievable with a dictionary,
+/// as reported by [`LetterBoxed::compare_solution`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comparison<'word> {
+    /// The number of words in the solution.
+    pub words: usize,
+    /// A solution with the fewest words, or `None` if the dictionary has no
+    /// solution of at most `words` words (e.g. because the solution uses
+    /// words which aren't in it).
+    pub optimal: Option<Vec<&'word str>>,
+    /// The number of letters drawn to play the solution. See
+    /// [`crate::Solution::letters_drawn`].
+    pub letters_drawn: usize,
+    /// How many more letters were drawn than there are on the board, i.e.
+    /// how many letters were played more than once.
+    pub letters_wasted: usize,
+    /// Other solutions of at most `words` words which start with the same
+    /// word as the solution, shortest first.
+    pub alternatives: Vec<Vec<&'word str>>,
+}
+
+impl Comparison<'_> {
+    /// How many more words the solution used than it needed to.
+    pub fn word_delta(&self) -> usize {
+        self.optimal
+            .as_ref()
+            .map_or(0, |optimal| self.words - optimal.len())
+    }
+}
+
+impl fmt::Display for Comparison<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} words", self.words)?;
+        match &self.optimal {
+            Some(optimal) if self.word_delta() > 0 => write!(
+                f,
+                ", {} more than the best of {} ({})",
+                self.word_delta(),
+                optimal.len(),
+                optimal.join(" - ")
+            )?,
+            Some(_) => write!(f, ", as few as possible")?,
+            None => {}
+        }
+        write!(
+            f,
+            "; {} letters drawn, {} wasted",
+            self.letters_drawn, self.letters_wasted
+        )
+    }
+}
+
+impl LetterBoxed {
+    /// Compare a solution to the best achievable with the builtin word list.
+    /// See [`LetterBoxed::compare_solution`].
+    #[cfg(feature = "std")]
+    pub fn compare_solution_with_builtin_list(
+        &self,
+        solution: &[&str],
+        max_alternatives: usize,
+    ) -> Result<Comparison<'static>, Vec<Violation>> {
+        self.compare_solution(crate::builtin_words(), solution, max_alternatives)
+    }
+
+    /// Compare a solution (in any case) to the best achievable with `words`,
+    /// e.g. for a "how well did I do?" screen after a game: how many more
+    /// words it used than it needed to, how many letters it played more than
+    /// once, and up to `max_alternatives` (or, if it is 0, all) other
+    /// solutions starting with the same word.
+    ///
+    /// Returns every rule the solution breaks if it isn't valid and complete,
+    /// as in [`LetterBoxed::validate_detailed`]. The solution's words don't
+    /// have to be in `words`, but there are only alternatives if the first
+    /// one is.
+    pub fn compare_solution<'word>(
+        &self,
+        words: &[&'word str],
+        solution: &[&str],
+        max_alternatives: usize,
+    ) -> Result<Comparison<'word>, Vec<Violation>> {
+        let violations = self.validate_detailed(solution);
+        if !violations.is_empty() {
+            return Err(violations);
+        }
+
+        // There's no need to look for solutions longer than this one.
+        let optimal = self
+            .solve_iter(words, &[], solution.len())
+            .next()
+            .map(|s| s.words);
+
+        let alternatives = match solution.first().and_then(|w| find_word(words, w)) {
+            Some(first) => self
+                .solve_iter(words, &[first], solution.len())
+                .map(|s| s.words)
+                .filter(|alternative| {
+                    alternative.len() != solution.len()
+                        || alternative
+                            .iter()
+                            .zip(solution)
+                            .any(|(a, b)| !letters(a).eq(letters(b)))
+                })
+                .take(result_limit(max_alternatives))
+                .collect(),
+            None => vec![],
+        };
+
+        let letters_drawn = letters_drawn(solution.iter().copied());
+        Ok(Comparison {
+            words: solution.len(),
+            optimal,
+            letters_drawn,
+            letters_wasted: letters_drawn.saturating_sub(self.num_letters()),
+            alternatives,
+        })
+    }
+}
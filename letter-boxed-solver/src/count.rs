@@ -0,0 +1,140 @@
+use crate::collections::HashMap;
+use crate::prelude::*;
+
+use crate::search::{Context, State};
+use crate::{LetterBoxed, Mask};
+
+/// Counts complete solutions without building any of them.
+///
+/// Since the number of solutions from a state only depends on its current
+/// letter, the letters it has visited, and how many more words it may use,
+/// these counts are memoized rather than enumerating every path.
+pub(crate) struct Counter {
+    /// For each letter, the words which start with it, as the index of their
+    /// last letter and the letters they use.
+    edges: Vec<Vec<(usize, Mask)>>,
+    /// Every letter on the board.
+    full: Mask,
+    /// The index of each letter on the board.
+    index: HashMap<char, usize>,
+    max_depth: usize,
+    /// Solution counts, keyed by current letter, visited letters, and
+    /// remaining words, where the `k`th entry is the number of solutions
+    /// which use exactly `k` more words.
+    memo: HashMap<(usize, Mask, usize), Vec<usize>>,
+}
+
+impl Counter {
+    /// Build a counter for the search described by `ctx`, or `None` if each
+    /// letter may only be used once (which the masks can't express).
+    pub(crate) fn new(ctx: &Context<'_, '_>) -> Option<Self> {
+        let letters = &ctx.board.letters;
+        if ctx.board.rules().each_letter_once() {
+            return None;
+        }
+        let index = letters
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (*c, i))
+            .collect::<HashMap<_, _>>();
+
+        let mut edges = vec![vec![]; letters.len()];
+        for (first, options) in &ctx.graph {
+            for (last, word_indices) in options {
+                for idx in word_indices {
+                    edges[index[first]].push((index[last], ctx.word_masks[idx].clone()));
+                }
+            }
+        }
+
+        Some(Counter {
+            edges,
+            full: Mask::full(letters.len()),
+            index,
+            max_depth: ctx.max_depth,
+            memo: HashMap::new(),
+        })
+    }
+
+    /// Count the complete solutions reachable from `frontier`, where the
+    /// `k`th entry is the number of solutions with `k` words in total.
+    pub(crate) fn count(&mut self, frontier: &[State]) -> Vec<usize> {
+        let mut counts = vec![0; self.max_depth + 1];
+        for state in frontier {
+            let depth = state.path.len();
+            if depth > self.max_depth {
+                continue;
+            }
+            let cur = self.index[&state.cur];
+            let from_here = self.count_from(cur, &state.visited, self.max_depth - depth);
+            for (k, n) in from_here.into_iter().enumerate() {
+                counts[depth + k] += n;
+            }
+        }
+        counts
+    }
+
+    fn count_from(&mut self, cur: usize, mask: &Mask, remaining: usize) -> Vec<usize> {
+        let mut counts = vec![0; remaining + 1];
+        if *mask == self.full {
+            counts[0] = 1;
+            return counts;
+        }
+        if remaining == 0 {
+            return counts;
+        }
+        let key = (cur, mask.clone(), remaining);
+        if let Some(counts) = self.memo.get(&key) {
+            return counts.clone();
+        }
+
+        for i in 0..self.edges[cur].len() {
+            let (next, word) = &self.edges[cur][i];
+            // only consider routes that add a new letter to the visited set
+            if !word.is_subset(mask) {
+                let next_mask = mask.union(word);
+                let from_next = self.count_from(*next, &next_mask, remaining - 1);
+                for (k, n) in from_next.into_iter().enumerate() {
+                    counts[k + 1] += n;
+                }
+            }
+        }
+
+        self.memo.insert(key, counts.clone());
+        counts
+    }
+}
+
+impl LetterBoxed {
+    /// Count solutions using the builtin word list. See
+    /// [`LetterBoxed::count_solutions`].
+    ///
+    /// This will crash if an element in `prior_words` is not in the builtin
+    /// word list.
+    #[cfg(feature = "std")]
+    pub fn count_solutions_with_builtin_list(
+        &self,
+        prior_words: &[&str],
+        max_depth: usize,
+    ) -> Vec<usize> {
+        let words = crate::builtin_words();
+        let prior_words_indices = crate::builtin_indices(prior_words);
+        self.count_solutions(words, &prior_words_indices, max_depth)
+    }
+
+    /// Count the solutions of at most `max_depth` words (including the prior
+    /// words), without building any of them. The `k`th entry of the result is
+    /// the number of solutions with exactly `k` words.
+    ///
+    /// This counts exactly the solutions [`LetterBoxed::solve_iter`] would
+    /// yield, but is much faster and uses far less memory.
+    pub fn count_solutions(
+        &self,
+        words: &[&str],
+        prior_words_indices: &[usize],
+        max_depth: usize,
+    ) -> Vec<usize> {
+        let ctx = Context::new(self, words, max_depth);
+        ctx.count_by_length(ctx.initial_frontier(prior_words_indices))
+    }
+}
@@ -0,0 +1,76 @@
+use alloc::collections::BTreeSet;
+
+use crate::prelude::*;
+use crate::search::Context;
+use crate::{letters, LetterBoxed};
+
+/// Which letters a game in progress has covered, and which words would cover
+/// the rest, as reported by [`LetterBoxed::coverage`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coverage<'word> {
+    /// The letters used by the words played so far.
+    pub covered: BTreeSet<char>,
+    /// The letters which haven't been used yet.
+    pub remaining: BTreeSet<char>,
+    /// The words which can be played next, along with how many of the
+    /// `remaining` letters each of them covers. Sorted by most letters
+    /// covered, then alphabetically. Words which don't cover any remaining
+    /// letters are omitted.
+    pub words: Vec<(&'word str, usize)>,
+}
+
+impl LetterBoxed {
+    /// Analyze the coverage of a game in progress using the builtin word list.
+    /// See [`LetterBoxed::coverage`].
+    ///
+    /// This will crash if an element in `prior_words` is not in the builtin
+    /// word list.
+    #[cfg(feature = "std")]
+    pub fn coverage_with_builtin_list(&self, prior_words: &[&str]) -> Coverage<'static> {
+        let words = crate::builtin_words();
+        let prior_words_indices = crate::builtin_indices(prior_words);
+        self.coverage(words, &prior_words_indices)
+    }
+
+    /// Analyze the coverage of a game in progress: which letters have been
+    /// used by the words played so far, which remain, and which playable next
+    /// words cover the most remaining letters.
+    pub fn coverage<'word>(
+        &self,
+        words: &[&'word str],
+        prior_words_indices: &[usize],
+    ) -> Coverage<'word> {
+        let ctx = Context::new(self, words, usize::MAX);
+
+        let covered = prior_words_indices
+            .iter()
+            .flat_map(|idx| letters(words[*idx]))
+            .filter(|c| self.letters.contains(c))
+            .collect::<BTreeSet<_>>();
+        let remaining = self
+            .letters
+            .difference(&covered)
+            .copied()
+            .collect::<BTreeSet<_>>();
+
+        let mut next_words = ctx
+            .initial_frontier(prior_words_indices)
+            .iter()
+            .flat_map(|s| ctx.expand(s))
+            .map(|next| {
+                let idx = next.path.last().unwrap();
+                (
+                    words[idx],
+                    ctx.word_letters[&idx].intersection(&remaining).count(),
+                )
+            })
+            .collect::<Vec<_>>();
+        next_words.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+
+        Coverage {
+            covered,
+            remaining,
+            words: next_words,
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use crate::collections::HashSet;
+use crate::letters;
+use crate::prelude::*;
+use crate::rules::is_proper_noun;
+
+/// A word list loaded from user-provided text, e.g. `/usr/share/dict/words`,
+/// normalized into the form the solver expects.
+///
+/// Pass [`Dictionary::words`] to any method which takes a word list.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Dictionary {
+    words: Vec<String>,
+}
+
+/// Why [`Dictionary::clean`] dropped a word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DropReason {
+    /// The word contains something other than a letter, e.g. an apostrophe,
+    /// a hyphen, or a digit.
+    NonAlphabetic(char),
+    /// The word is the same as an earlier one, ignoring case and Unicode
+    /// normalization.
+    Duplicate,
+    /// The word is capitalized, marking it as a proper noun. See
+    /// [`Dictionary::clean_without_proper_nouns`].
+    ProperNoun,
+}
+
+/// A word which [`Dictionary::clean`] dropped, and why.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DroppedWord {
+    /// The word as it appeared in the input, without surrounding whitespace.
+    pub word: String,
+    /// Why it was dropped.
+    pub reason: DropReason,
+}
+
+impl Dictionary {
+    /// Load `words` as they are, without cleaning them. The solver will
+    /// misbehave if they aren't already uppercase letters without
+    /// duplicates; see [`Dictionary::clean`].
+    pub fn new(words: Vec<String>) -> Self {
+        Dictionary { words }
+    }
+
+    /// Clean up a raw word list with one word per line (or separated by any
+    /// whitespace): words are normalized to uppercase, and any word with a
+    /// character which isn't a letter or which repeats an earlier word is
+    /// dropped. Returns the dictionary along with every dropped word, in the
+    /// order they appeared.
+    pub fn clean(text: &str) -> (Dictionary, Vec<DroppedWord>) {
+        Self::clean_impl(text, false)
+    }
+
+    /// Clean up a raw word list as in [`Dictionary::clean`], but also drop
+    /// proper nouns, which the list flags by capitalizing them (e.g. `Paris`),
+    /// as the official puzzle doesn't allow them. See
+    /// [`crate::BoardRules::nyt`].
+    pub fn clean_without_proper_nouns(text: &str) -> (Dictionary, Vec<DroppedWord>) {
+        Self::clean_impl(text, true)
+    }
+
+    fn clean_impl(text: &str, drop_proper_nouns: bool) -> (Dictionary, Vec<DroppedWord>) {
+        let mut seen = HashSet::new();
+        let mut words = vec![];
+        let mut dropped = vec![];
+        for word in text.split_whitespace() {
+            let normalized = letters(word).collect::<String>();
+            let reason = match normalized.chars().find(|c| !c.is_alphabetic()) {
+                Some(c) => DropReason::NonAlphabetic(c),
+                None if drop_proper_nouns && is_proper_noun(word) => DropReason::ProperNoun,
+                None if !seen.insert(normalized.clone()) => DropReason::Duplicate,
+                None => {
+                    words.push(normalized);
+                    continue;
+                }
+            };
+            dropped.push(DroppedWord {
+                word: word.to_string(),
+                reason,
+            });
+        }
+        (Dictionary { words }, dropped)
+    }
+
+    /// The words, to pass to methods which take a word list.
+    pub fn words(&self) -> Vec<&str> {
+        self.words.iter().map(|w| w.as_str()).collect()
+    }
+
+    /// Take the words, e.g. to keep them in an
+    /// [`OwnedWordGraph`](crate::OwnedWordGraph).
+    pub(crate) fn into_words(self) -> Vec<String> {
+        self.words
+    }
+
+    /// The number of words.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Whether there are no words.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
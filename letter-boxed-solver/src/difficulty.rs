@@ -0,0 +1,101 @@
+use core::fmt;
+
+use crate::search::Context;
+use crate::LetterBoxed;
+
+/// A coarse difficulty label for a board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Difficulty::Easy => write!(f, "easy"),
+            Difficulty::Medium => write!(f, "medium"),
+            Difficulty::Hard => write!(f, "hard"),
+        }
+    }
+}
+
+/// An estimate of how hard a board is with a given dictionary, as reported
+/// by [`LetterBoxed::difficulty`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DifficultyReport {
+    /// The number of words which can be played on the board.
+    pub playable_words: usize,
+    /// The number of solutions using one or two words.
+    pub two_word_solutions: usize,
+    /// The board letter appearing in the fewest playable words, and how many
+    /// words that is. Boards where some letter can only be covered by a
+    /// handful of (often obscure) words are harder.
+    pub rarest_letter: Option<(char, usize)>,
+    /// The average number of playable words starting with each letter which
+    /// starts any word.
+    pub branching_factor: f64,
+    /// A score from 0 (trivial) to 1 (very hard), combining the above.
+    pub score: f64,
+    /// A label derived from `score`.
+    pub difficulty: Difficulty,
+}
+
+impl LetterBoxed {
+    /// Rate the difficulty of the board with the builtin word list. See
+    /// [`LetterBoxed::difficulty`].
+    #[cfg(feature = "std")]
+    pub fn difficulty_with_builtin_list(&self) -> DifficultyReport {
+        self.difficulty(crate::builtin_words())
+    }
+
+    /// Rate the difficulty of the board with `words`.
+    ///
+    /// The score averages three components, each scaled to lie between 0 and
+    /// 1, where fewer options mean a harder board: the number of two-word
+    /// solutions, the number of words covering the rarest letter, and the
+    /// branching factor. Scores below 0.35 are easy, and scores of 0.6 and
+    /// above are hard.
+    pub fn difficulty(&self, words: &[&str]) -> DifficultyReport {
+        let ctx = Context::new(self, words, 2);
+
+        let playable_words = ctx.word_letters.len();
+        let two_word_solutions = ctx.count_complete(ctx.initial_frontier(&[]));
+        let rarest_letter = self
+            .letters
+            .iter()
+            .map(|l| {
+                let count = ctx.word_letters.values().filter(|w| w.contains(l)).count();
+                (*l, count)
+            })
+            .min_by_key(|(_, count)| *count);
+        let branching_factor = if ctx.graph.is_empty() {
+            0.0
+        } else {
+            playable_words as f64 / ctx.graph.len() as f64
+        };
+
+        let scale = |n: f64| 1.0 / (1.0 + n / 10.0);
+        let score = (scale(two_word_solutions as f64)
+            + scale(rarest_letter.map_or(0, |(_, count)| count) as f64)
+            + scale(branching_factor))
+            / 3.0;
+        let difficulty = if score < 0.35 {
+            Difficulty::Easy
+        } else if score < 0.6 {
+            Difficulty::Medium
+        } else {
+            Difficulty::Hard
+        };
+
+        DifficultyReport {
+            playable_words,
+            two_word_solutions,
+            rarest_letter,
+            branching_factor,
+            score,
+            difficulty,
+        }
+    }
+}
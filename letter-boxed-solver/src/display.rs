@@ -0,0 +1,76 @@
+use alloc::collections::BTreeSet;
+use core::fmt;
+
+use crate::prelude::*;
+use crate::{letters, LetterBoxed};
+
+/// Renders the board as in the puzzle, e.g.
+///
+/// ```text
+///   E L Z
+/// I       C
+/// V       T
+/// A       H
+///   R Y U
+/// ```
+///
+/// where the sides are, in order, the top, left, bottom, and right sides.
+/// Boards which aren't square are rendered with one side per line.
+impl fmt::Display for LetterBoxed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [top, left, bottom, right] = match self.sides.as_slice() {
+            [a, b, c, d] => [a, b, c, d].map(|s| s.chars().collect::<Vec<_>>()),
+            _ => {
+                for (idx, side) in self.sides.iter().enumerate() {
+                    if idx > 0 {
+                        writeln!(f)?;
+                    }
+                    write!(f, "side {}: {side}", idx + 1)?;
+                }
+                return Ok(());
+            }
+        };
+
+        let width = top.len().max(bottom.len());
+        let height = left.len().max(right.len());
+
+        let horizontal = |f: &mut fmt::Formatter<'_>, side: &[char]| {
+            write!(f, " ")?;
+            for c in side {
+                write!(f, " {c}")?;
+            }
+            Ok(())
+        };
+
+        horizontal(f, &top)?;
+        for row in 0..height {
+            writeln!(f)?;
+            let l = left.get(row).copied().unwrap_or(' ');
+            match right.get(row) {
+                Some(r) => write!(f, "{l}{:width$}{r}", "", width = 2 * width + 1)?,
+                None => write!(f, "{l}")?,
+            }
+        }
+        writeln!(f)?;
+        horizontal(f, &bottom)
+    }
+}
+
+impl LetterBoxed {
+    /// Render the board as in its [`Display`](fmt::Display) implementation,
+    /// with `solution` written underneath along with how many letters it
+    /// covers.
+    pub fn render_solution(&self, solution: &[&str]) -> String {
+        let covered = solution
+            .iter()
+            .flat_map(|w| letters(w))
+            .filter(|c| self.letters.contains(c))
+            .collect::<BTreeSet<_>>();
+        format!(
+            "{self}\n\n{} ({}/{} letters)",
+            solution.join(" - "),
+            covered.len(),
+            self.letters.len()
+        )
+    }
+}
@@ -0,0 +1,73 @@
+use alloc::collections::BTreeSet;
+use core::cmp::Reverse;
+use core::fmt::Write;
+
+use crate::prelude::*;
+use crate::{result_limit, LetterBoxed, WordGraph};
+
+impl WordGraph<'_, '_> {
+    /// Render the graph as [Graphviz DOT](https://graphviz.org/doc/info/lang.html),
+    /// e.g. to pipe into `dot -Tsvg`, with a node for each letter, clustered
+    /// by side, and an edge from each start letter to each end letter labelled
+    /// with the words which bridge them.
+    ///
+    /// Only the `top_k` (or, if it is 0, all) words per edge are listed,
+    /// preferring those which use the most distinct letters, then the longest,
+    /// and then the order of the original word list; the edge notes how many
+    /// more there are. Letters no word starts or ends with are left without
+    /// edges, which helps to see why a board has no short solutions.
+    pub fn to_dot(&self, top_k: usize) -> String {
+        let mut dot = String::from("digraph {\n");
+        let mut seen = BTreeSet::new();
+        for (side, letters) in self.board.sides().enumerate() {
+            let _ = writeln!(dot, "    subgraph cluster_{side} {{");
+            let _ = writeln!(dot, "        label=\"{}\";", self.board.side_name(side));
+            // A letter on more than one side is only drawn once.
+            for c in letters.chars().filter(|c| seen.insert(*c)) {
+                let _ = writeln!(dot, "        \"{c}\";");
+            }
+            dot.push_str("    }\n");
+        }
+
+        for (start, ends) in &self.graph {
+            for (end, indices) in ends {
+                let mut indices = indices.iter().copied().collect::<Vec<_>>();
+                indices.sort_by_key(|idx| {
+                    (
+                        Reverse(self.word_letters[idx].len()),
+                        Reverse(self.words[*idx].len()),
+                        *idx,
+                    )
+                });
+                let shown = result_limit(top_k).min(indices.len());
+                let mut label = indices[..shown]
+                    .iter()
+                    .map(|idx| self.words[*idx])
+                    .collect::<Vec<_>>()
+                    .join("\\n");
+                if shown < indices.len() {
+                    let _ = write!(label, "\\n(+{} more)", indices.len() - shown);
+                }
+                let _ = writeln!(dot, "    \"{start}\" -> \"{end}\" [label=\"{label}\"];");
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl LetterBoxed {
+    /// Render the word graph of the builtin word list as DOT. See
+    /// [`LetterBoxed::word_graph_dot`].
+    #[cfg(feature = "std")]
+    pub fn word_graph_dot_with_builtin_list(&self, top_k: usize) -> String {
+        self.word_graph_dot(crate::builtin_words(), top_k)
+    }
+
+    /// Render the start-letter to end-letter graph of the playable words in
+    /// `words` as Graphviz DOT, listing at most `top_k` (or, if it is 0, all)
+    /// words per edge. See [`WordGraph::to_dot`].
+    pub fn word_graph_dot(&self, words: &[&str], top_k: usize) -> String {
+        WordGraph::new(self, words).to_dot(top_k)
+    }
+}
@@ -0,0 +1,66 @@
+use core::fmt;
+
+/// Errors which can occur when constructing a board.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BoardError {
+    /// A board needs at least two sides for any word to be playable.
+    TooFewSides {
+        /// The number of sides which were provided.
+        found: usize,
+    },
+    /// A side (zero-indexed) has no letters on it.
+    EmptySide {
+        /// The index of the empty side.
+        side: usize,
+    },
+    /// A letter appears more than once on the board.
+    DuplicateLetter {
+        /// The repeated letter.
+        letter: char,
+        /// The index of the side the letter first appears on.
+        first_side: usize,
+        /// The index of the side the letter appears on again. This may be the
+        /// same as `first_side`.
+        second_side: usize,
+    },
+    /// A side contains something other than a letter.
+    NonAlphabetic {
+        /// The offending character.
+        character: char,
+        /// The index of the side containing the character.
+        side: usize,
+    },
+}
+
+impl fmt::Display for BoardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BoardError::TooFewSides { found } => {
+                write!(f, "a board needs at least 2 sides, but {found} were given")
+            }
+            BoardError::EmptySide { side } => write!(f, "side {} is empty", side + 1),
+            BoardError::DuplicateLetter {
+                letter,
+                first_side,
+                second_side,
+            } if first_side == second_side => {
+                write!(f, "{letter} appears twice on side {}", first_side + 1)
+            }
+            BoardError::DuplicateLetter {
+                letter,
+                first_side,
+                second_side,
+            } => write!(
+                f,
+                "{letter} appears on both side {} and side {}",
+                first_side + 1,
+                second_side + 1
+            ),
+            BoardError::NonAlphabetic { character, side } => {
+                write!(f, "{character:?} on side {} is not a letter", side + 1)
+            }
+        }
+    }
+}
+
+impl core::error::Error for BoardError {}
@@ -0,0 +1,78 @@
+use core::fmt;
+
+use crate::prelude::*;
+use crate::{LetterBoxed, Position};
+
+/// A letter drawn while playing a solution, as reported by
+/// [`LetterBoxed::explain`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    /// The letter drawn.
+    pub letter: char,
+    /// Where the letter is on the board.
+    pub position: Position,
+    /// The name of the side the letter is on. See [`LetterBoxed::side_name`].
+    pub side_name: String,
+}
+
+/// How a solution is drawn on the board, letter by letter, e.g. to show a new
+/// player why a chain of words is legal.
+///
+/// Displays as the letters with their sides, e.g.
+/// `V(left)→E(top)→H(right)→…`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    /// The letters in the order they are drawn. The letter shared by the end
+    /// of one word and the start of the next is only drawn once, and each
+    /// letter can legally follow the one before it.
+    pub steps: Vec<Step>,
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, step) in self.steps.iter().enumerate() {
+            if idx > 0 {
+                write!(f, "→")?;
+            }
+            write!(f, "{}({})", step.letter, step.side_name)?;
+        }
+        Ok(())
+    }
+}
+
+impl LetterBoxed {
+    /// A name for the side at `side`. On a square board these are `top`,
+    /// `left`, `bottom` and `right`, in the order the board is
+    /// [displayed](fmt::Display); on other boards they are numbered from
+    /// `side 1`.
+    pub fn side_name(&self, side: usize) -> String {
+        match (self.sides.len(), side) {
+            (4, 0) => "top".to_string(),
+            (4, 1) => "left".to_string(),
+            (4, 2) => "bottom".to_string(),
+            (4, 3) => "right".to_string(),
+            _ => format!("side {}", side + 1),
+        }
+    }
+
+    /// Explain how `solution` is drawn on the board: which side each letter
+    /// is on, in the order they are drawn. Every transition between letters
+    /// is checked against the board's rules, e.g. that it moves to another
+    /// side, so this returns `None` under the same conditions as
+    /// [`LetterBoxed::trace`]. Words are case-insensitive.
+    pub fn explain(&self, solution: &[&str]) -> Option<Explanation> {
+        let steps = self
+            .trace(solution)?
+            .into_iter()
+            .map(|position| Step {
+                letter: self.sides[position.side]
+                    .chars()
+                    .nth(position.index)
+                    .expect("traced positions are on the board"),
+                position,
+                side_name: self.side_name(position.side),
+            })
+            .collect();
+        Some(Explanation { steps })
+    }
+}
@@ -0,0 +1,108 @@
+use alloc::borrow::Cow;
+
+use crate::prelude::*;
+use crate::search::Context;
+use crate::{letters, LetterBoxed, Solution, WordGraph};
+
+impl Solution<'_> {
+    /// The number of letters drawn to play the solution, where the letter
+    /// shared by the end of one word and the start of the next is only drawn
+    /// once. A "perfect" solution draws each letter on the board exactly once.
+    pub fn letters_drawn(&self) -> usize {
+        letters_drawn(self.words.iter().copied())
+    }
+}
+
+pub(crate) fn letters_drawn<'w>(words: impl Iterator<Item = &'w str>) -> usize {
+    let mut drawn = 0;
+    for (i, word) in words.enumerate() {
+        drawn += letters(word).count() - usize::from(i > 0);
+    }
+    drawn
+}
+
+impl<'word> WordGraph<'_, 'word> {
+    /// Find the solutions which draw the fewest letters as in
+    /// [`LetterBoxed::solve_fewest_letters`], reusing this graph.
+    pub fn solve_fewest_letters(
+        &self,
+        prior_words_indices: &[usize],
+        max_depth: usize,
+    ) -> Vec<Solution<'word>> {
+        let ctx = Context::with_graph(Cow::Borrowed(self), max_depth);
+        let num_letters = self.board.letters.len();
+        // Starting from a bare letter draws just that letter.
+        let prior_drawn = letters_drawn(prior_words_indices.iter().map(|i| self.words[*i])).max(1);
+
+        let mut best = usize::MAX;
+        let mut solutions = vec![];
+
+        // Search depth-first, tracking the letters drawn along each path so
+        // that paths can be abandoned once they can't beat the best solution.
+        let mut stack = ctx
+            .initial_frontier(prior_words_indices)
+            .into_iter()
+            .map(|s| (s, prior_drawn))
+            .rev()
+            .collect::<Vec<_>>();
+        while let Some((state, drawn)) = stack.pop() {
+            // Every letter not yet covered has to be drawn at least once.
+            if drawn + (num_letters - state.letters_covered()) > best {
+                continue;
+            }
+            if ctx.is_complete(&state) {
+                if drawn < best {
+                    best = drawn;
+                    solutions.clear();
+                }
+                solutions.push(ctx.solution(&state.path.to_vec(), state.letters_covered()));
+                continue;
+            }
+            for next in ctx.expand(&state).into_iter().rev() {
+                // The first letter of the word was already drawn.
+                let word = self.words[next.path.last().unwrap()];
+                stack.push((next, drawn + letters(word).count() - 1));
+            }
+        }
+
+        // Shorter solutions first, as the other solvers return them.
+        solutions.sort_by_key(|s| s.words.len());
+        solutions
+    }
+}
+
+impl LetterBoxed {
+    /// Find the solutions which draw the fewest letters using the builtin
+    /// word list. See [`LetterBoxed::solve_fewest_letters`].
+    ///
+    /// This will crash if an element in `prior_words` is not in the builtin
+    /// word list.
+    #[cfg(feature = "std")]
+    pub fn solve_fewest_letters_with_builtin_list(
+        &self,
+        prior_words: &[&str],
+        max_depth: usize,
+    ) -> Vec<Solution<'static>> {
+        let words = crate::builtin_words();
+        let prior_words_indices = crate::builtin_indices(prior_words);
+        self.solve_fewest_letters(words, &prior_words_indices, max_depth)
+    }
+
+    /// Find every solution of at most `max_depth` words (including the prior
+    /// words) which draws the fewest letters in total, as counted by
+    /// [`Solution::letters_drawn`]. If that's the number of letters on the
+    /// board, these are "perfect" solutions which never reuse a letter.
+    ///
+    /// The search abandons any path which can't beat the best solution found
+    /// so far, but still has to consider solutions of every length up to
+    /// `max_depth`, since a longer solution may draw fewer letters.
+    pub fn solve_fewest_letters<'word>(
+        &self,
+        words: &[&'word str],
+        prior_words_indices: &[usize],
+        max_depth: usize,
+    ) -> Vec<Solution<'word>> {
+        self.word_graph(words)
+            .solve_fewest_letters(prior_words_indices, max_depth)
+    }
+}
@@ -0,0 +1,71 @@
+#[cfg(feature = "builtin-frequencies")]
+use std::sync::OnceLock;
+
+use crate::collections::HashMap;
+use crate::letters;
+use crate::prelude::*;
+
+/// A table of word frequency ranks, used to prefer common words over obscure
+/// ones. Rank 0 is the most common word.
+///
+/// Words are matched ignoring case and Unicode normalization differences.
+#[derive(Debug, Clone, Default)]
+pub struct FrequencyTable {
+    ranks: HashMap<String, usize>,
+}
+
+impl FrequencyTable {
+    /// Build a table from words ordered from most to least common. If a word
+    /// appears more than once, its first (most common) rank is kept.
+    pub fn from_ranked_words<'a>(words: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut ranks = HashMap::new();
+        for (rank, word) in words.into_iter().enumerate() {
+            ranks.entry(letters(word).collect()).or_insert(rank);
+        }
+        FrequencyTable { ranks }
+    }
+
+    /// Build a table from text with one word per line, ordered from most to
+    /// least common. Blank lines are ignored.
+    pub fn from_ranked_list(text: &str) -> Self {
+        Self::from_ranked_words(text.lines().filter(|l| !l.trim().is_empty()))
+    }
+
+    /// Frequencies for the builtin word list, derived from the English
+    /// Wikipedia word frequency list shipped with zxcvbn (MIT licensed).
+    /// Only the most common ~15,000 words are ranked.
+    #[cfg(feature = "builtin-frequencies")]
+    pub fn builtin() -> &'static FrequencyTable {
+        static TABLE: OnceLock<FrequencyTable> = OnceLock::new();
+        TABLE.get_or_init(|| FrequencyTable::from_ranked_list(FREQUENCIES))
+    }
+
+    /// The rank of `word`, or `None` if it isn't in the table.
+    pub fn rank(&self, word: &str) -> Option<usize> {
+        self.ranks.get(&letters(word).collect::<String>()).copied()
+    }
+
+    /// The number of ranked words.
+    pub fn len(&self) -> usize {
+        self.ranks.len()
+    }
+
+    /// Whether the table is empty.
+    pub fn is_empty(&self) -> bool {
+        self.ranks.is_empty()
+    }
+
+    /// Restrict `words` to those with a rank of at most `max_rank`, keeping
+    /// their order. The result can be passed to [`crate::LetterBoxed::solve`]
+    /// to only find solutions made of common words.
+    pub fn common_words<'word>(&self, words: &[&'word str], max_rank: usize) -> Vec<&'word str> {
+        words
+            .iter()
+            .filter(|w| self.rank(w).is_some_and(|r| r <= max_rank))
+            .copied()
+            .collect()
+    }
+}
+
+#[cfg(feature = "builtin-frequencies")]
+const FREQUENCIES: &str = include_str!("frequencies.txt");
@@ -0,0 +1,363 @@
+//! Random generation of solvable boards.
+
+use alloc::collections::BTreeSet;
+
+use crate::prelude::*;
+use crate::{letters, LetterBoxed};
+
+/// Approximate relative frequencies of letters in English words, used to
+/// pick letters which are likely to make a playable board.
+const LETTER_WEIGHTS: [(char, u32); 26] = [
+    ('A', 85),
+    ('B', 21),
+    ('C', 45),
+    ('D', 34),
+    ('E', 112),
+    ('F', 18),
+    ('G', 25),
+    ('H', 30),
+    ('I', 75),
+    ('J', 2),
+    ('K', 11),
+    ('L', 55),
+    ('M', 30),
+    ('N', 67),
+    ('O', 72),
+    ('P', 32),
+    ('Q', 2),
+    ('R', 76),
+    ('S', 57),
+    ('T', 70),
+    ('U', 36),
+    ('V', 10),
+    ('W', 13),
+    ('X', 3),
+    ('Y', 18),
+    ('Z', 3),
+];
+
+/// A small, deterministic pseudo-random number generator (SplitMix64), so that
+/// a given seed always produces the same board regardless of platform or
+/// dependency versions.
+#[derive(Debug, Clone)]
+pub(crate) struct Rng(u64);
+
+impl Rng {
+    pub(crate) fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`. `n` must be nonzero.
+    pub(crate) fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    pub(crate) fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Generates random boards which are guaranteed to be solvable, optionally
+/// with a shortest solution of an exact number of words.
+///
+/// Generation is reproducible: the same settings and seed always produce the
+/// same board.
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use letter_boxed_solver::generator::BoardGenerator;
+///
+/// let board = BoardGenerator::new().max_words(3).generate(42).unwrap();
+/// assert_eq!(board.sides().count(), 4);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BoardGenerator {
+    sides: usize,
+    letters_per_side: usize,
+    min_words: usize,
+    max_words: usize,
+    max_attempts: usize,
+}
+
+impl Default for BoardGenerator {
+    fn default() -> Self {
+        BoardGenerator {
+            sides: 4,
+            letters_per_side: 3,
+            min_words: 1,
+            max_words: 3,
+            max_attempts: 100,
+        }
+    }
+}
+
+impl BoardGenerator {
+    /// A generator for standard boards: four sides of three letters each,
+    /// solvable in at most three words.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of sides of generated boards.
+    pub fn sides(mut self, sides: usize) -> Self {
+        self.sides = sides;
+        self
+    }
+
+    /// Set the number of letters on each side of generated boards.
+    pub fn letters_per_side(mut self, letters_per_side: usize) -> Self {
+        self.letters_per_side = letters_per_side;
+        self
+    }
+
+    /// Set the maximum number of words a generated board must be solvable in.
+    pub fn max_words(mut self, max_words: usize) -> Self {
+        self.max_words = max_words;
+        self
+    }
+
+    /// Set the fewest words a generated board's shortest solution may have,
+    /// e.g. 2 to rule out boards with a one-word pangram.
+    pub fn min_words(mut self, min_words: usize) -> Self {
+        self.min_words = min_words;
+        self
+    }
+
+    /// Only generate boards whose shortest solution has exactly `words`
+    /// words, e.g. 2 for a board with no one-word pangram but at least one
+    /// two-word solution. This sets both [`BoardGenerator::min_words`] and
+    /// [`BoardGenerator::max_words`].
+    pub fn exact_words(self, words: usize) -> Self {
+        self.min_words(words).max_words(words)
+    }
+
+    /// Set how many candidate boards to try before giving up.
+    pub fn max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Generate a board which is solvable with the builtin word list.
+    ///
+    /// Returns `None` if no solvable board was found within the maximum
+    /// number of attempts, or if the board would need more than 26 letters.
+    #[cfg(feature = "std")]
+    pub fn generate(&self, seed: u64) -> Option<LetterBoxed> {
+        self.generate_with_words(seed, crate::builtin_words())
+    }
+
+    /// Generate a board which is solvable with `words`.
+    ///
+    /// Only letters A-Z are used. Returns `None` if no solvable board was
+    /// found within the maximum number of attempts, or if the board would
+    /// need more than 26 letters.
+    pub fn generate_with_words(&self, seed: u64, words: &[&str]) -> Option<LetterBoxed> {
+        let num_letters = self.sides * self.letters_per_side;
+        if self.sides < 2 || self.letters_per_side == 0 || num_letters > LETTER_WEIGHTS.len() {
+            return None;
+        }
+
+        let mut rng = Rng::new(seed);
+        for _ in 0..self.max_attempts {
+            let board = self.candidate(&mut rng, num_letters);
+            if board.is_solvable(words) && self.has_optimal_length(&board, words) {
+                return Some(board);
+            }
+        }
+        None
+    }
+
+    /// Generate a board on which all of `seed_words` are playable, and which is
+    /// solvable with the builtin word list. See
+    /// [`BoardGenerator::generate_from_seed_words_with_words`].
+    #[cfg(feature = "std")]
+    pub fn generate_from_seed_words(&self, seed: u64, seed_words: &[&str]) -> Option<LetterBoxed> {
+        self.generate_from_seed_words_with_words(seed, seed_words, crate::builtin_words())
+    }
+
+    /// Generate a board on which all of `seed_words` are playable, e.g. to
+    /// hide an intended answer like `["VEHICULAR", "RITZILY"]` in a custom
+    /// puzzle.
+    ///
+    /// If the seed words don't use every letter on the board, the remaining
+    /// letters are picked at random, and the board must also be solvable with
+    /// `words`. The seed words don't need to be in `words`.
+    ///
+    /// Returns `None` if the seed words use too many letters, or no side
+    /// assignment was found within the maximum number of attempts.
+    pub fn generate_from_seed_words_with_words(
+        &self,
+        seed: u64,
+        seed_words: &[&str],
+        words: &[&str],
+    ) -> Option<LetterBoxed> {
+        let num_letters = self.sides * self.letters_per_side;
+        if self.sides < 2 || self.letters_per_side == 0 {
+            return None;
+        }
+
+        // Every pair of consecutive letters in a seed word must end up on
+        // different sides.
+        let mut seed_letters = BTreeSet::new();
+        let mut edges = BTreeSet::new();
+        for word in seed_words {
+            let word = letters(word).collect::<Vec<_>>();
+            for pair in word.windows(2) {
+                if pair[0] == pair[1] {
+                    return None;
+                }
+                edges.insert((pair[0], pair[1]));
+                edges.insert((pair[1], pair[0]));
+            }
+            seed_letters.extend(word);
+        }
+        if seed_letters.len() > num_letters {
+            return None;
+        }
+
+        let mut rng = Rng::new(seed);
+        for _ in 0..self.max_attempts {
+            let mut letters = seed_letters.iter().copied().collect::<Vec<_>>();
+            let filler = self.pick_letters(&mut rng, num_letters - letters.len(), &seed_letters);
+            letters.extend(filler);
+
+            let Some(sides) = self.assign_sides(&mut rng, &letters, &edges) else {
+                continue;
+            };
+            let board =
+                LetterBoxed::load_board(&sides.iter().map(|s| s.as_str()).collect::<Vec<_>>());
+
+            let mut candidate_words = words.to_vec();
+            candidate_words.extend(seed_words);
+            if self.has_optimal_length(&board, &candidate_words) {
+                return Some(board);
+            }
+        }
+        None
+    }
+
+    /// Whether the shortest solution of `board` with `words` has between the
+    /// minimum and maximum number of words.
+    fn has_optimal_length(&self, board: &LetterBoxed, words: &[&str]) -> bool {
+        // Solutions are found in order of word count, so the first is the
+        // shortest.
+        board
+            .solve_iter(words, &[], self.max_words)
+            .next()
+            .is_some_and(|s| s.words.len() >= self.min_words)
+    }
+
+    /// Deal `letters` out onto the sides of a board such that no two letters
+    /// joined by an edge share a side, using a randomized backtracking search.
+    fn assign_sides(
+        &self,
+        rng: &mut Rng,
+        letters: &[char],
+        edges: &BTreeSet<(char, char)>,
+    ) -> Option<Vec<String>> {
+        fn place(
+            letters: &[char],
+            edges: &BTreeSet<(char, char)>,
+            order: &[usize],
+            capacity: usize,
+            sides: &mut Vec<Vec<char>>,
+        ) -> bool {
+            let Some((c, rest)) = letters.split_first() else {
+                return true;
+            };
+            for &side in order {
+                if sides[side].len() < capacity
+                    && sides[side]
+                        .iter()
+                        .all(|other| !edges.contains(&(*c, *other)))
+                {
+                    sides[side].push(*c);
+                    if place(rest, edges, order, capacity, sides) {
+                        return true;
+                    }
+                    sides[side].pop();
+                }
+            }
+            false
+        }
+
+        // Place the most constrained letters first.
+        let mut letters = letters.to_vec();
+        rng.shuffle(&mut letters);
+        letters.sort_by_key(|c| core::cmp::Reverse(edges.iter().filter(|(a, _)| a == c).count()));
+
+        let mut order = (0..self.sides).collect::<Vec<_>>();
+        rng.shuffle(&mut order);
+
+        let mut sides = vec![vec![]; self.sides];
+        if !place(&letters, edges, &order, self.letters_per_side, &mut sides) {
+            return None;
+        }
+        Some(
+            sides
+                .into_iter()
+                .map(|mut side| {
+                    rng.shuffle(&mut side);
+                    side.into_iter().collect()
+                })
+                .collect(),
+        )
+    }
+
+    /// Pick `num_letters` distinct letters, weighted by their frequency, and
+    /// deal them out onto the sides of a board.
+    fn candidate(&self, rng: &mut Rng, num_letters: usize) -> LetterBoxed {
+        let mut letters = self.pick_letters(rng, num_letters, &BTreeSet::new());
+        rng.shuffle(&mut letters);
+
+        let sides = letters
+            .chunks(self.letters_per_side)
+            .map(|side| side.iter().collect::<String>())
+            .collect::<Vec<_>>();
+        LetterBoxed::load_board(&sides.iter().map(|s| s.as_str()).collect::<Vec<_>>())
+    }
+
+    /// Pick `num_letters` distinct letters which aren't in `exclude`, weighted
+    /// by their frequency.
+    fn pick_letters(
+        &self,
+        rng: &mut Rng,
+        num_letters: usize,
+        exclude: &BTreeSet<char>,
+    ) -> Vec<char> {
+        let mut pool = LETTER_WEIGHTS
+            .iter()
+            .filter(|(c, _)| !exclude.contains(c))
+            .copied()
+            .collect::<Vec<_>>();
+        let mut letters = vec![];
+        while letters.len() < num_letters && !pool.is_empty() {
+            let total = pool.iter().map(|(_, w)| *w as usize).sum::<usize>();
+            let mut pick = rng.below(total);
+            let idx = pool
+                .iter()
+                .position(|(_, w)| {
+                    if pick < *w as usize {
+                        true
+                    } else {
+                        pick -= *w as usize;
+                        false
+                    }
+                })
+                .unwrap();
+            letters.push(pool.remove(idx).0);
+        }
+        letters
+    }
+}
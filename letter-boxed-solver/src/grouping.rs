@@ -0,0 +1,68 @@
+use core::fmt;
+
+use crate::prelude::*;
+
+/// Solutions which start with the same word, as returned by
+/// [`group_by_first_word`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolutionGroup<'word> {
+    /// The word every solution in the group starts with.
+    pub first_word: &'word str,
+    /// The positions of the group's solutions in the grouped results, in the
+    /// order they appeared there.
+    pub indices: Vec<usize>,
+}
+
+impl SolutionGroup<'_> {
+    /// The number of solutions in the group.
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Whether the group has no solutions. Groups returned by
+    /// [`group_by_first_word`] are never empty.
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+}
+
+impl fmt::Display for SolutionGroup<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} → {} {}",
+            self.first_word,
+            self.len(),
+            if self.len() == 1 {
+                "solution"
+            } else {
+                "solutions"
+            }
+        )
+    }
+}
+
+/// Group solutions, given as their words, by the word they start with, e.g.
+/// to show how many solutions each opening word leads to instead of a long
+/// list of near-duplicates. Groups are in the order their first solution
+/// appears, so they keep the order the solutions were found or ranked in.
+///
+/// Solutions with no words are skipped.
+pub fn group_by_first_word<'a, 'word: 'a>(
+    solutions: impl IntoIterator<Item = &'a [&'word str]>,
+) -> Vec<SolutionGroup<'word>> {
+    let mut groups: Vec<SolutionGroup<'word>> = vec![];
+    for (idx, words) in solutions.into_iter().enumerate() {
+        let Some(first_word) = words.first() else {
+            continue;
+        };
+        match groups.iter_mut().find(|g| g.first_word == *first_word) {
+            Some(group) => group.indices.push(idx),
+            None => groups.push(SolutionGroup {
+                first_word,
+                indices: vec![idx],
+            }),
+        }
+    }
+    groups
+}
@@ -0,0 +1,87 @@
+use alloc::collections::BTreeSet;
+
+use crate::prelude::*;
+use crate::search::Context;
+use crate::{letters, LetterBoxed};
+
+/// A candidate next word, as suggested by [`LetterBoxed::hint`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Hint<'word> {
+    /// The suggested word.
+    pub word: &'word str,
+    /// How many letters the word covers which haven't been used yet.
+    pub new_letters: usize,
+    /// How many complete solutions (within the maximum depth) start by
+    /// playing this word next.
+    pub completions: usize,
+}
+
+impl LetterBoxed {
+    /// Suggest the next word to play using the builtin word list. See
+    /// [`LetterBoxed::hint`].
+    ///
+    /// This will crash if an element in `prior_words` is not in the builtin
+    /// word list.
+    #[cfg(feature = "std")]
+    pub fn hint_with_builtin_list(
+        &self,
+        prior_words: &[&str],
+        max_depth: usize,
+    ) -> Vec<Hint<'static>> {
+        let words = crate::builtin_words();
+        let prior_words_indices = crate::builtin_indices(prior_words);
+        self.hint(words, &prior_words_indices, max_depth)
+    }
+
+    /// Suggest the next word to play, given the words which have already
+    /// been played, without revealing an entire solution.
+    ///
+    /// Candidates are ranked by how many complete solutions of at most
+    /// `max_depth` words (including the prior words) they lead to, and then by
+    /// how many new letters they cover.
+    pub fn hint<'word>(
+        &self,
+        words: &[&'word str],
+        prior_words_indices: &[usize],
+        max_depth: usize,
+    ) -> Vec<Hint<'word>> {
+        let ctx = Context::new(self, words, max_depth);
+        hints(&ctx, prior_words_indices)
+    }
+}
+
+/// Suggest the next word to play in the search described by `ctx`. See
+/// [`LetterBoxed::hint`].
+pub(crate) fn hints<'word>(
+    ctx: &Context<'_, 'word>,
+    prior_words_indices: &[usize],
+) -> Vec<Hint<'word>> {
+    let words = &ctx.words;
+
+    let prior_visited = prior_words_indices
+        .iter()
+        .flat_map(|idx| letters(words[*idx]))
+        .collect::<BTreeSet<_>>();
+
+    let mut hints = ctx
+        .initial_frontier(prior_words_indices)
+        .iter()
+        .flat_map(|s| ctx.expand(s))
+        .map(|next| {
+            let idx = next.path.last().unwrap();
+            Hint {
+                word: words[idx],
+                new_letters: ctx.word_letters[&idx].difference(&prior_visited).count(),
+                completions: ctx.count_complete(vec![next]),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    hints.sort_by(|a, b| {
+        b.completions
+            .cmp(&a.completions)
+            .then(b.new_letters.cmp(&a.new_letters))
+            .then(a.word.cmp(b.word))
+    });
+    hints
+}
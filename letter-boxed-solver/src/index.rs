@@ -0,0 +1,251 @@
+use alloc::borrow::Cow;
+use alloc::collections::BTreeSet;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+
+use crate::prelude::*;
+use crate::{letters, LetterBoxed, WordGraph};
+
+/// The most distinct letters a [`DictionaryIndex`] tracks in its letter
+/// masks. Letters beyond these are still checked, just without the fast path.
+const MASK_LETTERS: usize = u128::BITS as usize;
+
+/// The start of every [serialized](DictionaryIndex::to_bytes) index, naming
+/// the version of the format.
+const MAGIC: &[u8] = b"letter-boxed index v1\n";
+
+/// Errors which can occur when loading a [serialized](DictionaryIndex::to_bytes)
+/// index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IndexError {
+    /// The data isn't an index, or is from an incompatible version.
+    BadHeader,
+    /// The data ends partway through the index.
+    Truncated,
+    /// A word or its letters aren't valid UTF-8.
+    InvalidUtf8,
+}
+
+impl fmt::Display for IndexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IndexError::BadHeader => write!(f, "not a dictionary index, or the wrong version"),
+            IndexError::Truncated => write!(f, "the dictionary index is truncated"),
+            IndexError::InvalidUtf8 => write!(f, "the dictionary index has invalid UTF-8"),
+        }
+    }
+}
+
+impl core::error::Error for IndexError {}
+
+/// A word list prepared once for compiling [word graphs](WordGraph) of many
+/// boards, e.g. to sweep an archive of puzzles.
+///
+/// Compiling a word list for a board mostly normalizes each word's letters,
+/// which doesn't depend on the board, so the index does it up front. It also
+/// keeps a mask of the letters in each word, which lets most words be ruled
+/// out for a board with a single comparison.
+///
+/// An index can be [saved](DictionaryIndex::to_bytes) and
+/// [loaded](DictionaryIndex::from_bytes) again, so that a large word list
+/// doesn't have to be normalized at every start up.
+#[derive(Debug, Clone)]
+pub struct DictionaryIndex<'a, 'word> {
+    words: Cow<'a, [&'word str]>,
+    /// The normalized letters of each word.
+    letters: Vec<Vec<char>>,
+    /// The letters given a bit in the masks, in order.
+    alphabet: Vec<char>,
+    /// The letters of each word which are in `alphabet`, as a bitmask.
+    masks: Vec<u128>,
+}
+
+impl<'a, 'word> DictionaryIndex<'a, 'word> {
+    /// Prepare `words` for compiling many boards.
+    pub fn new(words: &'a [&'word str]) -> Self {
+        let letters = words
+            .iter()
+            .map(|w| letters(w).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let alphabet = letters
+            .iter()
+            .flatten()
+            .copied()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .take(MASK_LETTERS)
+            .collect::<Vec<_>>();
+        let index = DictionaryIndex {
+            words: Cow::Borrowed(words),
+            masks: vec![],
+            alphabet,
+            letters,
+        };
+        let masks = index.letters.iter().map(|w| index.mask(w)).collect();
+        DictionaryIndex { masks, ..index }
+    }
+
+    /// The word list this index was prepared from.
+    pub fn words(&self) -> &[&'word str] {
+        &self.words
+    }
+
+    /// Compile the word list for `board`, as [`WordGraph::new`] would.
+    pub fn word_graph<'s>(&'s self, board: &'s LetterBoxed) -> WordGraph<'s, 'word> {
+        let mut graph = WordGraph::empty(board, &self.words);
+        let counts = board.letter_counts();
+        let board_mask = self.mask(board.letters.iter());
+        for (i, (w, mask)) in self.letters.iter().zip(&self.masks).enumerate() {
+            if mask & !board_mask == 0 {
+                graph.add_word(i, w, &counts);
+            }
+        }
+        graph
+    }
+
+    /// Solve each of `boards` as in [`LetterBoxed::solve`], with no prior
+    /// words, returning their solutions in the same order.
+    ///
+    /// With the `rayon` feature enabled, the boards are solved in parallel.
+    pub fn solve_batch(
+        &self,
+        boards: &[LetterBoxed],
+        max_depth: usize,
+        max_results: usize,
+    ) -> Vec<Vec<(Vec<&'word str>, usize)>> {
+        let solve = |board| self.word_graph(board).solve(&[], max_depth, max_results);
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+            boards.par_iter().map(solve).collect()
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            boards.iter().map(solve).collect()
+        }
+    }
+
+    /// Serialize the index, e.g. to save it to a file and load it again with
+    /// [`DictionaryIndex::from_bytes`].
+    pub fn to_bytes(&self) -> Vec<u8> {
+        fn write_str(bytes: &mut Vec<u8>, s: &str) {
+            bytes.extend((s.len() as u32).to_le_bytes());
+            bytes.extend(s.as_bytes());
+        }
+
+        let mut bytes = MAGIC.to_vec();
+        write_str(&mut bytes, &self.alphabet.iter().collect::<String>());
+        bytes.extend((self.words.len() as u32).to_le_bytes());
+        for ((word, letters), mask) in self.words.iter().zip(&self.letters).zip(&self.masks) {
+            write_str(&mut bytes, word);
+            write_str(&mut bytes, &letters.iter().collect::<String>());
+            bytes.extend(mask.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// The bits of `letters` which are in the alphabet.
+    fn mask<'c>(&self, letters: impl IntoIterator<Item = &'c char>) -> u128 {
+        letters
+            .into_iter()
+            .filter_map(|c| self.alphabet.binary_search(c).ok())
+            .fold(0, |mask, bit| mask | 1 << bit)
+    }
+}
+
+impl<'a> DictionaryIndex<'a, 'a> {
+    /// Load an index saved by [`DictionaryIndex::to_bytes`]. The words are
+    /// borrowed from `bytes` rather than copied.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, IndexError> {
+        let mut reader = Reader {
+            rest: bytes.strip_prefix(MAGIC).ok_or(IndexError::BadHeader)?,
+        };
+        let alphabet = reader.str()?.chars().collect();
+        let count = reader.u32()? as usize;
+        // Every word takes more than a byte, so a corrupt count can't make
+        // this allocate more than the data would need.
+        let capacity = count.min(reader.rest.len());
+        let mut words = Vec::with_capacity(capacity);
+        let mut letters = Vec::with_capacity(capacity);
+        let mut masks = Vec::with_capacity(capacity);
+        for _ in 0..count {
+            words.push(reader.str()?);
+            letters.push(reader.str()?.chars().collect());
+            masks.push(u128::from_le_bytes(reader.array()?));
+        }
+
+        Ok(DictionaryIndex {
+            words: Cow::Owned(words),
+            letters,
+            alphabet,
+            masks,
+        })
+    }
+}
+
+/// Reads the fields of a serialized index in turn.
+struct Reader<'a> {
+    rest: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], IndexError> {
+        let (taken, rest) = self.rest.split_first_chunk().ok_or(IndexError::Truncated)?;
+        self.rest = rest;
+        Ok(*taken)
+    }
+
+    fn u32(&mut self) -> Result<u32, IndexError> {
+        self.array().map(u32::from_le_bytes)
+    }
+
+    /// A string written with its length in bytes first.
+    fn str(&mut self) -> Result<&'a str, IndexError> {
+        let len = self.u32()? as usize;
+        if self.rest.len() < len {
+            return Err(IndexError::Truncated);
+        }
+        let (taken, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        core::str::from_utf8(taken).map_err(|_| IndexError::InvalidUtf8)
+    }
+}
+
+#[cfg(feature = "std")]
+impl DictionaryIndex<'static, 'static> {
+    /// The index of the builtin word list, prepared on first use.
+    pub fn builtin() -> &'static Self {
+        static INDEX: OnceLock<DictionaryIndex<'static, 'static>> = OnceLock::new();
+        INDEX.get_or_init(|| DictionaryIndex::new(crate::builtin_words()))
+    }
+}
+
+impl LetterBoxed {
+    /// Solve many boards with the builtin word list. See
+    /// [`LetterBoxed::solve_batch`].
+    #[cfg(feature = "std")]
+    pub fn solve_batch_with_builtin_list(
+        boards: &[LetterBoxed],
+        max_depth: usize,
+        max_results: usize,
+    ) -> Vec<Vec<(Vec<&'static str>, usize)>> {
+        DictionaryIndex::builtin().solve_batch(boards, max_depth, max_results)
+    }
+
+    /// Solve each of `boards` with `words` as in [`LetterBoxed::solve`],
+    /// returning their solutions in the same order. The word list is only
+    /// normalized once, rather than for every board; to reuse it across
+    /// batches, build a [`DictionaryIndex`] and call
+    /// [`DictionaryIndex::solve_batch`].
+    ///
+    /// With the `rayon` feature enabled, the boards are solved in parallel.
+    pub fn solve_batch<'word>(
+        boards: &[LetterBoxed],
+        words: &[&'word str],
+        max_depth: usize,
+        max_results: usize,
+    ) -> Vec<Vec<(Vec<&'word str>, usize)>> {
+        DictionaryIndex::new(words).solve_batch(boards, max_depth, max_results)
+    }
+}
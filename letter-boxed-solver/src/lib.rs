@@ -1,5 +1,133 @@
-use std::collections::{BTreeMap, BTreeSet, HashSet, VecDeque};
-use std::sync::OnceLock;
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::sync::Arc;
+use core::ops::ControlFlow;
+use core::str::FromStr;
+use core::sync::atomic::AtomicBool;
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+use unicode_normalization::UnicodeNormalization;
+
+use collections::HashSet;
+use mask::Mask;
+use prelude::*;
+use search::Context;
+
+/// Emit a `tracing` event at `$level` with the `tracing` feature, and do
+/// nothing otherwise.
+macro_rules! trace_event {
+    ($level:ident, $($arg:tt)*) => {
+        #[cfg(feature = "tracing")]
+        tracing::$level!($($arg)*);
+    };
+}
+
+mod analytics;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "archive")]
+pub mod archive;
+#[cfg(feature = "capi")]
+pub mod capi;
+mod code;
+mod combine;
+mod compare;
+mod count;
+mod coverage;
+mod dictionary;
+mod difficulty;
+mod display;
+mod dot;
+mod error;
+mod explain;
+mod fewest_letters;
+mod frequency;
+pub mod generator;
+mod grouping;
+mod hint;
+mod index;
+mod mask;
+mod metadata;
+mod mutation;
+#[cfg(feature = "nyt")]
+pub mod nyt;
+mod opening;
+mod options;
+mod pangram;
+mod par;
+mod partial;
+mod playable;
+mod position;
+mod ranking;
+#[cfg(feature = "render")]
+mod render;
+mod rules;
+mod search;
+#[cfg(feature = "serde")]
+mod serialize;
+mod session;
+mod solvability;
+#[cfg(feature = "async")]
+mod stream;
+mod two_words;
+mod validate;
+mod word_graph;
+#[cfg(feature = "std")]
+mod word_list;
+
+/// The `alloc` items which the `std` prelude provides, for `no_std` builds.
+mod prelude {
+    pub(crate) use alloc::boxed::Box;
+    pub(crate) use alloc::string::{String, ToString};
+    pub(crate) use alloc::vec::Vec;
+    pub(crate) use alloc::{format, vec};
+}
+
+/// Hash maps and sets with the `std` feature, or B-tree ones without it,
+/// where there's no source of randomness to seed hashing.
+mod collections {
+    #[cfg(not(feature = "std"))]
+    pub(crate) use alloc::collections::{
+        btree_map as hash_map, BTreeMap as HashMap, BTreeSet as HashSet,
+    };
+    #[cfg(feature = "std")]
+    pub(crate) use std::collections::{hash_map, HashMap, HashSet};
+}
+
+pub use analytics::DictionaryAnalytics;
+pub use combine::Combination;
+pub use compare::Comparison;
+pub use coverage::Coverage;
+pub use dictionary::{Dictionary, DropReason, DroppedWord};
+pub use difficulty::{Difficulty, DifficultyReport};
+pub use error::BoardError;
+pub use explain::{Explanation, Step};
+pub use frequency::FrequencyTable;
+pub use grouping::{group_by_first_word, SolutionGroup};
+pub use hint::Hint;
+pub use index::{DictionaryIndex, IndexError};
+pub use metadata::BoardMetadata;
+pub use mutation::{Change, Mutation, MutationReport};
+pub use opening::Opener;
+pub use options::SolveOptions;
+pub use par::{ParRating, ParReport};
+pub use playable::WordOrder;
+pub use position::Position;
+pub use ranking::Ranking;
+pub use rules::{Adjacency, BoardRules};
+pub use search::{OwnedSolution, Progress, SearchStrategy, Solution, SolutionIter, Statistics};
+pub use session::SolverSession;
+pub use solvability::SolvabilityReport;
+#[cfg(feature = "async")]
+pub use stream::SolutionStream;
+pub use validate::{GameState, Violation};
+pub use word_graph::{OwnedWordGraph, WordGraph};
+#[cfg(feature = "std")]
+pub use word_list::WordList;
 
 /// A basic solver for the New York Times "Letter Boxed" puzzle.
 ///
@@ -8,6 +136,9 @@ use std::sync::OnceLock;
 /// square to another, and the first letter of the next word must match the last
 /// letter of the current word.
 ///
+/// Boards don't have to be square: any polygon with two or more sides (e.g. a
+/// triangle or a hexagon) follows the same rules.
+///
 /// The goal of the game is to use all of the letters with the fewest number of
 /// words.
 ///
@@ -21,47 +152,160 @@ use std::sync::OnceLock;
 ///
 /// has a valid solution of "VEHICULAR" followed by "RITZILY".
 ///
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LetterBoxed {
+    /// The sides of the board, in the order they were provided.
+    sides: Vec<String>,
     /// Letters which are not permitted to be adjacent to one another
     nonadjacent: HashSet<(char, char)>,
     /// For convenience, all of the letters that are in the puzzle.
     letters: BTreeSet<char>,
+    /// The shortest word which may be played.
+    min_word_length: usize,
+    /// The rules of the variant being played.
+    rules: BoardRules,
+    /// Where the board came from, if known.
+    metadata: BoardMetadata,
 }
 
+/// The shortest word which may be played in the official puzzle.
+pub const DEFAULT_MIN_WORD_LENGTH: usize = 3;
+
 impl LetterBoxed {
     /// Load the board. For simplicity, just take a slice representing each side.
-    /// Order does not matter.
+    /// Order does not matter, and there may be any number of sides. Letters
+    /// are case-insensitive.
+    ///
+    /// Unlike [`LetterBoxed::try_load_board`], a letter may appear on more
+    /// than one side, as in some variants of the puzzle. Such a letter may be
+    /// played at any of its positions, so it can be followed by any letter
+    /// (including itself) which isn't only on the same single side.
     pub fn load_board(sides: &[&str]) -> LetterBoxed {
-        let mut nonadjacent = HashSet::new();
-
-        for side in sides {
-            for c in side.chars() {
-                for cc in side.chars() {
-                    nonadjacent.insert((c, cc));
-                }
-            }
-        }
+        let sides = sides
+            .iter()
+            .map(|s| letters(s).collect::<String>())
+            .collect::<Vec<_>>();
+        let rules = BoardRules::default();
+        let nonadjacent = rules.nonadjacent(&sides);
         let letters = sides
             .iter()
             .flat_map(|s| s.chars())
             .collect::<BTreeSet<char>>();
 
         LetterBoxed {
+            sides,
             nonadjacent,
             letters,
+            min_word_length: DEFAULT_MIN_WORD_LENGTH,
+            rules,
+            metadata: BoardMetadata::default(),
+        }
+    }
+
+    /// Play this board under variant `rules` instead of the official ones.
+    /// This applies both when solving and when validating solutions.
+    pub fn with_rules(mut self, rules: BoardRules) -> Self {
+        self.nonadjacent = rules.nonadjacent(&self.sides);
+        self.rules = rules;
+        self
+    }
+
+    /// The rules this board is played under.
+    pub fn rules(&self) -> &BoardRules {
+        &self.rules
+    }
+
+    /// Only allow words with at least `min_word_length` letters, instead of
+    /// [`DEFAULT_MIN_WORD_LENGTH`]. This applies both when solving and when
+    /// validating solutions.
+    pub fn with_min_word_length(mut self, min_word_length: usize) -> Self {
+        self.min_word_length = min_word_length;
+        self
+    }
+
+    /// The shortest word which may be played on this board.
+    pub fn min_word_length(&self) -> usize {
+        self.min_word_length
+    }
+
+    /// Which of `letters` are on the board, as a [`Mask`].
+    pub(crate) fn mask(&self, letters: impl IntoIterator<Item = char>) -> Mask {
+        let mut mask = Mask::default();
+        for bit in letters
+            .into_iter()
+            .filter_map(|c| self.letters.iter().position(|l| *l == c))
+        {
+            mask.insert(bit);
         }
+        mask
     }
 
-    /// Validate that a given solution is correct on this board.
+    /// Load the board, checking that it is well-formed: there must be at least
+    /// two sides, every side must be non-empty, every character must be a
+    /// letter, and no letter may appear more than once.
+    pub fn try_load_board(sides: &[&str]) -> Result<LetterBoxed, BoardError> {
+        if sides.len() < 2 {
+            return Err(BoardError::TooFewSides { found: sides.len() });
+        }
+
+        let mut seen = BTreeMap::new();
+        for (idx, side) in sides.iter().enumerate() {
+            if side.trim().is_empty() {
+                return Err(BoardError::EmptySide { side: idx });
+            }
+            for c in letters(side) {
+                if !c.is_alphabetic() {
+                    return Err(BoardError::NonAlphabetic {
+                        character: c,
+                        side: idx,
+                    });
+                }
+                if let Some(first_side) = seen.insert(c, idx) {
+                    return Err(BoardError::DuplicateLetter {
+                        letter: c,
+                        first_side,
+                        second_side: idx,
+                    });
+                }
+            }
+        }
+
+        Ok(LetterBoxed::load_board(sides))
+    }
+
+    /// The sides of the board, in the order they were loaded.
+    pub fn sides(&self) -> impl Iterator<Item = &str> {
+        self.sides.iter().map(|s| s.as_str())
+    }
+
+    /// The total number of letters on the board.
+    pub fn num_letters(&self) -> usize {
+        self.letters.len()
+    }
+
+    /// Validate that a given solution is correct on this board. Words are
+    /// case-insensitive.
+    ///
+    /// This only checks that the words chain together, that they are long
+    /// enough, that no word is repeated (unless
+    /// [`BoardRules::with_repeated_words`] allows it), that letters alternate
+    /// sides, and (under [`BoardRules::with_each_letter_once`]) that no
+    /// letter is reused; see [`LetterBoxed::validate_detailed`] for a full
+    /// report, including whether every letter is covered.
     pub fn validate(&self, solution: &[&str]) -> bool {
         for window in solution.windows(2) {
-            if window[0].chars().last() != window[1].chars().next() {
+            if letters(window[0]).last() != letters(window[1]).next() {
                 return false;
             }
         }
-        for word in solution {
-            let mut iter = word.chars();
+        for (idx, word) in solution.iter().enumerate() {
+            if letters(word).count() < self.min_word_length {
+                return false;
+            }
+            if !self.rules.repeated_words() && rules::earlier_copy(solution, idx).is_some() {
+                return false;
+            }
+            let mut iter = letters(word);
             let mut current = iter.next();
 
             for next in iter {
@@ -75,38 +319,169 @@ impl LetterBoxed {
                 }
             }
         }
-        true
+        !self.rules.each_letter_once() || self.reused_letters(solution).0.is_empty()
     }
 
     /// Solve using a built-in hardcoded word list, where all solutions will not
     /// exceed `max_depth` in length.
     ///
     /// The solver prefers shorter solutions to longer solutions, and will
-    /// return up to `max_results` solutions.
+    /// return up to `max_results` solutions, or every solution if
+    /// `max_results` is 0.
     ///
-    /// `prior_words` are words (all-caps) which have already been played. This
-    /// will crash if an element in `prior_words` is not in the builtin word list.
+    /// `prior_words` are words (in any case) which have already been played.
+    /// This will crash if an element in `prior_words` is not in the builtin
+    /// word list.
+    #[cfg(feature = "std")]
     pub fn solve_with_builtin_list(
         &self,
         prior_words: &[&str],
         max_depth: usize,
         max_results: usize,
     ) -> Vec<(Vec<&'static str>, usize)> {
-        static WORDS_LIST: OnceLock<Vec<&'static str>> = OnceLock::new();
-        let words = WORDS_LIST.get_or_init(|| WORDS.lines().map(|w| w.trim()).collect::<Vec<_>>());
-        let mut prior_words_indices = vec![];
-        for w in prior_words {
-            let idx = words.iter().position(|ww| ww == w).unwrap();
-            prior_words_indices.push(idx);
-        }
+        let words = crate::builtin_words();
+        let prior_words_indices = crate::builtin_indices(prior_words);
         self.solve(words, &prior_words_indices, max_depth, max_results)
     }
 
+    /// Solve using a provided word list, e.g. a non-English dictionary, where
+    /// all solutions will not exceed `max_depth` in length.
+    ///
+    /// Unlike [`LetterBoxed::solve`], `prior_words` are given as words rather
+    /// than indices, and are matched against `words` ignoring case and Unicode
+    /// normalization. Returns `None` if any of `prior_words` is not in `words`.
+    pub fn solve_with_word_list<'word>(
+        &self,
+        words: &[&'word str],
+        prior_words: &[&str],
+        max_depth: usize,
+        max_results: usize,
+    ) -> Option<Vec<(Vec<&'word str>, usize)>> {
+        let prior_words_indices = prior_words
+            .iter()
+            .map(|w| find_word(words, w))
+            .collect::<Option<Vec<_>>>()?;
+        Some(self.solve(words, &prior_words_indices, max_depth, max_results))
+    }
+
+    /// Solve using the built-in hardcoded word list as in
+    /// [`LetterBoxed::solve_with_builtin_list`], but never use any of the
+    /// words in `exclude`.
+    ///
+    /// This will crash if an element in `prior_words` is not in the builtin
+    /// word list.
+    #[cfg(feature = "std")]
+    #[deprecated(note = "use LetterBoxed::solve_with_options_with_builtin_list")]
+    pub fn solve_excluding_with_builtin_list(
+        &self,
+        prior_words: &[&str],
+        exclude: &[&str],
+        max_depth: usize,
+        max_results: usize,
+    ) -> Vec<(Vec<&'static str>, usize)> {
+        let options = SolveOptions::new()
+            .prior_words_indices(&crate::builtin_indices(prior_words))
+            .excluded(exclude)
+            .max_depth(max_depth)
+            .max_results(max_results);
+        self.solve_as_pairs(crate::builtin_words(), &options)
+    }
+
+    /// Solve using the built-in hardcoded word list as in
+    /// [`LetterBoxed::solve_with_builtin_list`], but return at most
+    /// `max_per_start_word` solutions starting with each word.
+    ///
+    /// This will crash if an element in `prior_words` is not in the builtin
+    /// word list.
+    #[cfg(feature = "std")]
+    #[deprecated(note = "use LetterBoxed::solve_with_options_with_builtin_list")]
+    pub fn solve_varied_with_builtin_list(
+        &self,
+        prior_words: &[&str],
+        max_depth: usize,
+        max_results: usize,
+        max_per_start_word: usize,
+    ) -> Vec<(Vec<&'static str>, usize)> {
+        let options = SolveOptions::new()
+            .prior_words_indices(&crate::builtin_indices(prior_words))
+            .max_depth(max_depth)
+            .max_results(max_results)
+            .max_per_start_word(max_per_start_word);
+        self.solve_as_pairs(crate::builtin_words(), &options)
+    }
+
+    /// Solve using the built-in hardcoded word list as in
+    /// [`LetterBoxed::solve_with_builtin_list`], but only return solutions
+    /// which use `required` somewhere.
+    ///
+    /// This will crash if an element in `prior_words` is not in the builtin
+    /// word list.
+    #[cfg(feature = "std")]
+    #[deprecated(note = "use LetterBoxed::solve_with_options_with_builtin_list")]
+    pub fn solve_requiring_with_builtin_list(
+        &self,
+        prior_words: &[&str],
+        required: &str,
+        max_depth: usize,
+        max_results: usize,
+    ) -> Vec<(Vec<&'static str>, usize)> {
+        let options = SolveOptions::new()
+            .prior_words_indices(&crate::builtin_indices(prior_words))
+            .required(required)
+            .max_depth(max_depth)
+            .max_results(max_results);
+        self.solve_as_pairs(crate::builtin_words(), &options)
+    }
+
+    /// Find every solution with the fewest possible words using the built-in
+    /// hardcoded word list. See [`LetterBoxed::solve_all_minimal`].
+    ///
+    /// This will crash if an element in `prior_words` is not in the builtin
+    /// word list.
+    #[cfg(feature = "std")]
+    pub fn solve_all_minimal_with_builtin_list(
+        &self,
+        prior_words: &[&str],
+        max_depth: usize,
+    ) -> Vec<Solution<'static>> {
+        let words = crate::builtin_words();
+        let prior_words_indices = crate::builtin_indices(prior_words);
+        self.solve_all_minimal(words, &prior_words_indices, max_depth)
+    }
+
+    /// Lazily solve using the built-in hardcoded word list. See
+    /// [`LetterBoxed::solve_iter`].
+    ///
+    /// This will crash if an element in `prior_words` is not in the builtin
+    /// word list.
+    #[cfg(feature = "std")]
+    pub fn solve_iter_with_builtin_list(
+        &self,
+        prior_words: &[&str],
+        max_depth: usize,
+    ) -> SolutionIter<'_, 'static> {
+        let words = crate::builtin_words();
+        let prior_words_indices = crate::builtin_indices(prior_words);
+        self.solve_iter(words, &prior_words_indices, max_depth)
+    }
+
     /// Solve using a provided word list, where all solutions will not
     /// exceed `max_depth` in length.
     ///
     /// `prior_words_indices` should correspond to any words that have already
     /// been played, represented as indices into `words`.
+    ///
+    /// At most `max_results` solutions are returned, or every solution if it
+    /// is 0. The search stops as soon as there are enough, so use
+    /// [`LetterBoxed::solve_iter`] to keep searching after collecting them.
+    ///
+    /// If there are no complete solutions, this returns the best partial
+    /// solution found instead, whose score is the number of letters it
+    /// covers. See [`LetterBoxed::solve_partial`] to find partial solutions
+    /// explicitly.
+    ///
+    /// With the `rayon` feature enabled, each level of the search is expanded
+    /// in parallel.
     pub fn solve<'word>(
         &self,
         words: &[&'word str],
@@ -114,154 +489,382 @@ impl LetterBoxed {
         max_depth: usize,
         max_results: usize,
     ) -> Vec<(Vec<&'word str>, usize)> {
-        let mut results = vec![];
-
-        // The graph maps from a start-letter to an end-letter, with each
-        // possible word that bridges them according to the board as a potential
-        // route.
-        //
-        // In the above example board (replicated here)
-        //
-        //   E L Z
-        // I       C
-        // V       T
-        // A       H
-        //   R Y U
-        //
-        // This would include an entry 'V' -> 'R' {..., "VEHICULAR", ...}
-        let mut graph: BTreeMap<char, BTreeMap<char, BTreeSet<usize>>> = BTreeMap::new();
-
-        'outer: for (i, w) in words.iter().enumerate() {
-            let w = w.trim();
-            // Eliminate words that are too short, and those which contain
-            // letters not on the board at all
-            if w.len() < 3 || w.chars().any(|c| !self.letters.contains(&c)) {
-                continue;
-            }
+        self.word_graph(words)
+            .solve(prior_words_indices, max_depth, max_results)
+    }
 
-            let mut c_iter = w.chars();
-            let first_char = c_iter.next().unwrap();
-            let mut current_char = first_char;
+    /// Solve using a provided word list as in [`LetterBoxed::solve`], but
+    /// never use any of the words in `exclude`, e.g. words which the official
+    /// game rejects. These are matched ignoring case and Unicode
+    /// normalization.
+    #[deprecated(note = "use LetterBoxed::solve_with_options")]
+    pub fn solve_excluding<'word>(
+        &self,
+        words: &[&'word str],
+        prior_words_indices: &[usize],
+        exclude: &[&str],
+        max_depth: usize,
+        max_results: usize,
+    ) -> Vec<(Vec<&'word str>, usize)> {
+        let options = SolveOptions::new()
+            .prior_words_indices(prior_words_indices)
+            .excluded(exclude)
+            .max_depth(max_depth)
+            .max_results(max_results);
+        self.solve_as_pairs(words, &options)
+    }
 
-            // Check that adjacent characters are not in the known-nonadjacent set.
-            for c in c_iter {
-                let c = c;
-                if self.nonadjacent.contains(&(current_char, c)) {
-                    continue 'outer;
-                }
-                current_char = c;
-            }
+    /// Solve using a provided word list as in [`LetterBoxed::solve`], but
+    /// only return solutions which use `required` somewhere. It is matched
+    /// ignoring case and Unicode normalization.
+    ///
+    /// If no solution uses `required`, this returns the best partial solution
+    /// found, which may not use it either.
+    #[deprecated(note = "use LetterBoxed::solve_with_options")]
+    pub fn solve_requiring<'word>(
+        &self,
+        words: &[&'word str],
+        prior_words_indices: &[usize],
+        required: &str,
+        max_depth: usize,
+        max_results: usize,
+    ) -> Vec<(Vec<&'word str>, usize)> {
+        let options = SolveOptions::new()
+            .prior_words_indices(prior_words_indices)
+            .required(required)
+            .max_depth(max_depth)
+            .max_results(max_results);
+        self.solve_as_pairs(words, &options)
+    }
 
-            let options = graph.entry(first_char).or_default();
-            options.entry(current_char).or_default().insert(i);
-        }
+    /// Solve using a provided word list as in [`LetterBoxed::solve`], but
+    /// return at most `max_per_start_word` solutions starting with each word
+    /// (after the prior words), so that the results show a variety of
+    /// openings rather than many variations on the same one. See
+    /// [`SolutionIter::with_max_per_start_word`].
+    #[deprecated(note = "use LetterBoxed::solve_with_options")]
+    pub fn solve_varied<'word>(
+        &self,
+        words: &[&'word str],
+        prior_words_indices: &[usize],
+        max_depth: usize,
+        max_results: usize,
+        max_per_start_word: usize,
+    ) -> Vec<(Vec<&'word str>, usize)> {
+        let options = SolveOptions::new()
+            .prior_words_indices(prior_words_indices)
+            .max_depth(max_depth)
+            .max_results(max_results)
+            .max_per_start_word(max_per_start_word);
+        self.solve_as_pairs(words, &options)
+    }
 
-        /// State for the word-search.
-        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-        struct State {
-            /// The current letter we are starting from
-            cur: char,
-            /// All the letters we've visited on this path
-            visited: BTreeSet<char>,
-            path: Vec<usize>,
-        }
+    /// Solve using a provided word list as in [`LetterBoxed::solve`], but
+    /// stop searching once `deadline` passes, returning whatever solutions
+    /// were found by then (or the best partial solution, if there were none).
+    ///
+    /// Note that `Instant` is not available on `wasm32-unknown-unknown`.
+    #[cfg(feature = "std")]
+    #[deprecated(note = "use LetterBoxed::solve_with_options")]
+    pub fn solve_until<'word>(
+        &self,
+        words: &[&'word str],
+        prior_words_indices: &[usize],
+        max_depth: usize,
+        max_results: usize,
+        deadline: Instant,
+    ) -> Vec<(Vec<&'word str>, usize)> {
+        let options = SolveOptions::new()
+            .prior_words_indices(prior_words_indices)
+            .max_depth(max_depth)
+            .max_results(max_results)
+            .deadline(deadline);
+        self.solve_as_pairs(words, &options)
+    }
 
-        let mut q = VecDeque::new();
+    /// Solve using a provided word list as in [`LetterBoxed::solve`], but
+    /// stop searching once `cancel` is set to `true`, returning whatever
+    /// solutions were found by then (or the best partial solution, if there
+    /// were none). See [`SolutionIter::with_cancellation`].
+    #[deprecated(note = "use LetterBoxed::solve_with_options")]
+    pub fn solve_cancellable<'word>(
+        &self,
+        words: &[&'word str],
+        prior_words_indices: &[usize],
+        max_depth: usize,
+        max_results: usize,
+        cancel: Arc<AtomicBool>,
+    ) -> Vec<(Vec<&'word str>, usize)> {
+        let options = SolveOptions::new()
+            .prior_words_indices(prior_words_indices)
+            .max_depth(max_depth)
+            .max_results(max_results)
+            .cancellation(cancel);
+        self.solve_as_pairs(words, &options)
+    }
 
-        let mut best = (0, vec![]);
+    /// Solve using a provided word list as in [`LetterBoxed::solve`], calling
+    /// `progress` periodically with statistics about the search.
+    #[deprecated(note = "use LetterBoxed::solve_iter_with_options and SolutionIter::with_progress")]
+    pub fn solve_with_progress<'word>(
+        &self,
+        words: &[&'word str],
+        prior_words_indices: &[usize],
+        max_depth: usize,
+        max_results: usize,
+        progress: impl FnMut(&Progress),
+    ) -> Vec<(Vec<&'word str>, usize)> {
+        let options = SolveOptions::new()
+            .prior_words_indices(prior_words_indices)
+            .max_depth(max_depth)
+            .max_results(max_results);
+        let mut iter = self
+            .solve_iter_with_options(words, &options)
+            .expect("prior words are given as indices")
+            .with_progress(progress);
+        solution_pairs(options.collect(&mut iter))
+    }
 
-        if prior_words_indices.is_empty() {
-            // Preload the queue at each possible start location
-            for k in graph.keys() {
-                let mut visited = BTreeSet::new();
-                visited.insert(*k);
-                q.push_back(State {
-                    cur: *k,
-                    visited,
-                    path: vec![],
-                })
-            }
-        } else {
-            let last_c = words[prior_words_indices[prior_words_indices.len() - 1]]
-                .chars()
-                .last()
-                .unwrap();
-            let mut visited = BTreeSet::new();
-
-            for idx in prior_words_indices {
-                visited.extend(words[*idx].chars());
-            }
+    /// Find every solution with the fewest possible words, up to `max_depth`
+    /// words, with no limit on the number of results. This is empty if there
+    /// is no solution within `max_depth` words.
+    ///
+    /// `prior_words_indices` should correspond to any words that have already
+    /// been played, represented as indices into `words`.
+    pub fn solve_all_minimal<'word>(
+        &self,
+        words: &[&'word str],
+        prior_words_indices: &[usize],
+        max_depth: usize,
+    ) -> Vec<Solution<'word>> {
+        let mut iter = self.solve_iter(words, prior_words_indices, max_depth);
+        let Some(first) = iter.next() else {
+            return vec![];
+        };
+        // Solutions are found in order of word count, so we can stop at the
+        // first longer one.
+        let len = first.words.len();
+        core::iter::once(first)
+            .chain(iter.take_while(|s| s.words.len() == len))
+            .collect()
+    }
 
-            q.push_back(State {
-                cur: last_c,
-                visited,
-                path: prior_words_indices.to_vec(),
-            })
-        }
+    /// Solve using a provided word list as in [`LetterBoxed::solve`], also
+    /// returning statistics about the search.
+    #[deprecated(note = "use LetterBoxed::solve_iter_with_options and SolutionIter::statistics")]
+    pub fn solve_with_statistics<'word>(
+        &self,
+        words: &[&'word str],
+        prior_words_indices: &[usize],
+        max_depth: usize,
+        max_results: usize,
+    ) -> (Vec<(Vec<&'word str>, usize)>, Statistics) {
+        let options = SolveOptions::new()
+            .prior_words_indices(prior_words_indices)
+            .max_depth(max_depth)
+            .max_results(max_results);
+        let mut iter = self
+            .solve_iter_with_options(words, &options)
+            .expect("prior words are given as indices");
+        let results = solution_pairs(options.collect(&mut iter));
+        (results, iter.statistics())
+    }
 
-        while let Some(state) = q.pop_front() {
-            // Keep track of the best-available solution, since we might not
-            // find one with the given max_depth.
-            if state.visited.len() > best.0
-                || (state.visited.len() == best.0 && state.path.len() < best.1.len())
-            {
-                best = (state.visited.len(), state.path.clone());
-            }
+    /// Solve using a provided word list, calling `on_solution` with each
+    /// solution as soon as the search finds it, rather than collecting them
+    /// all first. The search stops once `on_solution` returns
+    /// [`ControlFlow::Break`], whose value is then returned.
+    ///
+    /// Solutions are found in the same order as [`LetterBoxed::solve_iter`]
+    /// yields them, so shorter solutions come first.
+    pub fn solve_with<'word, B>(
+        &self,
+        words: &[&'word str],
+        prior_words_indices: &[usize],
+        max_depth: usize,
+        on_solution: impl FnMut(Solution<'word>) -> ControlFlow<B>,
+    ) -> ControlFlow<B> {
+        self.solve_iter(words, prior_words_indices, max_depth)
+            .try_for_each(on_solution)
+    }
 
-            // Check if we're done!
-            if state.visited == self.letters {
-                results.push((state.path.clone(), self.letters.len()));
+    /// Lazily solve using a provided word list, where all solutions will not
+    /// exceed `max_depth` in length.
+    ///
+    /// Solutions are yielded in the order the search discovers them, so
+    /// shorter solutions come first. Every word in a solution must cover a
+    /// letter which the words before it (including `prior_words_indices`)
+    /// don't, so no word is ever played twice. Nothing is searched until the
+    /// iterator is advanced, and dropping it stops the search.
+    ///
+    /// `prior_words_indices` should correspond to any words that have already
+    /// been played, represented as indices into `words`.
+    pub fn solve_iter<'a, 'word>(
+        &'a self,
+        words: &'a [&'word str],
+        prior_words_indices: &[usize],
+        max_depth: usize,
+    ) -> SolutionIter<'a, 'word> {
+        SolutionIter::new(Context::new(self, words, max_depth), prior_words_indices)
+    }
 
-                if results.len() >= max_results {
-                    break;
-                }
-            } else if let Some(options) = graph.get(&state.cur) {
-                if state.path.len() + 1 > max_depth {
-                    continue;
-                }
-                // Go through all the potential end-letters
-                for (next_letter, word_indices) in options {
-                    // and all the paths to get there
-                    for idx in word_indices {
-                        let w = words[*idx];
-                        // only consider routes that add a new word to the visited set
-                        if w.chars().any(|c| !state.visited.contains(&c)) {
-                            let mut v = state.visited.clone();
-                            v.extend(w.chars());
-
-                            let mut new_path = state.path.clone();
-                            new_path.push(*idx);
-
-                            let new_state = State {
-                                cur: *next_letter,
-                                visited: v,
-                                path: new_path,
-                            };
-
-                            q.push_back(new_state);
-                        }
-                    }
+    /// Solve with `options`, whose prior words are given as indices, as
+    /// `(words, letters covered)` pairs for the functions which predate
+    /// [`SolveOptions`].
+    fn solve_as_pairs<'word>(
+        &self,
+        words: &[&'word str],
+        options: &SolveOptions<'_>,
+    ) -> Vec<(Vec<&'word str>, usize)> {
+        let solutions = self
+            .solve_with_options(words, options)
+            .expect("prior words are given as indices");
+        solution_pairs(solutions)
+    }
+}
+
+/// Parse a board from a compact string such as `"ELZ-IVA-RYU-CTH"`, where
+/// sides are separated by `-`, `/`, or whitespace. The board is checked as in
+/// [`LetterBoxed::try_load_board`].
+impl FromStr for LetterBoxed {
+    type Err = BoardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut sides = vec![];
+        if !s.trim().is_empty() {
+            for part in s.split(['-', '/']) {
+                let part = part.trim();
+                if part.is_empty() {
+                    return Err(BoardError::EmptySide { side: sides.len() });
                 }
+                sides.extend(part.split_whitespace());
             }
         }
 
-        // if we couldn't find any complete results, add the best one we found to the output.
-        if results.is_empty() {
-            results.push((best.1, best.0));
-        }
+        LetterBoxed::try_load_board(&sides)
+    }
+}
 
-        results
-            .into_iter()
-            .map(|(idxes, c)| (idxes.into_iter().map(|idx| words[idx]).collect(), c))
-            .collect()
+/// The number of results to return for a `max_results` argument, where 0
+/// means there is no limit.
+pub(crate) fn result_limit(max_results: usize) -> usize {
+    if max_results == 0 {
+        usize::MAX
+    } else {
+        max_results
+    }
+}
+
+/// Collect up to `max_results` solutions from a search, or every solution if
+/// it is 0. The search stops as soon as there are enough.
+pub(crate) fn collect_results<'word>(
+    iter: &mut SolutionIter<'_, 'word>,
+    max_results: usize,
+) -> Vec<(Vec<&'word str>, usize)> {
+    let mut results = iter
+        .by_ref()
+        .take(result_limit(max_results))
+        .collect::<Vec<_>>();
+
+    // if we couldn't find any complete results, add the best one we found to the output.
+    if results.is_empty() {
+        results.push(iter.best_partial());
+    }
+
+    solution_pairs(results)
+}
+
+/// Convert solutions to the `(words, letters covered)` pairs returned by
+/// [`LetterBoxed::solve`].
+pub(crate) fn solution_pairs(solutions: Vec<Solution<'_>>) -> Vec<(Vec<&str>, usize)> {
+    solutions
+        .into_iter()
+        .map(|s| (s.words, s.letters_covered))
+        .collect()
+}
+
+/// The builtin word list, split into lines.
+#[cfg(feature = "std")]
+pub(crate) fn builtin_words() -> &'static [&'static str] {
+    WordList::default().words()
+}
+
+/// Look up the indices of `prior_words` in the builtin word list.
+#[cfg(feature = "std")]
+pub(crate) fn builtin_indices(prior_words: &[&str]) -> Vec<usize> {
+    let words = crate::builtin_words();
+    prior_words
+        .iter()
+        .map(|w| find_word(words, w).unwrap())
+        .collect()
+}
+
+/// Normalize a letter so that comparisons are case-insensitive.
+pub(crate) fn normalize(c: char) -> char {
+    fn single(mut chars: impl Iterator<Item = char>) -> Option<char> {
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(c),
+            _ => None,
+        }
     }
+
+    // Some letters (e.g. 'ß') don't have a single-character uppercase form,
+    // so they are left in lowercase. Going through lowercase first means that
+    // their uppercase forms (e.g. 'ẞ') end up in the same place.
+    let lower = single(c.to_lowercase()).unwrap_or(c);
+    single(lower.to_uppercase()).unwrap_or(lower)
 }
 
-const WORDS: &str = include_str!("words.txt");
+/// The normalized letters of a word or side, ignoring surrounding whitespace.
+///
+/// The text is converted to Unicode normalization form C first, so that e.g.
+/// 'Ñ' is a single letter regardless of whether it was typed as one code
+/// point or as 'N' followed by a combining tilde.
+pub(crate) fn letters(word: &str) -> impl Iterator<Item = char> + '_ {
+    word.trim().nfc().map(normalize)
+}
+
+/// Find the index of `word` in `words`, ignoring case and Unicode
+/// normalization differences, the same way the solver matches prior,
+/// excluded and required words.
+pub fn find_word(words: &[&str], word: &str) -> Option<usize> {
+    words.iter().position(|w| letters(w).eq(letters(word)))
+}
+
+#[cfg(all(test, not(feature = "std")))]
+mod no_std_tests {
+    use super::{LetterBoxed, SearchStrategy};
+    use crate::prelude::*;
+
+    #[test]
+    fn test_solve_without_std() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["VEHICULAR", "RITZILY", "RIZ", "ZITY", "YEAH"];
+        assert_eq!(
+            b.solve(&words, &[], 3, 25),
+            vec![
+                (vec!["VEHICULAR", "RITZILY"], 12),
+                (vec!["VEHICULAR", "RIZ", "ZITY"], 12),
+            ]
+        );
+        let iddfs = b
+            .solve_iter(&words, &[], 3)
+            .with_strategy(SearchStrategy::IterativeDeepening)
+            .count();
+        assert_eq!(iddfs, 2);
+        assert_eq!(b.count_solutions(&words, &[], 3), vec![0, 0, 1, 1]);
+        assert!(b.validate(&["vehicular", "ritzily"]));
+    }
+}
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
-    use super::LetterBoxed;
+    use super::{
+        Adjacency, BoardError, BoardMetadata, BoardRules, Change, Dictionary, DictionaryIndex,
+        Difficulty, DropReason, FrequencyTable, Hint, IndexError, LetterBoxed, OwnedWordGraph,
+        ParRating, Position, Ranking, SearchStrategy, Solution, SolveOptions, SolverSession,
+        Violation, WordGraph, WordList, WordOrder,
+    };
 
     #[test]
     fn test_1() {
@@ -302,4 +905,1975 @@ mod tests {
             assert!(b.validate(&r.0));
         }
     }
+
+    #[test]
+    fn test_solve_iter() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["VEHICULAR", "RITZILY", "ZITHER", "YEAH"];
+        let mut iter = b.solve_iter(&words, &[], 3);
+        let first = iter.next().unwrap();
+        assert_eq!(first.words, vec!["VEHICULAR", "RITZILY"]);
+        assert_eq!(first.letters_covered, 12);
+        assert!(iter.all(|s| b.validate(&s.words)));
+    }
+
+    #[test]
+    fn test_dedup() {
+        let b = LetterBoxed::load_board(&["ABC", "DEF", "GHI"]);
+        let words = ["CHAD", "DIBEGFC", "FIC"];
+        let all = b.solve_iter(&words, &[], 3).collect::<Vec<_>>();
+        assert_eq!(all.len(), 3);
+        assert_eq!(all[1].words, vec!["DIBEGFC", "CHAD"]);
+        assert_eq!(all[2].words, vec!["FIC", "CHAD", "DIBEGFC"]);
+
+        let deduped = b
+            .solve_iter(&words, &[], 3)
+            .with_dedup(true)
+            .collect::<Vec<_>>();
+        assert_eq!(deduped.len(), 1);
+        assert_eq!(deduped[0].words, vec!["CHAD", "DIBEGFC"]);
+    }
+
+    #[allow(deprecated)]
+    #[test]
+    fn test_excluding() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["VEHICULAR", "RITZILY", "RIZ", "ZITY", "YEAH"];
+        let results = b.solve_excluding(&words, &[], &["ritzily"], 3, 25);
+        assert_eq!(results, vec![(vec!["VEHICULAR", "RIZ", "ZITY"], 12)]);
+
+        let iter = b
+            .solve_iter(&words, &[], 3)
+            .with_strategy(SearchStrategy::IterativeDeepening)
+            .with_excluded(&["RITZILY"]);
+        assert_eq!(iter.count(), 1);
+
+        let results = b.solve_excluding_with_builtin_list(&[], &["VEHICULAR"], 2, 25);
+        assert!(results.iter().all(|(w, _)| !w.contains(&"VEHICULAR")));
+    }
+
+    #[allow(deprecated)]
+    #[test]
+    fn test_requiring() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["VEHICULAR", "RITZILY", "RIZ", "ZITY", "YEAH"];
+        let results = b.solve_requiring(&words, &[], "zity", 3, 25);
+        assert_eq!(results, vec![(vec!["VEHICULAR", "RIZ", "ZITY"], 12)]);
+        let results = b.solve_requiring(&words, &[], "ZITY", 2, 25);
+        assert_eq!(results.len(), 1);
+        assert!(results[0].1 < 12);
+
+        let iter = b
+            .solve_iter(&words, &[], 3)
+            .with_strategy(SearchStrategy::IterativeDeepening)
+            .with_required("VEHICULAR");
+        assert_eq!(iter.count(), 2);
+
+        let results = b.solve_requiring_with_builtin_list(&[], "VEHICULAR", 2, 25);
+        assert!(results.iter().all(|(w, _)| w.contains(&"VEHICULAR")));
+    }
+
+    #[test]
+    fn test_min_word_length() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["VEHICULAR", "RITZILY", "RIZ", "ZITY"];
+        assert_eq!(b.min_word_length(), 3);
+        assert!(b.validate(&["VEHICULAR", "RIZ", "ZITY"]));
+        assert_eq!(b.solve(&words, &[], 3, 25).len(), 2);
+
+        let b = b.with_min_word_length(4);
+        assert!(!b.validate(&["VEHICULAR", "RIZ", "ZITY"]));
+        assert_eq!(
+            b.validate_detailed(&["VEHICULAR", "RIZ", "ZITY"]),
+            vec![Violation::TooShort {
+                word: 1,
+                length: 3,
+                minimum: 4
+            }]
+        );
+        assert_eq!(
+            b.solve(&words, &[], 3, 25),
+            vec![(vec!["VEHICULAR", "RITZILY"], 12)]
+        );
+
+        let b = LetterBoxed::load_board(&["AB", "CD"]).with_min_word_length(2);
+        assert_eq!(b.solve(&["AC", "CB", "BD"], &[], 3, 25).len(), 1);
+    }
+
+    #[test]
+    fn test_solve_all_minimal() {
+        let b = LetterBoxed::load_board(&["ABC", "DEF", "GHI"]);
+        let words = ["CHAD", "DIBEGFC", "FIC"];
+        let minimal = b.solve_all_minimal(&words, &[], 3);
+        assert_eq!(minimal.len(), 2);
+        assert!(minimal.iter().all(|s| s.words.len() == 2));
+        assert!(b.solve_all_minimal(&words, &[], 1).is_empty());
+
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let minimal = b.solve_all_minimal_with_builtin_list(&[], 2);
+        assert!(minimal
+            .iter()
+            .any(|s| s.words == vec!["VEHICULAR", "RITZILY"]));
+        assert!(minimal.len() > 1);
+    }
+
+    #[test]
+    fn test_count_solutions() {
+        let b = LetterBoxed::load_board(&["ABC", "DEF", "GHI"]);
+        let words = ["CHAD", "DIBEGFC", "FIC"];
+        assert_eq!(b.count_solutions(&words, &[], 3), vec![0, 0, 2, 1]);
+        assert_eq!(b.count_solutions(&words, &[2], 3), vec![0, 0, 0, 1]);
+
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let counts = b.count_solutions_with_builtin_list(&[], 2);
+        assert_eq!(
+            counts[2],
+            b.solve_all_minimal_with_builtin_list(&[], 2).len()
+        );
+    }
+
+    #[test]
+    fn test_meet_in_the_middle() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = crate::builtin_words();
+        let sorted = |mut solutions: Vec<Solution<'static>>| {
+            solutions.sort_by(|a, b| (a.words.len(), &a.words).cmp(&(b.words.len(), &b.words)));
+            solutions
+        };
+
+        let mut bfs = b.solve_iter(words, &[], 3);
+        let expected = sorted(bfs.by_ref().collect());
+        let mut mitm = b
+            .solve_iter(words, &[], 3)
+            .with_strategy(SearchStrategy::MeetInTheMiddle);
+        let found = mitm.by_ref().collect::<Vec<_>>();
+        assert!(found
+            .windows(2)
+            .all(|w| w[0].words.len() <= w[1].words.len()));
+        assert_eq!(sorted(found), expected);
+        assert!(mitm.states_expanded() < bfs.states_expanded());
+
+        let words = ["VEHICULAR", "RITZILY", "RIZ", "ZITY", "YEAH"];
+        let found = b
+            .solve_iter(&words, &[0], 3)
+            .with_strategy(SearchStrategy::MeetInTheMiddle)
+            .with_required("ZITY")
+            .collect::<Vec<_>>();
+        assert_eq!(
+            found,
+            b.solve_iter(&words, &[0], 3)
+                .with_required("ZITY")
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_greedy() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["YEAH", "HAIL", "VEHICULAR", "RITZILY", "RIZ", "ZITY"];
+        let bfs = b.solve_iter(&words, &[], 3).collect::<Vec<_>>();
+        let greedy = b
+            .solve_iter(&words, &[], 3)
+            .with_strategy(SearchStrategy::Greedy)
+            .collect::<Vec<_>>();
+        assert_eq!(greedy.len(), bfs.len());
+        assert!(greedy.iter().all(|s| bfs.contains(s)));
+        assert!(greedy
+            .windows(2)
+            .all(|w| w[0].words.len() <= w[1].words.len()));
+
+        // Once every starting letter is expanded, VEHICULAR covers the most
+        // letters, so it's the first word checked.
+        let mut iter = b
+            .solve_iter(&words, &[], 1)
+            .with_strategy(SearchStrategy::Greedy);
+        iter.pause_after(b.word_graph(&words).graph.len());
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.best_partial().words, vec!["VEHICULAR"]);
+    }
+
+    #[test]
+    fn test_beam_width() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let mut full = b.solve_iter_with_builtin_list(&[], 3);
+        let first = full.next().unwrap();
+
+        let mut beam = b.solve_iter_with_builtin_list(&[], 3).with_beam_width(100);
+        let solutions = beam.by_ref().collect::<Vec<_>>();
+        assert!(!solutions.is_empty());
+        assert!(solutions.iter().all(|s| b.validate(&s.words)));
+        assert_eq!(solutions[0].words.len(), first.words.len());
+        assert!(beam.states_expanded() < full.states_expanded());
+        assert!(beam.statistics().states_pruned > 0);
+    }
+
+    #[test]
+    fn test_solve_partial() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["VEHICULAR", "RITZILY", "RIZ", "ZITY", "YEAH"];
+        let partials = b.solve_partial(&words, &[], 2, 3);
+        assert_eq!(
+            partials
+                .iter()
+                .map(|s| (s.words.clone(), s.letters_covered))
+                .collect::<Vec<_>>(),
+            vec![
+                (vec!["VEHICULAR", "RIZ"], 10),
+                (vec!["VEHICULAR"], 9),
+                (vec!["RITZILY", "YEAH"], 9),
+            ]
+        );
+        assert!(b
+            .solve_partial(&words, &[0], 3, 10)
+            .iter()
+            .all(|s| s.words[0] == "VEHICULAR" && s.letters_covered < 12));
+        assert_eq!(b.solve_partial(&words, &[], 2, 0).len(), 9);
+    }
+
+    #[test]
+    fn test_board_code() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        assert_eq!(b.to_code().unwrap(), "ElzIvaRyuCth");
+        let decoded = LetterBoxed::from_code("ElzIvaRyuCth").unwrap();
+        assert_eq!(
+            decoded.sides().collect::<Vec<_>>(),
+            vec!["ELZ", "IVA", "RYU", "CTH"]
+        );
+
+        let uneven = LetterBoxed::load_board(&["AB", "C", "DEFA"]);
+        let code = uneven.to_code().unwrap();
+        assert_eq!(code, "AbCDefa");
+        assert_eq!(
+            LetterBoxed::from_code(&code)
+                .unwrap()
+                .sides()
+                .collect::<Vec<_>>(),
+            vec!["AB", "C", "DEFA"]
+        );
+
+        assert_eq!(LetterBoxed::load_board(&["ÉLZ", "IVA"]).to_code(), None);
+        assert_eq!(
+            LetterBoxed::from_code("Elz-Iva").unwrap_err(),
+            BoardError::NonAlphabetic {
+                character: '-',
+                side: 0
+            }
+        );
+        assert_eq!(
+            LetterBoxed::from_code("Elziva").unwrap_err(),
+            BoardError::TooFewSides { found: 1 }
+        );
+    }
+
+    #[test]
+    fn test_solve_fewest_letters() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["VEHICULAR", "RITZILY", "RIZ", "ZITY", "RITZY"];
+        let fewest = b.solve_fewest_letters(&words, &[], 3);
+        assert_eq!(fewest.len(), 1);
+        assert_eq!(fewest[0].words, vec!["VEHICULAR", "RITZY"]);
+        assert_eq!(fewest[0].letters_drawn(), 13);
+        // Without RITZY, the three word solution draws fewer letters than
+        // the two word one.
+        let fewest = b.solve_fewest_letters(&words[..4], &[], 3);
+        assert_eq!(fewest.len(), 1);
+        assert_eq!(fewest[0].words, vec!["VEHICULAR", "RIZ", "ZITY"]);
+        assert_eq!(fewest[0].letters_drawn(), 14);
+        assert!(b.solve_fewest_letters(&words, &[1], 3).is_empty());
+    }
+
+    #[test]
+    fn test_dominance_pruning() {
+        let b = LetterBoxed::load_board(&["ABC", "DEF", "GHI"]);
+        // "FIC" then "CHAD" reaches D with the same letters as "FICHAD".
+        let words = ["FICHAD", "FIC", "CHAD", "DIBEG", "GEB"];
+        let all = b.solve_iter(&words, &[], 4).collect::<Vec<_>>();
+        assert_eq!(all.len(), 2);
+        for strategy in [
+            SearchStrategy::BreadthFirst,
+            SearchStrategy::IterativeDeepening,
+        ] {
+            let pruned = b
+                .solve_iter(&words, &[], 4)
+                .with_strategy(strategy)
+                .with_dominance_pruning(true)
+                .collect::<Vec<_>>();
+            assert_eq!(pruned, all[..1]);
+        }
+
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = crate::builtin_words();
+        let minimal = b
+            .solve_iter(words, &[], 3)
+            .with_dominance_pruning(true)
+            .take_while(|s| s.words.len() == 2)
+            .count();
+        assert_eq!(minimal, b.count_solutions(words, &[], 2)[2]);
+    }
+
+    #[test]
+    fn test_state_dedup() {
+        let b = LetterBoxed::load_board(&["ABC", "DEF", "GHI"]);
+        // AGE then EHB reaches B with the same letters as AHE then EGB.
+        let words = ["AGE", "EHB", "AHE", "EGB", "BFIDC"];
+        let all = b.solve_iter(&words, &[], 3).collect::<Vec<_>>();
+        assert_eq!(all.len(), 2);
+        for strategy in [
+            SearchStrategy::BreadthFirst,
+            SearchStrategy::IterativeDeepening,
+            SearchStrategy::Greedy,
+        ] {
+            let mut iter = b
+                .solve_iter(&words, &[], 3)
+                .with_strategy(strategy)
+                .with_state_dedup(true);
+            assert_eq!(iter.by_ref().collect::<Vec<_>>(), all[..1]);
+            assert!(iter.statistics().states_pruned > 0);
+        }
+
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = crate::builtin_words();
+        let mut full = b.solve_iter(words, &[], 2);
+        let mut deduped = b.solve_iter(words, &[], 2).with_state_dedup(true);
+        assert_eq!(full.next(), deduped.next());
+        full.by_ref().for_each(drop);
+        deduped.by_ref().for_each(drop);
+        assert!(deduped.statistics().states_pruned > 0);
+    }
+
+    #[test]
+    fn test_word_graph() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["VEHICULAR", "RITZILY", "ZITHER", "YEAH"];
+        let graph = b.word_graph(&words);
+        assert_eq!(
+            graph.playable_words().collect::<Vec<_>>(),
+            vec!["VEHICULAR", "RITZILY", "YEAH"]
+        );
+        assert_eq!(graph.solve(&[], 3, 25), b.solve(&words, &[], 3, 25));
+        assert_eq!(graph.solve(&[0], 3, 25), b.solve(&words, &[0], 3, 25));
+        assert_eq!(graph.hint(&[0], 2), b.hint(&words, &[0], 2));
+        assert_eq!(
+            graph.solve_iter(&[], 2).with_excluded(&["RITZILY"]).count(),
+            0
+        );
+        // Excluding words from one search doesn't affect the graph.
+        assert_eq!(graph.solve_iter(&[], 2).count(), 1);
+        assert_eq!(graph.indices(&["vehicular", "YEAH"]), Some(vec![0, 3]));
+        assert_eq!(graph.indices(&["ZIT"]), None);
+    }
+
+    #[test]
+    fn test_owned_word_graph() {
+        static WORDS: [&str; 4] = ["VEHICULAR", "RITZILY", "ZITHER", "YEAH"];
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let expected = b.solve(&WORDS, &[], 3, 25);
+
+        let graph = OwnedWordGraph::new(b.clone(), &WORDS);
+        assert_eq!(graph.graph().solve(&[], 3, 25), expected);
+
+        let (dictionary, _) = Dictionary::clean("vehicular ritzily zither yeah");
+        let graph = OwnedWordGraph::from_dictionary(b.clone(), dictionary);
+        assert_eq!(graph.board().sides().count(), 4);
+        let g = graph.graph();
+        assert_eq!(g.solve(&[], 3, 25), expected);
+        assert_eq!(g.indices(&["yeah"]), Some(vec![3]));
+        assert_eq!(g.playable_words().count(), 3);
+
+        // Cloning the graph shares its words, which outlive the original.
+        let copy = graph.clone();
+        drop(graph);
+        assert_eq!(copy.graph().solve(&[], 3, 25), expected);
+
+        // A graph which owns its board can be searched without borrowing it.
+        let iter = WordGraph::with_owned_board(b.clone(), &WORDS).into_solve_iter(&[0], 2);
+        let solutions = iter.map(|s| s.words).collect::<Vec<_>>();
+        assert_eq!(solutions, vec![vec!["VEHICULAR", "RITZILY"]]);
+    }
+
+    #[test]
+    fn test_session() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let all = b.solve_iter_with_builtin_list(&[], 2).collect::<Vec<_>>();
+
+        let mut session = b.session_with_builtin_list(&[], 2);
+        let mut runs = 0;
+        while !session.is_finished() {
+            let before = session.states_expanded();
+            session.run(1000);
+            assert!(session.states_expanded() - before <= 1000);
+            runs += 1;
+        }
+        assert!(runs > 1);
+        assert_eq!(session.solutions(), all);
+        assert!(session.run(1000).is_empty());
+
+        let words = ["VEHICULAR", "RITZILY", "RIZ", "ZITY", "YEAH"];
+        let iter = b
+            .solve_iter(&words, &[], 3)
+            .with_strategy(SearchStrategy::IterativeDeepening);
+        let mut session = SolverSession::new(iter);
+        assert_eq!(session.run_for_results(1).len(), 1);
+        assert!(!session.is_finished());
+        while !session.is_finished() {
+            session.run(1);
+        }
+        assert_eq!(session.solutions().len(), 2);
+        assert_eq!(session.best_partial().letters_covered, 12);
+    }
+
+    #[test]
+    fn test_session_state_dedup() {
+        // Pausing and resuming mustn't prune the state the search paused at.
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["VEHICULAR", "RITZILY", "RIZ", "ZITY", "YEAH"];
+        for strategy in [SearchStrategy::IterativeDeepening, SearchStrategy::Greedy] {
+            let iter = || {
+                b.solve_iter(&words, &[], 3)
+                    .with_strategy(strategy)
+                    .with_state_dedup(true)
+            };
+            let all = iter().collect::<Vec<_>>();
+            assert!(!all.is_empty());
+
+            let mut session = SolverSession::new(iter());
+            while !session.is_finished() {
+                session.run(1);
+            }
+            assert_eq!(session.solutions(), all);
+        }
+    }
+
+    #[test]
+    fn test_session_page() {
+        let b = LetterBoxed::load_board(&["OAL", "NUK", "CET", "RPI"]);
+        let all = b.solve_iter_with_builtin_list(&[], 2).collect::<Vec<_>>();
+        assert!(all.len() >= 3);
+
+        let mut session = b.session_with_builtin_list(&[], 2);
+        assert_eq!(session.page(0, 1), &all[..1]);
+        assert_eq!(session.solutions().len(), 1);
+        assert_eq!(session.page(1, 1), &all[1..2]);
+        assert_eq!(session.page(0, 2), &all[..2]);
+        assert_eq!(session.page(all.len() - 1, 10), &all[all.len() - 1..]);
+        assert!(session.is_finished());
+        assert!(session.page(all.len() + 5, 10).is_empty());
+    }
+
+    #[test]
+    fn test_display() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let expected = "  E L Z\nI       C\nV       T\nA       H\n  R Y U";
+        assert_eq!(b.to_string(), expected);
+        assert_eq!(
+            b.render_solution(&["vehicular", "ritzily"]),
+            format!("{expected}\n\nvehicular - ritzily (12/12 letters)")
+        );
+
+        let b = LetterBoxed::load_board(&["ABC", "DEF", "GHI"]);
+        assert_eq!(b.to_string(), "side 1: ABC\nside 2: DEF\nside 3: GHI");
+    }
+
+    #[allow(deprecated)]
+    #[test]
+    fn test_statistics() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["VEHICULAR", "RITZILY", "ZITHER", "YEAH"];
+        let (results, stats) = b.solve_with_statistics(&words, &[], 3, 25);
+        assert_eq!(results.len(), 1);
+        assert_eq!(stats.graph_words, 3);
+        assert_eq!(stats.graph_edges, 3);
+        assert!(stats.states_expanded > 0);
+        assert!(stats.peak_queue_len > 0);
+        assert_eq!(stats.states_pruned, 0);
+        assert!(stats.elapsed.is_some());
+
+        let words = ["FICHAD", "FIC", "CHAD", "DIBEG", "GEB"];
+        let b = LetterBoxed::load_board(&["ABC", "DEF", "GHI"]);
+        let mut iter = b.solve_iter(&words, &[], 4).with_dominance_pruning(true);
+        iter.by_ref().for_each(drop);
+        assert_eq!(iter.statistics().states_pruned, 1);
+    }
+
+    #[test]
+    fn test_pangrams() {
+        let b = LetterBoxed::load_board(&["ABC", "DEF", "GHI"]);
+        let words = ["CHAD", "CHADIBEGF", "DIBEGFC", "BEGFCHADI"];
+        assert_eq!(b.pangrams(&words), vec!["CHADIBEGF", "BEGFCHADI"]);
+        assert_eq!(b.word_graph(&words).pangrams(), b.pangrams(&words));
+
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        assert!(b.pangrams_with_builtin_list().is_empty());
+    }
+
+    #[test]
+    fn test_analytics() {
+        let b = LetterBoxed::load_board(&["ABC", "DEF", "GHI"]);
+        let words = ["CHAD", "CHADIBEGF", "DIBEGFC", "BEGFCHADI", "ABC"];
+        let analytics = b.analytics(&words);
+        assert_eq!(analytics.playable_words, 4);
+        assert_eq!(analytics.words_per_start_letter[&'C'], 2);
+        assert_eq!(analytics.words_per_start_letter[&'A'], 0);
+        assert_eq!(
+            analytics.letters_covered,
+            [(4, 1), (7, 1), (9, 2)].into_iter().collect()
+        );
+        assert_eq!(analytics.pangrams, 2);
+        assert_eq!(
+            analytics.to_string(),
+            "4 playable words covering 7.2 letters on average, 2 pangrams"
+        );
+
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let analytics = b.analytics_with_builtin_list();
+        assert_eq!(
+            analytics.words_per_start_letter.values().sum::<usize>(),
+            analytics.playable_words
+        );
+    }
+
+    #[test]
+    fn test_solve_batch() {
+        let boards = [
+            LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]),
+            LetterBoxed::load_board(&["OAL", "NUK", "CET", "RPI"]),
+        ];
+        let words = crate::builtin_words();
+        let index = DictionaryIndex::new(words);
+        for board in &boards {
+            assert_eq!(
+                index.word_graph(board).playable_words().collect::<Vec<_>>(),
+                board.word_graph(words).playable_words().collect::<Vec<_>>()
+            );
+        }
+
+        let solved = LetterBoxed::solve_batch_with_builtin_list(&boards, 2, 5);
+        assert_eq!(solved.len(), 2);
+        for (board, solutions) in boards.iter().zip(&solved) {
+            assert_eq!(*solutions, board.solve(words, &[], 2, 5));
+        }
+        assert_eq!(LetterBoxed::solve_batch(&boards, words, 2, 5), solved);
+
+        let bytes = index.to_bytes();
+        let loaded = DictionaryIndex::from_bytes(&bytes).unwrap();
+        assert_eq!(loaded.words(), words);
+        assert_eq!(loaded.solve_batch(&boards, 2, 5), solved);
+        assert_eq!(
+            DictionaryIndex::from_bytes(&bytes[..bytes.len() - 1]).unwrap_err(),
+            IndexError::Truncated
+        );
+        assert_eq!(
+            DictionaryIndex::from_bytes(b"not an index").unwrap_err(),
+            IndexError::BadHeader
+        );
+    }
+
+    #[test]
+    fn test_solve_two_words() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = crate::builtin_words();
+        let pairs = b.solve_two_words(words);
+        let searched = b
+            .solve_iter(words, &[], 2)
+            .map(|s| (s.words[0], s.words[1]))
+            .collect::<Vec<_>>();
+        assert_eq!(pairs, searched);
+        assert!(pairs.contains(&("VEHICULAR", "RITZILY")));
+
+        // The fast path gives the same results as searching.
+        let solved = b.solve(words, &[], 3, 10);
+        let mut iter = b.solve_iter(words, &[], 3);
+        assert_eq!(solved, crate::collect_results(&mut iter, 10));
+
+        // Falls back to searching when there aren't enough short solutions.
+        let b = LetterBoxed::load_board(&["ABC", "DEF", "GHI"]);
+        let words = ["CHAD", "DIBEGFC", "FIC"];
+        assert_eq!(b.solve_two_words(&words).len(), 2);
+        let solved = b.solve(&words, &[], 3, 25);
+        assert_eq!(solved.len(), 3);
+        assert_eq!(solved[2].0, vec!["FIC", "CHAD", "DIBEGFC"]);
+    }
+
+    #[test]
+    fn test_letter_masks_at_the_limits() {
+        // An empty board has no letters to mask.
+        let b = LetterBoxed::load_board(&[]);
+        assert_eq!(b.solve_two_words(&["ABC"]), vec![]);
+        assert_eq!(b.solve(&["ABC"], &[], 2, 5), vec![(vec![], 0)]);
+        assert_eq!(b.count_solutions(&["ABC"], &[], 2), vec![0, 0, 0]);
+
+        // A board with exactly as many letters as a mask holds inline.
+        let letters = ('\u{4E00}'..).take(128).collect::<Vec<_>>();
+        let sides = letters
+            .chunks(2)
+            .map(|side| side.iter().collect::<String>())
+            .collect::<Vec<_>>();
+        let b = LetterBoxed::load_board(&sides.iter().map(|s| s.as_str()).collect::<Vec<_>>());
+        let word = letters.iter().step_by(2).collect::<String>();
+        assert_eq!(b.count_solutions(&[&word], &[], 2), vec![0, 0, 0]);
+        assert_eq!(b.solve_two_words(&[&word]), vec![]);
+    }
+
+    #[test]
+    fn test_check_word() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        assert_eq!(b.check_word("vehicular"), Ok(()));
+        assert!(b.is_playable("RITZILY"));
+        assert!(!b.is_playable("ZITHER"));
+        assert_eq!(
+            b.check_word("ZITHER"),
+            Err(Violation::SameSide {
+                word: 0,
+                letters: ('T', 'H'),
+                side: 3
+            })
+        );
+        assert_eq!(
+            b.check_word("QUIZ"),
+            Err(Violation::NotOnBoard {
+                word: 0,
+                letter: 'Q'
+            })
+        );
+        assert_eq!(
+            b.check_word("RY"),
+            Err(Violation::TooShort {
+                word: 0,
+                length: 2,
+                minimum: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_playable_words() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["YEAH", "VEHICULAR", "ZITHER", "RITZILY", "ZEAL"];
+        assert_eq!(
+            b.playable_words(&words, WordOrder::WordList),
+            vec!["YEAH", "VEHICULAR", "RITZILY"]
+        );
+        assert_eq!(
+            b.playable_words(&words, WordOrder::Length),
+            vec!["VEHICULAR", "RITZILY", "YEAH"]
+        );
+        assert_eq!(
+            b.playable_words(&["RITZILY", "YEAH", "RITZY"], WordOrder::Coverage),
+            vec!["RITZILY", "RITZY", "YEAH"]
+        );
+        assert!(b
+            .playable_words_with_builtin_list(WordOrder::default())
+            .contains(&"VEHICULAR"));
+    }
+
+    #[test]
+    fn test_best_coverage_words() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["YEAH", "ZITY", "RITZY", "VEHICULAR", "ZITHER", "RITZILY"];
+        assert_eq!(
+            b.best_coverage_words(&words, 3, None),
+            vec![("VEHICULAR", 9), ("RITZILY", 6), ("RITZY", 5)]
+        );
+        assert_eq!(
+            b.best_coverage_words(&words, 0, None)[3..],
+            [("YEAH", 4), ("ZITY", 4)]
+        );
+        let table = FrequencyTable::from_ranked_list("zity\n");
+        assert_eq!(
+            b.best_coverage_words(&words, 0, Some(&table))[3..],
+            [("ZITY", 4), ("YEAH", 4)]
+        );
+    }
+
+    #[test]
+    fn test_opening_words() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["VEHICULAR", "RITZILY", "RITZY", "RIZ", "ZITY", "YEAH"];
+        let openers = b.opening_words(&words, 3, 0);
+        assert_eq!(
+            openers
+                .iter()
+                .map(|o| (o.word, o.finishers, o.completions))
+                .collect::<Vec<_>>(),
+            vec![("VEHICULAR", 2, 3)]
+        );
+        assert_eq!(openers[0].letters_covered, 9);
+        assert!(b.opening_words(&words, 1, 0).is_empty());
+    }
+
+    #[test]
+    fn test_playable_words_from() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["RIZ", "YEAH", "RITZILY", "VEHICULAR", "RITZY"];
+        assert_eq!(
+            b.playable_words_from(&words, 'r', WordOrder::WordList),
+            vec!["RIZ", "RITZILY", "RITZY"]
+        );
+        assert_eq!(
+            b.word_graph(&words).playable_words_from('R'),
+            vec!["RIZ", "RITZILY", "RITZY"]
+        );
+        assert_eq!(
+            b.playable_words_from(&words, 'R', WordOrder::Length),
+            vec!["RITZILY", "RITZY", "RIZ"]
+        );
+        assert!(b
+            .playable_words_from(&words, 'Q', WordOrder::WordList)
+            .is_empty());
+        assert!(b
+            .playable_words_from_with_builtin_list('R', WordOrder::WordList)
+            .contains(&"RITZILY"));
+    }
+
+    #[test]
+    fn test_no_repeated_words() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        // A repeated word never covers anything new, so isn't played again,
+        // even if the word list has duplicates.
+        let words = ["VEHICULAR", "RIZ", "ZIZ", "ZITY", "RIZ", "ZIZ"];
+        for s in b.solve_iter(&words, &[1], 4) {
+            let mut seen = std::collections::HashSet::new();
+            assert!(s.words.iter().all(|w| seen.insert(*w)), "{:?}", s.words);
+        }
+        assert!(!b.validate(&["RIZ", "ZIZ", "ZIZ"]));
+        assert_eq!(
+            b.validate_detailed(&["VEHICULAR", "RIZ", "ZIZ", "ziz", "ZITY"]),
+            vec![Violation::RepeatedWord { word: 3, first: 2 }]
+        );
+        // Prior words mustn't repeat either.
+        assert_eq!(b.solve_iter(&words, &[0, 1, 2, 5], 5).count(), 0);
+
+        let b = b.with_rules(BoardRules::new().with_repeated_words(true));
+        assert!(b.rules().repeated_words());
+        assert!(b.validate(&["VEHICULAR", "RIZ", "ZIZ", "ziz", "ZITY"]));
+        assert_eq!(
+            b.validate_detailed(&["VEHICULAR", "RIZ", "ZIZ", "ziz", "ZITY"]),
+            vec![]
+        );
+        assert_eq!(
+            b.solve_iter(&words, &[0, 1, 2, 5], 5)
+                .map(|s| s.words)
+                .collect::<Vec<_>>(),
+            vec![vec!["VEHICULAR", "RIZ", "ZIZ", "ZIZ", "ZITY"]]
+        );
+    }
+
+    #[test]
+    fn test_repeated_letters() {
+        // E is on both the first and second sides.
+        let b = LetterBoxed::load_board(&["ELZ", "IVE", "RYU", "CTH"]);
+        assert_eq!(b.num_letters(), 11);
+        // E can follow L, since it can be played on the second side...
+        assert!(b.validate(&["LEI"]));
+        // ...and E can follow V, since it can be played on the first side...
+        assert!(b.validate(&["VEIL"]));
+        // ...and even itself, but letters only on one side still can't.
+        assert!(b.validate(&["TEE"]));
+        assert!(!b.validate(&["LZE"]));
+        assert_eq!(
+            b.check_word("ELZ"),
+            Err(Violation::SameSide {
+                word: 0,
+                letters: ('L', 'Z'),
+                side: 0
+            })
+        );
+        assert!(b
+            .solve(&["VEIL", "LEI"], &[], 2, 5)
+            .iter()
+            .all(|(w, _)| b.validate(w)));
+
+        assert!(matches!(
+            LetterBoxed::try_load_board(&["ELZ", "IVE", "RYU", "CTH"]),
+            Err(BoardError::DuplicateLetter { letter: 'E', .. })
+        ));
+    }
+
+    #[test]
+    fn test_board_rules() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVE", "RYU", "CTH"]);
+        assert_eq!(b.rules().adjacency(), Adjacency::DifferentSides);
+        assert!(!b.validate(&["LZE"]));
+
+        let rules = BoardRules::new().with_adjacency(Adjacency::DifferentPositions);
+        let b = b.with_rules(rules);
+        assert!(b.validate(&["LZE"]));
+        // E is at two positions, so it may follow itself, but L isn't.
+        assert!(b.validate(&["TEE"]));
+        assert!(!b.validate(&["ELL"]));
+        assert!(b
+            .solve(&["ZEL", "LULL", "LYRE"], &[], 2, 5)
+            .iter()
+            .all(|(w, _)| b.validate(w)));
+        assert_eq!(
+            b.playable_words(&["ZEL", "LULL"], WordOrder::WordList),
+            vec!["ZEL"]
+        );
+    }
+
+    #[test]
+    fn test_each_letter_once() {
+        let rules = BoardRules::new().with_each_letter_once(true);
+        let b = LetterBoxed::load_board(&["ABC", "DEF", "GHI"]).with_rules(rules);
+        let words = ["CHAD", "DIBEGF", "DIBE", "EGF", "AFAD"];
+
+        // DIBEGF reuses the D from CHAD, but the shared D of the chain only
+        // counts once.
+        assert!(b.validate(&["CHAD", "DIBEGF"]));
+        assert!(!b.validate(&["AFAD"]));
+        assert_eq!(
+            b.validate_detailed(&["CHAD", "DIBEGF", "FAD"]),
+            vec![
+                Violation::LetterReused {
+                    word: 2,
+                    letter: 'A'
+                },
+                Violation::LetterReused {
+                    word: 2,
+                    letter: 'D'
+                },
+            ]
+        );
+        assert_eq!(b.playable_words(&words, WordOrder::WordList).len(), 4);
+
+        let results = b.solve(&words, &[], 3, 25);
+        assert_eq!(
+            results,
+            vec![
+                (vec!["CHAD", "DIBEGF"], 9),
+                (vec!["CHAD", "DIBE", "EGF"], 9),
+            ]
+        );
+        assert_eq!(b.count_solutions(&words, &[], 3), vec![0, 0, 1, 1]);
+        assert_eq!(b.solve_two_words(&words), vec![("CHAD", "DIBEGF")]);
+
+        // Without the rule, FAD may follow CHAD and DIBEGF.
+        let b = LetterBoxed::load_board(&["ABC", "DEF", "GHI"]);
+        assert!(b.validate(&["CHAD", "DIBEGF", "FAD"]));
+    }
+
+    #[allow(deprecated)]
+    #[test]
+    fn test_unlimited_results() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let all = b.solve_iter_with_builtin_list(&[], 3).count();
+        assert!(all > 25);
+        assert_eq!(b.solve_with_builtin_list(&[], 3, 0).len(), all);
+        assert_eq!(b.solve_with_builtin_list(&[], 3, 1).len(), 1);
+        let two_words = b.solve_iter_with_builtin_list(&[], 2).count();
+        assert_eq!(b.solve_with_builtin_list(&[], 2, 0).len(), two_words);
+
+        let words = ["VEHICULAR", "RITZILY", "RIZ", "ZITY", "YEAH"];
+        assert_eq!(b.solve(&words, &[], 3, 0).len(), 2);
+        assert_eq!(b.solve_excluding(&words, &[], &["RIZ"], 3, 0).len(), 1);
+    }
+
+    #[test]
+    fn test_letter_positions() {
+        let b = LetterBoxed::load_board(&["elz", "IVE", "RYU", "CTH"]);
+        let positions = b.letter_positions();
+        assert_eq!(positions.len(), 11);
+        assert_eq!(positions[&'L'], vec![Position { side: 0, index: 1 }]);
+        assert_eq!(
+            b.positions_of('e'),
+            vec![
+                Position { side: 0, index: 0 },
+                Position { side: 1, index: 2 }
+            ]
+        );
+        assert_eq!(b.positions_of('Q'), vec![]);
+        assert_eq!(
+            b.sides().collect::<Vec<_>>(),
+            vec!["ELZ", "IVE", "RYU", "CTH"]
+        );
+    }
+
+    #[test]
+    fn test_trace() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let pos = |side, index| Position { side, index };
+        assert_eq!(
+            b.trace(&["yeah", "hire"]),
+            Some(vec![
+                pos(2, 1),
+                pos(0, 0),
+                pos(1, 2),
+                pos(3, 2),
+                pos(1, 0),
+                pos(2, 0),
+                pos(0, 0),
+            ])
+        );
+        assert_eq!(b.trace(&["YEAH", "LIAR"]), None);
+        assert_eq!(b.trace(&["ZEAL"]), None);
+
+        // E can be drawn from either side, but has to be drawn from the
+        // second side after L.
+        let b = LetterBoxed::load_board(&["ELZ", "IVE", "RYU", "CTH"]);
+        assert_eq!(
+            b.trace(&["LET"]),
+            Some(vec![pos(0, 1), pos(1, 2), pos(3, 1)])
+        );
+    }
+
+    #[cfg(feature = "render")]
+    #[test]
+    fn test_to_svg() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let svg = b.to_svg(&[]).unwrap();
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(svg.matches("<text").count(), 12);
+        assert!(!svg.contains("stroke-linejoin"));
+
+        let svg = b.to_svg(&["VEHICULAR", "RITZILY"]).unwrap();
+        // The line visits each of the 15 letters drawn.
+        let line = svg.lines().find(|l| l.contains("stroke-linejoin")).unwrap();
+        assert_eq!(line.matches(',').count(), 15);
+        assert_eq!(b.to_svg(&["ZEAL"]), None);
+
+        let b = LetterBoxed::load_board(&["AB", "CD", "EF"]);
+        assert_eq!(b.to_svg(&["ACE"]).unwrap().matches("<text").count(), 6);
+    }
+
+    #[test]
+    fn test_explain() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let explanation = b.explain(&["yeah", "hire"]).unwrap();
+        assert_eq!(explanation.steps.len(), 7);
+        assert_eq!(explanation.steps[1].letter, 'E');
+        assert_eq!(
+            explanation.steps[1].position,
+            Position { side: 0, index: 0 }
+        );
+        assert_eq!(
+            explanation.to_string(),
+            "Y(bottom)→E(top)→A(left)→H(right)→I(left)→R(bottom)→E(top)"
+        );
+        assert_eq!(b.explain(&["ZEAL"]), None);
+
+        let b = LetterBoxed::load_board(&["AB", "CD", "EF"]);
+        assert_eq!(
+            b.explain(&["ACE"]).unwrap().to_string(),
+            "A(side 1)→C(side 2)→E(side 3)"
+        );
+    }
+
+    #[test]
+    fn test_owned_solution() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let solution = {
+            let words = ["VEHICULAR".to_string(), "RITZILY".to_string()];
+            let words = words.iter().map(|w| w.as_str()).collect::<Vec<_>>();
+            let solution = b.solve_iter(&words, &[], 2).next().unwrap();
+            solution.into_owned()
+        };
+        let solution = std::thread::spawn(move || solution).join().unwrap();
+        assert_eq!(solution.words, vec!["VEHICULAR", "RITZILY"]);
+        assert_eq!(solution.letters_covered, 12);
+        assert_eq!(solution.as_solution().into_owned(), solution);
+    }
+
+    #[test]
+    fn test_solution_indices() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["YEAH", "VEHICULAR", "RIZ", "RITZILY", "ZITY"];
+        let solutions = b.solve_iter(&words, &[1], 3).collect::<Vec<_>>();
+        assert_eq!(solutions[0].indices, vec![1, 3]);
+        for s in &solutions {
+            let looked_up = s.indices.iter().map(|i| words[*i]).collect::<Vec<_>>();
+            assert_eq!(looked_up, s.words);
+        }
+        let owned = solutions[0].clone().into_owned();
+        assert_eq!(owned.indices, vec![1, 3]);
+        assert_eq!(owned.as_solution(), solutions[0]);
+    }
+
+    #[test]
+    fn test_triangle() {
+        let b = LetterBoxed::load_board(&["ABC", "DEF", "GHI"]);
+        assert_eq!(b.sides().count(), 3);
+        let words = ["CHAD", "DIBEGF", "FAD"];
+        let results = b.solve(&words, &[], 3, 25);
+        assert_eq!(results, vec![(vec!["CHAD", "DIBEGF"], 9)]);
+    }
+
+    #[test]
+    fn test_from_str() {
+        let b = "ELZ-IVA / RYU  CTH".parse::<LetterBoxed>().unwrap();
+        assert_eq!(
+            b.sides().collect::<Vec<_>>(),
+            vec!["ELZ", "IVA", "RYU", "CTH"]
+        );
+        assert_eq!(
+            "ELZ".parse::<LetterBoxed>().unwrap_err(),
+            BoardError::TooFewSides { found: 1 }
+        );
+        assert_eq!(
+            "ELZ--RYU".parse::<LetterBoxed>().unwrap_err(),
+            BoardError::EmptySide { side: 1 }
+        );
+    }
+
+    #[test]
+    fn test_try_load_board() {
+        assert!(LetterBoxed::try_load_board(&["ELZ", "IVA", "RYU", "CTH"]).is_ok());
+        assert_eq!(
+            LetterBoxed::try_load_board(&["ELZ", "IVA", "RYE", "CTH"]).unwrap_err(),
+            BoardError::DuplicateLetter {
+                letter: 'E',
+                first_side: 0,
+                second_side: 2
+            }
+        );
+        assert_eq!(
+            LetterBoxed::try_load_board(&["EL2", "IVA"]).unwrap_err(),
+            BoardError::NonAlphabetic {
+                character: '2',
+                side: 0
+            }
+        );
+        assert_eq!(
+            LetterBoxed::try_load_board(&["ELZ", ""]).unwrap_err(),
+            BoardError::EmptySide { side: 1 }
+        );
+    }
+
+    #[test]
+    fn test_more_than_128_letters() {
+        // Each letter is on its own side, so any word can be played.
+        let letters = ('\u{4E00}'..)
+            .take(200)
+            .map(String::from)
+            .collect::<Vec<_>>();
+        let sides = letters.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+        let b = LetterBoxed::try_load_board(&sides).unwrap();
+        assert_eq!(b.num_letters(), 200);
+
+        let first = letters[..150].concat();
+        let second = letters[149..].concat();
+        let partial = letters[..190].concat();
+        let words = [partial.as_str(), first.as_str(), second.as_str()];
+        assert_eq!(
+            b.solve(&words, &[], 2, 10),
+            vec![(vec![first.as_str(), second.as_str()], 200)]
+        );
+        assert_eq!(b.count_solutions(&words, &[], 2), [0, 0, 1]);
+        assert_eq!(
+            b.solve_two_words(&words),
+            vec![(first.as_str(), second.as_str())]
+        );
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        let b = LetterBoxed::load_board(&["elz", "IvA", "ryu", "cth"]);
+        assert!(b.validate(&["vehicular", "Ritzily"]));
+        let words = ["vehicular", "ritzily"];
+        let results = b.solve(&words, &[], 2, 25);
+        assert_eq!(results, vec![(vec!["vehicular", "ritzily"], 12)]);
+        assert!(LetterBoxed::try_load_board(&["elz", "ivA", "ryu", "cth", "a"]).is_err());
+    }
+
+    #[test]
+    fn test_unicode() {
+        // "Ñ" and "Í" are written with combining marks on the board, but are
+        // precomposed in the dictionary.
+        let b = LetterBoxed::load_board(&["SN\u{303}", "EO", "RI\u{301}", "AZ"]);
+        assert_eq!(b.num_letters(), 8);
+        assert!(b.validate(&["SEÑOR", "raíz"]));
+        let words = ["señor", "raíz", "sal"];
+        let results = b.solve_with_word_list(&words, &[], 2, 25).unwrap();
+        assert_eq!(results, vec![(vec!["señor", "raíz"], 8)]);
+        assert_eq!(b.solve_with_word_list(&words, &["SOL"], 2, 25), None);
+
+        // "ß" matches its uppercase form.
+        let b = LetterBoxed::load_board(&["ẞ", "A", "T"]);
+        assert_eq!(b.solve(&["taß"], &[], 1, 25), vec![(vec!["taß"], 3)]);
+    }
+
+    #[test]
+    fn test_validate_detailed() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        assert!(b.validate_detailed(&["VEHICULAR", "RITZILY"]).is_empty());
+        assert_eq!(
+            b.validate_detailed(&["VEHICULAR", "ZIT", "TRAVE"]),
+            vec![
+                Violation::BrokenChain {
+                    word: 1,
+                    expected: 'R',
+                    found: Some('Z')
+                },
+                Violation::SameSide {
+                    word: 2,
+                    letters: ('A', 'V'),
+                    side: 1
+                },
+                Violation::Uncovered {
+                    letters: ['Y'].into_iter().collect()
+                },
+            ]
+        );
+        assert_eq!(
+            b.validate_detailed(&["VEHICULAR", "ROB"])[..2],
+            [
+                Violation::NotOnBoard {
+                    word: 1,
+                    letter: 'O'
+                },
+                Violation::NotOnBoard {
+                    word: 1,
+                    letter: 'B'
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_prefix() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let state = b.validate_prefix(&[]);
+        assert!(state.is_valid());
+        assert_eq!(state.remaining.len(), 12);
+        assert_eq!(state.next_letter, None);
+
+        let state = b.validate_prefix(&["vehicular"]);
+        assert!(state.is_valid() && !state.is_complete());
+        assert_eq!(state.covered.len(), 9);
+        assert_eq!(state.remaining, ['T', 'Y', 'Z'].into_iter().collect());
+        assert_eq!(state.next_letter, Some('R'));
+
+        let state = b.validate_prefix(&["VEHICULAR", "ZIT"]);
+        assert_eq!(
+            state.violations,
+            vec![Violation::BrokenChain {
+                word: 1,
+                expected: 'R',
+                found: Some('Z')
+            }]
+        );
+        assert_eq!(state.next_letter, Some('T'));
+
+        assert!(b.validate_prefix(&["VEHICULAR", "RITZILY"]).is_complete());
+    }
+
+    #[test]
+    fn test_group_by_first_word() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["VEHICULAR", "RITZILY", "RITZY", "YEAH", "HAZILY"];
+        let results = b.solve(&words, &[], 3, 0);
+        let groups = super::group_by_first_word(results.iter().map(|(w, _)| &w[..]));
+        assert_eq!(groups.iter().map(|g| g.len()).sum::<usize>(), results.len());
+        for group in &groups {
+            for idx in &group.indices {
+                assert_eq!(results[*idx].0[0], group.first_word);
+            }
+        }
+        assert_eq!(groups[0].first_word, "VEHICULAR");
+        assert_eq!(groups[0].to_string(), "VEHICULAR → 2 solutions");
+    }
+
+    #[allow(deprecated)]
+    #[test]
+    fn test_solve_varied() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["VEHICULAR", "RITZILY", "RITZY", "YEAH", "HAZILY"];
+        let all = b.solve(&words, &[], 3, 0);
+        let varied = b.solve_varied(&words, &[], 3, 0, 1);
+        assert!(varied.len() < all.len());
+        let groups = super::group_by_first_word(varied.iter().map(|(w, _)| &w[..]));
+        assert!(groups.iter().all(|g| g.len() == 1));
+        assert_eq!(varied[0].0, vec!["VEHICULAR", "RITZILY"]);
+
+        // With prior words, the limit applies to the word after them.
+        let varied = b.solve_varied(&words, &[0], 3, 0, 1);
+        assert_eq!(
+            varied,
+            vec![
+                (vec!["VEHICULAR", "RITZILY"], 12),
+                (vec!["VEHICULAR", "RITZY"], 12)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compare_solution() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["VEHICULAR", "RITZILY", "RITZY", "RIZ", "ZITY", "YEAH"];
+
+        let comparison = b
+            .compare_solution(&words, &["vehicular", "riz", "zity"], 0)
+            .unwrap();
+        assert_eq!(comparison.words, 3);
+        assert_eq!(comparison.optimal, Some(vec!["VEHICULAR", "RITZILY"]));
+        assert_eq!(comparison.word_delta(), 1);
+        assert_eq!(comparison.letters_drawn, 9 + 2 + 3);
+        assert_eq!(comparison.letters_wasted, 2);
+        assert_eq!(
+            comparison.alternatives,
+            vec![vec!["VEHICULAR", "RITZILY"], vec!["VEHICULAR", "RITZY"]]
+        );
+        assert_eq!(
+            comparison.to_string(),
+            "3 words, 1 more than the best of 2 (VEHICULAR - RITZILY); \
+             14 letters drawn, 2 wasted"
+        );
+
+        let comparison = b
+            .compare_solution(&words, &["VEHICULAR", "RITZY"], 0)
+            .unwrap();
+        assert_eq!(comparison.word_delta(), 0);
+        assert_eq!(comparison.alternatives, vec![vec!["VEHICULAR", "RITZILY"]]);
+
+        assert!(b.compare_solution(&words, &["VEHICULAR"], 0).is_err());
+    }
+
+    #[test]
+    fn test_hint() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["VEHICULAR", "RITZILY", "RAVE", "VIRTUALLY", "YEAH"];
+        let hints = b.hint(&words, &[], 2);
+        assert_eq!(
+            hints[0],
+            Hint {
+                word: "VEHICULAR",
+                new_letters: 9,
+                completions: 1
+            }
+        );
+        assert!(hints[1..].iter().all(|h| h.completions == 0));
+
+        let hints = b.hint(&words, &[0], 3);
+        assert_eq!(hints[0].word, "RITZILY");
+        assert_eq!(hints[0].new_letters, 3);
+    }
+
+    #[test]
+    fn test_coverage() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["VEHICULAR", "RITZILY", "RAIL", "RAT"];
+        let coverage = b.coverage(&words, &[0]);
+        assert_eq!(coverage.remaining, ['T', 'Y', 'Z'].into_iter().collect());
+        assert_eq!(coverage.covered.len(), 9);
+        assert_eq!(coverage.words, vec![("RITZILY", 3), ("RAT", 1)]);
+    }
+
+    #[test]
+    fn test_solvability() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        assert!(b.is_solvable(&["VEHICULAR", "RITZILY"]));
+
+        let report = b.solvability_report(&["VEHICULAR", "ZIT"]);
+        assert!(!report.is_solvable());
+        assert_eq!(report.dead_letters, ['Y'].into_iter().collect());
+        assert_eq!(report.best_start, Some('V'));
+        assert_eq!(report.unreachable_letters, ['Z', 'T'].into_iter().collect());
+    }
+
+    #[test]
+    fn test_dead_letters() {
+        let b = LetterBoxed::load_board(&["ELQ", "IVA", "RYU", "CTH"]);
+        // QUEEN uses letters which aren't on the board, so Q is still dead.
+        let words = ["VEHICULAR", "RIT", "TYE", "QUEEN"];
+        assert_eq!(b.dead_letters(&words), ['Q'].into_iter().collect());
+        assert!(b
+            .dead_letters(&["VEHICULAR", "RIT", "TYE", "QUA"])
+            .is_empty());
+
+        let mut iter = b.solve_iter(&words, &[], 10);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.dead_letters(), &['Q'].into_iter().collect());
+        assert!(!iter.timed_out());
+        let best = iter.best_partial();
+        assert_eq!(best.words, vec!["VEHICULAR", "RIT", "TYE"]);
+        assert_eq!(best.letters_covered, b.num_letters() - 1);
+    }
+
+    #[test]
+    fn test_generator() {
+        use super::generator::BoardGenerator;
+
+        let words = ["VEHICULAR", "RITZILY", "RAT", "TEA", "ALE", "EAT"];
+        let generator = BoardGenerator::new()
+            .sides(3)
+            .letters_per_side(1)
+            .max_attempts(1000);
+        let b = generator.generate_with_words(7, &words).unwrap();
+        assert!(b.is_solvable(&words));
+        let again = generator.generate_with_words(7, &words).unwrap();
+        assert_eq!(
+            b.sides().collect::<Vec<_>>(),
+            again.sides().collect::<Vec<_>>()
+        );
+        // No word covers a whole board, so the shortest solutions have two
+        // words, e.g. TAT - TOT.
+        let words = ["TAT", "TIT", "TOT", "NAN", "NUN", "NON", "SIS", "SOS"];
+        let b = generator
+            .clone()
+            .exact_words(2)
+            .generate_with_words(7, &words)
+            .unwrap();
+        assert_eq!(b.solve(&words, &[], 1, 1)[0].1, 2);
+        assert_eq!(b.solve(&words, &[], 2, 1)[0].1, 3);
+        assert!(generator
+            .exact_words(1)
+            .generate_with_words(7, &words)
+            .is_none());
+    }
+
+    #[test]
+    fn test_generate_from_seed_words() {
+        use super::generator::BoardGenerator;
+
+        let generator = BoardGenerator::new().max_words(2);
+        let b = generator
+            .generate_from_seed_words_with_words(1, &["VEHICULAR", "RITZILY"], &[])
+            .unwrap();
+        assert!(b.validate_detailed(&["VEHICULAR", "RITZILY"]).is_empty());
+
+        // Too many letters for the board.
+        let generator = BoardGenerator::new().sides(2);
+        assert!(generator
+            .generate_from_seed_words_with_words(1, &["VEHICULAR", "RITZILY"], &[])
+            .is_none());
+    }
+
+    #[test]
+    fn test_difficulty() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let report = b.difficulty(&["VEHICULAR", "RITZILY", "RAT", "ZITHER"]);
+        assert_eq!(report.playable_words, 3);
+        assert_eq!(report.two_word_solutions, 1);
+        assert_eq!(report.rarest_letter, Some(('C', 1)));
+        assert_eq!(report.difficulty, Difficulty::Hard);
+
+        let report = b.difficulty_with_builtin_list();
+        assert!(report.two_word_solutions > 0);
+        assert!(report.score < 1.0);
+    }
+
+    #[test]
+    fn test_ranking() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["VEHICULAR", "RITZILY", "RIZ", "ZITY", "RITZY"];
+        let order = |ranking| {
+            b.solve_ranked(&words, &[], 3, 25, &ranking)
+                .into_iter()
+                .map(|s| s.words[1..].join(" "))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            order(Ranking::FewestWords),
+            vec!["RITZILY", "RITZY", "RIZ ZITY"]
+        );
+        assert_eq!(
+            order(Ranking::FewestLetters),
+            vec!["RITZY", "RITZILY", "RIZ ZITY"]
+        );
+        let rank = |w: &str| words.iter().position(|ww| *ww == w).filter(|i| *i != 1);
+        assert_eq!(
+            order(Ranking::CommonVocabulary(&rank)),
+            vec!["RIZ ZITY", "RITZY", "RITZILY"]
+        );
+        let score = |s: &super::Solution<'_>| -(s.words.len() as i64);
+        assert_eq!(order(Ranking::Custom(&score))[0], "RIZ ZITY");
+        assert_eq!(
+            order(Ranking::Alphabetical),
+            vec!["RITZILY", "RITZY", "RIZ ZITY"]
+        );
+        // Ties are broken alphabetically, rather than in search order.
+        let score = |_: &super::Solution<'_>| 0;
+        assert_eq!(order(Ranking::Custom(&score)), order(Ranking::Alphabetical));
+    }
+
+    #[test]
+    fn test_solve_options() {
+        use alloc::sync::Arc;
+        use core::sync::atomic::AtomicBool;
+
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["VEHICULAR", "RITZILY", "RIZ", "ZITY", "RITZY"];
+        let solve = |options: SolveOptions<'_>| {
+            b.solve_with_options(&words, &options.prior_words(&["vehicular"]))
+                .unwrap()
+                .into_iter()
+                .map(|s| s.words[1..].join(" "))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            solve(SolveOptions::new()),
+            vec!["RITZILY", "RITZY", "RIZ ZITY"]
+        );
+        assert_eq!(
+            solve(SolveOptions::new().ranking(Ranking::FewestLetters)),
+            vec!["RITZY", "RITZILY", "RIZ ZITY"]
+        );
+        assert_eq!(
+            solve(SolveOptions::new().max_depth(2).max_results(1)),
+            vec!["RITZILY"]
+        );
+        assert_eq!(
+            solve(SolveOptions::new().excluded(&["ritzily", "RITZY"])),
+            vec!["RIZ ZITY"]
+        );
+        assert_eq!(
+            solve(SolveOptions::new().required("ZITY")),
+            vec!["RIZ ZITY"]
+        );
+        assert_eq!(
+            solve(SolveOptions::new().min_word_length(4)),
+            vec!["RITZILY", "RITZY"]
+        );
+        assert_eq!(
+            solve(
+                SolveOptions::new()
+                    .strategy(SearchStrategy::IterativeDeepening)
+                    .deadline(std::time::Instant::now() + std::time::Duration::from_secs(60))
+            ),
+            solve(SolveOptions::new())
+        );
+        let varied = b
+            .solve_with_options(&words, &SolveOptions::new().max_per_start_word(1))
+            .unwrap();
+        assert_eq!(varied.len(), 1);
+        assert_eq!(varied[0].words, ["VEHICULAR", "RITZILY"]);
+        assert_eq!(
+            solve(
+                SolveOptions::new()
+                    .strategy(SearchStrategy::BreadthFirst)
+                    .beam_width(1)
+            ),
+            vec!["RITZILY"]
+        );
+        assert_eq!(
+            solve(
+                SolveOptions::new()
+                    .dedup(true)
+                    .state_dedup(true)
+                    .dominance_pruning(true)
+            ),
+            vec!["RITZILY", "RITZY"]
+        );
+        let cancel = Arc::new(AtomicBool::new(true));
+        assert_eq!(solve(SolveOptions::new().cancellation(cancel)), vec![""]);
+
+        let options = SolveOptions::new().max_depth(2).prior_words_indices(&[0]);
+        let cloned = options.clone().max_depth(3);
+        assert!(format!("{options:?}").contains("max_depth: 2"));
+        assert_eq!(
+            b.solve_with_options(&words, &cloned).unwrap(),
+            b.solve_with_options(&words, &SolveOptions::new().prior_words(&["VEHICULAR"]))
+                .unwrap()
+        );
+        assert!(b
+            .solve_with_options(&words, &SolveOptions::new().prior_words(&["ZITHER"]))
+            .is_none());
+    }
+
+    #[test]
+    fn test_frequency() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["VEHICULAR", "RITZILY", "RIZ", "ZITY", "RITZY"];
+        let table = FrequencyTable::from_ranked_list("ritzy\nvehicular\n\nriz\nzity\n");
+        assert_eq!(table.rank("RITZY"), Some(0));
+        assert_eq!(table.rank("RITZILY"), None);
+
+        let results = b.solve_ranked(&words, &[], 3, 25, &Ranking::Frequency(&table));
+        assert_eq!(results[0].words, vec!["VEHICULAR", "RITZY"]);
+
+        let common = table.common_words(&words, 1);
+        assert_eq!(common, vec!["VEHICULAR", "RITZY"]);
+        assert_eq!(b.solve(&common, &[], 3, 25).len(), 1);
+    }
+
+    #[cfg(feature = "builtin-frequencies")]
+    #[test]
+    fn test_builtin_frequencies() {
+        let table = FrequencyTable::builtin();
+        assert_eq!(table.rank("the"), Some(0));
+        assert!(table.rank("VEHICULAR").is_some());
+    }
+
+    #[cfg(feature = "capi")]
+    #[test]
+    fn test_capi() {
+        use crate::capi::*;
+        use std::ffi::{CStr, CString};
+
+        let sides = CString::new("ELZ-IVA-RYU-CTH").unwrap();
+        let prior = CString::new("vehicular").unwrap();
+        unsafe {
+            let board = lbs_board_new(sides.as_ptr());
+            assert!(!board.is_null());
+
+            let mut buf = [0 as std::ffi::c_char; 64];
+            let len = lbs_solve(board, prior.as_ptr(), 2, 1, buf.as_mut_ptr(), buf.len());
+            let out = CStr::from_ptr(buf.as_ptr()).to_str().unwrap().to_string();
+            assert_eq!(out, "12 VEHICULAR RITZILY\n");
+            assert_eq!(len, out.len() as isize);
+
+            // Output is truncated to fit the buffer.
+            let len = lbs_solve(board, prior.as_ptr(), 2, 1, buf.as_mut_ptr(), 4);
+            assert_eq!(CStr::from_ptr(buf.as_ptr()).to_str().unwrap(), "12 ");
+            assert_eq!(len, out.len() as isize);
+
+            let s = lbs_solve_alloc(board, prior.as_ptr(), 2, 1);
+            assert_eq!(CStr::from_ptr(s).to_str().unwrap(), out);
+            lbs_string_free(s);
+
+            let missing = CString::new("NOTAWORD").unwrap();
+            assert_eq!(
+                lbs_solve(board, missing.as_ptr(), 2, 1, buf.as_mut_ptr(), 64),
+                -1
+            );
+
+            lbs_board_free(board);
+            assert!(lbs_board_new(CString::new("AB-A").unwrap().as_ptr()).is_null());
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_solve_async() {
+        use futures_core::Stream;
+        use std::sync::Arc;
+        use std::task::{Context, Poll, Wake};
+
+        /// Wakes the test thread, which parks while the stream is pending.
+        struct Unpark(std::thread::Thread);
+        impl Wake for Unpark {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+        let next = |stream: &mut super::SolutionStream| {
+            let waker = Arc::new(Unpark(std::thread::current())).into();
+            let mut cx = Context::from_waker(&waker);
+            loop {
+                match std::pin::Pin::new(&mut *stream).poll_next(&mut cx) {
+                    Poll::Ready(solution) => return solution,
+                    Poll::Pending => std::thread::park(),
+                }
+            }
+        };
+
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = WordList::Standard.words();
+        let mut stream = b.solve_async(words, &[], 2);
+        let mut expected = b.solve_iter(words, &[], 2);
+        for _ in 0..20 {
+            assert_eq!(next(&mut stream), expected.next());
+        }
+        // Dropping the stream partway through stops the search.
+        drop(stream);
+
+        let mut stream = b.solve_async_with_builtin_list(&["VEHICULAR"], 2);
+        let first = next(&mut stream).unwrap();
+        assert_eq!(first.words, vec!["VEHICULAR", "RITZILY"]);
+        let mut rest = 0;
+        while next(&mut stream).is_some() {
+            rest += 1;
+        }
+        assert_eq!(
+            rest + 1,
+            b.solve_iter_with_builtin_list(&["VEHICULAR"], 2).count()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let json = serde_json::to_string(&b).unwrap();
+        assert_eq!(json, r#"["ELZ","IVA","RYU","CTH"]"#);
+
+        let round_trip = serde_json::from_str::<LetterBoxed>(&json).unwrap();
+        assert_eq!(
+            round_trip.sides().collect::<Vec<_>>(),
+            b.sides().collect::<Vec<_>>()
+        );
+        let compact = serde_json::from_str::<LetterBoxed>(r#""elz-iva-ryu-cth""#).unwrap();
+        assert_eq!(
+            compact.sides().collect::<Vec<_>>(),
+            b.sides().collect::<Vec<_>>()
+        );
+
+        let err = serde_json::from_str::<LetterBoxed>(r#"["ELZ","ELA"]"#).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("appears on both side 1 and side 2"));
+
+        let b = b.with_metadata(BoardMetadata {
+            date: Some("2024-01-31".to_string()),
+            par: Some(5),
+            source: None,
+        });
+        let json = serde_json::to_string(&b).unwrap();
+        assert_eq!(
+            json,
+            r#"{"sides":["ELZ","IVA","RYU","CTH"],"date":"2024-01-31","par":5}"#
+        );
+        let round_trip = serde_json::from_str::<LetterBoxed>(&json).unwrap();
+        assert_eq!(round_trip.metadata(), b.metadata());
+        let compact =
+            serde_json::from_str::<LetterBoxed>(r#"{"sides":"ELZ-IVA-RYU-CTH","par":5}"#).unwrap();
+        assert_eq!(compact.metadata().par, Some(5));
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn test_arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let mut rng = crate::generator::Rng::new(7);
+        for _ in 0..20 {
+            let bytes = (0..256).map(|_| rng.next_u64() as u8).collect::<Vec<_>>();
+            let mut u = Unstructured::new(&bytes);
+            let b = LetterBoxed::arbitrary(&mut u).unwrap();
+            let sides = b.sides().collect::<Vec<_>>();
+            assert!(LetterBoxed::try_load_board(&sides).is_ok());
+
+            let word = b.arbitrary_word(&mut u).unwrap();
+            assert!(b.is_playable(&word), "{word} on {sides:?}");
+
+            // Every solution the solver returns validates. Large boards take
+            // too long to solve here.
+            if b.num_letters() > 12 {
+                continue;
+            }
+            for (words, score) in b.solve_with_builtin_list(&[], 2, 10) {
+                assert!(score < b.num_letters() || b.validate(&words));
+            }
+        }
+    }
+
+    #[cfg(feature = "archive")]
+    #[test]
+    fn test_archive() {
+        let text = "# comment\n\n2024-01-31 2 ELZ-IVA-RYU-CTH VEHICULAR RITZILY\n2024-02-01 3 ABC-DEF-GHI\n";
+        let puzzles = crate::archive::parse(text).unwrap();
+        assert_eq!(puzzles.len(), 2);
+        assert_eq!(puzzles[0].date, "2024-01-31");
+        assert_eq!(puzzles[0].par, 2);
+        assert_eq!(puzzles[0].solution, vec!["VEHICULAR", "RITZILY"]);
+        assert_eq!(
+            puzzles[0].board().unwrap().sides().collect::<Vec<_>>(),
+            vec!["ELZ", "IVA", "RYU", "CTH"]
+        );
+        assert_eq!(
+            puzzles[0].board().unwrap().metadata().date.as_deref(),
+            Some("2024-01-31")
+        );
+        assert!(puzzles[1].solution.is_empty());
+        assert_eq!(puzzles[0].to_line(), text.lines().nth(2).unwrap());
+        assert_eq!(crate::archive::parse("2024-01-31 two ELZ-IVA"), Err(1));
+        assert_eq!(crate::archive::parse("#\n2024-01-31"), Err(2));
+
+        // The builtin archive always parses.
+        assert!(crate::archive::puzzles().all(|p| p.board().is_ok()));
+    }
+
+    #[cfg(feature = "nyt")]
+    #[test]
+    fn test_nyt_parse_page() {
+        let page = r#"<script>window.gameData = {"sides":["ELZ","IVA","RYU","CTH"],"par":5,"printDate":"2024-01-31","ourSolution":["VEHICULAR","RITZILY"],"dictionary":["RITZILY","VEHICULAR"]}; window.other = {};</script>"#;
+        let puzzle = crate::nyt::parse_page(page).unwrap();
+        assert_eq!(
+            puzzle.board.sides().collect::<Vec<_>>(),
+            vec!["ELZ", "IVA", "RYU", "CTH"]
+        );
+        assert_eq!(puzzle.par, 5);
+        assert_eq!(puzzle.date, "2024-01-31");
+        assert_eq!(puzzle.board.metadata().par, Some(5));
+        assert_eq!(puzzle.solution, vec!["VEHICULAR", "RITZILY"]);
+        assert_eq!(puzzle.dictionary.len(), 2);
+
+        assert!(matches!(
+            crate::nyt::parse_page("<html></html>"),
+            Err(crate::nyt::NytError::MissingGameData)
+        ));
+    }
+
+    #[test]
+    fn test_word_lists() {
+        for list in WordList::ALL {
+            assert_eq!(WordList::from_name(list.name()), Some(*list));
+            assert!(!list.words().is_empty());
+        }
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let list = WordList::Standard;
+        let prior = list.indices(&["vehicular"]).unwrap();
+        let results = b.solve(list.words(), &prior, 2, 25);
+        assert_eq!(results[0].0, vec!["VEHICULAR", "RITZILY"]);
+        assert_eq!(list.indices(&["QWERTY"]), None);
+    }
+
+    #[allow(deprecated)]
+    #[test]
+    fn test_deadline() {
+        use std::time::{Duration, Instant};
+
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let mut iter = b
+            .solve_iter_with_builtin_list(&[], 4)
+            .with_deadline(Instant::now());
+        assert_eq!(iter.next(), None);
+        assert!(iter.timed_out());
+
+        let deadline = Instant::now() + Duration::from_secs(60);
+        let results = b.solve_until(&["VEHICULAR", "RITZILY"], &[], 2, 25, deadline);
+        assert_eq!(results.len(), 1);
+    }
+
+    #[test]
+    fn test_word_graph_dot() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["VEHICULAR", "RITZY", "RITZILY"];
+        let dot = b.word_graph_dot(&words, 1);
+        assert!(dot.starts_with("digraph {\n    subgraph cluster_0 {\n        label=\"top\";\n"));
+        assert!(dot.contains("    \"V\" -> \"R\" [label=\"VEHICULAR\"];\n"));
+        assert!(dot.contains("    \"R\" -> \"Y\" [label=\"RITZILY\\n(+1 more)\"];\n"));
+        assert!(b
+            .word_graph_dot(&words, 0)
+            .contains("[label=\"RITZILY\\nRITZY\"]"));
+    }
+
+    #[test]
+    fn test_nyt_rules() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["VEHICULAR", "Ritzily", "RITZY", "YEZ"];
+        assert_eq!(b.solve(&words, &[], 2, 0).len(), 2);
+
+        let b = b.with_rules(BoardRules::nyt());
+        assert!(!b.rules().proper_nouns());
+        assert_eq!(
+            b.solve(&words, &[], 2, 0)
+                .into_iter()
+                .map(|(w, _)| w)
+                .collect::<Vec<_>>(),
+            vec![vec!["VEHICULAR", "RITZY"]]
+        );
+
+        let (dictionary, dropped) = Dictionary::clean_without_proper_nouns("Ritzily\nritzy\nYEZ");
+        assert_eq!(dictionary.words(), vec!["RITZY", "YEZ"]);
+        assert_eq!(dropped[0].reason, DropReason::ProperNoun);
+
+        let report = b.rate_solution(&words, &["VEHICULAR", "RITZY"], 3).unwrap();
+        assert_eq!(
+            report.nyt_message(),
+            "Wow! You solved it in 2 words, under par of 3."
+        );
+        let report = b.rate_solution(&words, &["VEHICULAR", "RITZY"], 2).unwrap();
+        assert_eq!(
+            report.nyt_message(),
+            "Nice! You solved it in 2 words, right at par."
+        );
+    }
+
+    #[test]
+    fn test_dictionary_clean() {
+        let text = "vehicular\n  Ritzily \n\nrock'n'roll\nX-RAY\nR2D2\nVEHICULAR\nritzy";
+        let (dictionary, dropped) = Dictionary::clean(text);
+        assert_eq!(dictionary.words(), vec!["VEHICULAR", "RITZILY", "RITZY"]);
+        assert_eq!(
+            dropped
+                .iter()
+                .map(|d| (d.word.as_str(), d.reason))
+                .collect::<Vec<_>>(),
+            vec![
+                ("rock'n'roll", DropReason::NonAlphabetic('\'')),
+                ("X-RAY", DropReason::NonAlphabetic('-')),
+                ("R2D2", DropReason::NonAlphabetic('2')),
+                ("VEHICULAR", DropReason::Duplicate),
+            ]
+        );
+
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        assert_eq!(b.solve(&dictionary.words(), &[], 2, 0).len(), 2);
+    }
+
+    #[test]
+    fn test_board_metadata() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        assert!(b.metadata().is_empty());
+        let metadata = BoardMetadata {
+            par: Some(5),
+            ..BoardMetadata::default()
+        };
+        let b = b.with_metadata(metadata.clone());
+        assert_eq!(b.metadata(), &metadata);
+        assert!(!b.metadata().is_empty());
+    }
+
+    #[test]
+    fn test_combine() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+
+        let combination = b.combine(&["ritzily", "vehicular", "ZZZ", "RIVAL"]);
+        assert_eq!(
+            combination
+                .unplayable
+                .iter()
+                .map(|(w, _)| *w)
+                .collect::<Vec<_>>(),
+            vec!["ZZZ", "RIVAL"]
+        );
+        assert_eq!(combination.solutions.len(), 1);
+        assert_eq!(combination.best.words, vec!["vehicular", "ritzily"]);
+        assert!(combination.missing.is_empty());
+
+        let combination = b.combine(&["VEHICULAR", "RIZ"]);
+        assert!(combination.solutions.is_empty());
+        assert_eq!(combination.best.words, vec!["VEHICULAR", "RIZ"]);
+        assert_eq!(combination.missing, ['T', 'Y'].into_iter().collect());
+    }
+
+    #[test]
+    fn test_mutations() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["VEHICULAR", "RITZILY", "RITZY"];
+        let report = b.mutations(&words, 2);
+        assert_eq!(report.min_words, Some(2));
+        // 12 letters each replaced by 14 others, and 54 swaps across sides.
+        assert_eq!(report.mutations.len(), 12 * 14 + 54);
+
+        // Replacing the Z in both RITZ words leaves no solution.
+        let z = Position { side: 0, index: 2 };
+        let replaced = report
+            .mutations
+            .iter()
+            .find(|m| {
+                m.change
+                    == Change::Replace {
+                        position: z,
+                        letter: 'B',
+                    }
+            })
+            .unwrap();
+        assert_eq!(replaced.sides, vec!["ELB", "IVA", "RYU", "CTH"]);
+        assert_eq!(replaced.min_words, None);
+
+        // Swapping Z and T keeps every word playable.
+        let t = Position { side: 3, index: 1 };
+        let swapped = report
+            .mutations
+            .iter()
+            .find(|m| m.change == Change::Swap(z, t))
+            .unwrap();
+        assert_eq!(swapped.sides, vec!["ELT", "IVA", "RYU", "CZH"]);
+        assert_eq!(swapped.min_words, Some(2));
+    }
+
+    #[test]
+    fn test_rate_solution() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["VEHICULAR", "RITZILY", "RIZ", "ZITY"];
+
+        let report = b
+            .rate_solution(&words, &["VEHICULAR", "RITZILY"], 3)
+            .unwrap();
+        assert_eq!(report.rating, ParRating::UnderPar);
+        assert_eq!(report.optimal, Some(2));
+        assert!(report.is_optimal());
+
+        let report = b
+            .rate_solution(&words, &["VEHICULAR", "RIZ", "ZITY"], 3)
+            .unwrap();
+        assert_eq!(report.rating, ParRating::AtPar);
+        assert!(!report.is_optimal());
+        assert_eq!(
+            report.to_string(),
+            "3 words, at par of 3 (it can be solved in 2)"
+        );
+
+        let report = b
+            .rate_solution(&words, &["VEHICULAR", "RIZ", "ZITY"], 2)
+            .unwrap();
+        assert_eq!(report.rating, ParRating::OverPar);
+
+        assert!(b.rate_solution(&words, &["VEHICULAR"], 2).is_err());
+    }
+
+    #[test]
+    fn test_solve_with() {
+        use core::ops::ControlFlow;
+
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["VEHICULAR", "RITZILY", "RIZ", "ZITY", "RITZY"];
+        let mut found = vec![];
+        let flow = b.solve_with(&words, &[], 3, |s| {
+            found.push(s.words);
+            ControlFlow::<()>::Continue(())
+        });
+        assert_eq!(flow, ControlFlow::Continue(()));
+        assert_eq!(found.len(), 3);
+
+        // Stop as soon as there's a solution, returning it.
+        let flow = b.solve_with(&words, &[], 3, ControlFlow::Break);
+        let ControlFlow::Break(first) = flow else {
+            panic!("expected a solution");
+        };
+        assert_eq!(first.words, found[0]);
+    }
+
+    #[allow(deprecated)]
+    #[test]
+    fn test_cancellation() {
+        use alloc::sync::Arc;
+        use core::sync::atomic::{AtomicBool, Ordering};
+
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let cancel = Arc::new(AtomicBool::new(false));
+        let mut iter = b
+            .solve_iter_with_builtin_list(&[], 4)
+            .with_cancellation(cancel.clone());
+        assert!(!iter.cancelled());
+        cancel.store(true, Ordering::Relaxed);
+        assert_eq!(iter.next(), None);
+        assert!(iter.cancelled());
+        assert!(iter.timed_out());
+
+        let words = ["VEHICULAR", "RITZILY"];
+        let results = b.solve_cancellable(&words, &[], 2, 25, Arc::new(AtomicBool::new(false)));
+        assert_eq!(results.len(), 1);
+        // A search cancelled before it starts only has a partial solution.
+        let results = b.solve_cancellable(&words, &[], 2, 25, cancel);
+        assert_eq!(results.len(), 1);
+        assert!(results[0].1 < b.num_letters());
+    }
+
+    #[allow(deprecated)]
+    #[test]
+    fn test_progress() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let mut updates = vec![];
+        let results = b.solve_with_progress(&["VEHICULAR", "RITZILY"], &[], 2, 25, |p| {
+            updates.push(p.clone())
+        });
+        assert_eq!(results.len(), 1);
+        assert_eq!(updates.len(), 3);
+        assert_eq!(updates[0].states_expanded, 2);
+        assert_eq!(updates[1].best_letters_covered, 9);
+        assert_eq!(updates[2].states_expanded, 5);
+    }
+
+    #[test]
+    fn test_iterative_deepening() {
+        let b = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+        let words = ["VEHICULAR", "RITZILY", "RIZ", "ZITY", "RITZY", "YEAH"];
+        let bfs = b.solve_iter(&words, &[], 4).collect::<Vec<_>>();
+        let iddfs = b
+            .solve_iter(&words, &[], 4)
+            .with_strategy(SearchStrategy::IterativeDeepening)
+            .collect::<Vec<_>>();
+        assert_eq!(bfs.len(), 3);
+        assert_eq!(bfs, iddfs);
+
+        let iddfs = b
+            .solve_iter(&words, &[0], 2)
+            .with_strategy(SearchStrategy::IterativeDeepening)
+            .collect::<Vec<_>>();
+        assert_eq!(iddfs.len(), 2);
+    }
 }
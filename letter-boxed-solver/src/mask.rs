@@ -0,0 +1,97 @@
+use crate::prelude::*;
+
+/// The number of letters held in a [`Mask`] without allocating.
+const INLINE_LETTERS: usize = u128::BITS as usize;
+
+/// A set of board letters, as a bitmask over their positions in the board's
+/// sorted letters.
+///
+/// The first 128 letters are held inline, so masks for any ordinary board are
+/// cheap to copy and combine, and only boards with more letters than that
+/// spill the rest onto the heap.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct Mask {
+    low: u128,
+    /// The letters after the first 128, with no trailing empty words, so
+    /// that equal sets compare and hash equal.
+    high: Vec<u128>,
+}
+
+impl Mask {
+    /// Every letter of a board with `letters` letters.
+    pub(crate) fn full(letters: usize) -> Self {
+        let mut mask = Mask::default();
+        for bit in 0..letters {
+            mask.insert(bit);
+        }
+        mask
+    }
+
+    /// Add the letter at position `bit`.
+    pub(crate) fn insert(&mut self, bit: usize) {
+        if bit < INLINE_LETTERS {
+            self.low |= 1 << bit;
+            return;
+        }
+        let bit = bit - INLINE_LETTERS;
+        let word = bit / INLINE_LETTERS;
+        if self.high.len() <= word {
+            self.high.resize(word + 1, 0);
+        }
+        self.high[word] |= 1 << (bit % INLINE_LETTERS);
+    }
+
+    /// The letters in either mask.
+    #[inline]
+    pub(crate) fn union(&self, other: &Mask) -> Mask {
+        let mut mask = self.clone();
+        mask.union_with(other);
+        mask
+    }
+
+    /// Add every letter in `other`.
+    #[inline]
+    pub(crate) fn union_with(&mut self, other: &Mask) {
+        self.low |= other.low;
+        if other.high.is_empty() {
+            return;
+        }
+        if self.high.len() < other.high.len() {
+            self.high.resize(other.high.len(), 0);
+        }
+        for (word, other) in self.high.iter_mut().zip(&other.high) {
+            *word |= other;
+        }
+    }
+
+    /// Whether every letter in this mask is also in `other`.
+    #[inline]
+    pub(crate) fn is_subset(&self, other: &Mask) -> bool {
+        self.low & !other.low == 0
+            && self
+                .high
+                .iter()
+                .enumerate()
+                .all(|(i, word)| word & !other.high.get(i).copied().unwrap_or(0) == 0)
+    }
+
+    /// Whether no letter is in both masks.
+    pub(crate) fn is_disjoint(&self, other: &Mask) -> bool {
+        self.low & other.low == 0
+            && self
+                .high
+                .iter()
+                .zip(&other.high)
+                .all(|(word, other)| word & other == 0)
+    }
+
+    /// The number of letters in the mask.
+    pub(crate) fn len(&self) -> usize {
+        self.low.count_ones() as usize
+            + self
+                .high
+                .iter()
+                .map(|word| word.count_ones() as usize)
+                .sum::<usize>()
+    }
+}
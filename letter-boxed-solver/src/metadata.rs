@@ -0,0 +1,47 @@
+use crate::prelude::*;
+use crate::LetterBoxed;
+
+/// Optional information about where a board came from, e.g. a published
+/// puzzle's date and par. None of it affects solving.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoardMetadata {
+    /// The date the puzzle was published for, e.g. `"2024-01-31"`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub date: Option<String>,
+    /// The number of words the puzzle should be solved in.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub par: Option<usize>,
+    /// Where the puzzle was published, e.g. a URL.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub source: Option<String>,
+}
+
+impl BoardMetadata {
+    /// Whether nothing is known about the board.
+    pub fn is_empty(&self) -> bool {
+        self.date.is_none() && self.par.is_none() && self.source.is_none()
+    }
+}
+
+impl LetterBoxed {
+    /// Attach `metadata` to this board, replacing any it already had.
+    pub fn with_metadata(mut self, metadata: BoardMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// Information about where this board came from.
+    pub fn metadata(&self) -> &BoardMetadata {
+        &self.metadata
+    }
+}
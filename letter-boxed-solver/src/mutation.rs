@@ -0,0 +1,135 @@
+use crate::prelude::*;
+use crate::{LetterBoxed, Position};
+
+/// A single-letter change to a board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Change {
+    /// Replace the letter at `position` with `letter`, which isn't on the
+    /// board yet.
+    Replace { position: Position, letter: char },
+    /// Swap the letters at two positions on different sides.
+    Swap(Position, Position),
+}
+
+/// A board one change away from the original, as reported by
+/// [`LetterBoxed::mutations`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mutation {
+    /// How the board was changed.
+    pub change: Change,
+    /// The sides of the changed board.
+    pub sides: Vec<String>,
+    /// The fewest words the changed board can be solved in, or `None` if it
+    /// can't be solved within the maximum depth.
+    pub min_words: Option<usize>,
+}
+
+/// How changing a single letter affects a board, as reported by
+/// [`LetterBoxed::mutations`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MutationReport {
+    /// The fewest words the original board can be solved in, or `None` if it
+    /// can't be solved within the maximum depth.
+    pub min_words: Option<usize>,
+    /// Every board one change away, with replacements first, in board order.
+    pub mutations: Vec<Mutation>,
+}
+
+impl LetterBoxed {
+    /// Analyze single-letter changes to the board with the builtin word list.
+    /// See [`LetterBoxed::mutations`].
+    #[cfg(feature = "std")]
+    pub fn mutations_with_builtin_list(&self, max_depth: usize) -> MutationReport {
+        self.mutations(crate::builtin_words(), max_depth)
+    }
+
+    /// Analyze how replacing any letter with one from `A` to `Z` which isn't
+    /// on the board, or swapping any two letters on different sides, changes
+    /// the fewest words the board can be solved in with `words`, searching
+    /// up to `max_depth` words.
+    ///
+    /// This is meant for tuning boards, e.g. towards "solvable in 2, but not
+    /// in 1". Changed boards keep this board's rules and minimum word length.
+    /// Every changed board is solved, so this is much slower than solving
+    /// once.
+    pub fn mutations(&self, words: &[&str], max_depth: usize) -> MutationReport {
+        let mut positions = self
+            .letter_positions()
+            .into_values()
+            .flatten()
+            .collect::<Vec<_>>();
+        positions.sort();
+
+        let mut changes = vec![];
+        for position in &positions {
+            for letter in 'A'..='Z' {
+                if !self.letters.contains(&letter) {
+                    changes.push(Change::Replace {
+                        position: *position,
+                        letter,
+                    });
+                }
+            }
+        }
+        for (i, a) in positions.iter().enumerate() {
+            for b in &positions[i + 1..] {
+                if a.side != b.side {
+                    changes.push(Change::Swap(*a, *b));
+                }
+            }
+        }
+
+        let mutations = changes
+            .into_iter()
+            .map(|change| {
+                let sides = self.changed_sides(change);
+                let min_words = self.with_sides(&sides).min_words(words, max_depth);
+                Mutation {
+                    change,
+                    sides,
+                    min_words,
+                }
+            })
+            .collect();
+
+        MutationReport {
+            min_words: self.min_words(words, max_depth),
+            mutations,
+        }
+    }
+
+    /// The sides of the board after `change`.
+    fn changed_sides(&self, change: Change) -> Vec<String> {
+        let mut sides = self
+            .sides
+            .iter()
+            .map(|s| s.chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        match change {
+            Change::Replace { position, letter } => {
+                sides[position.side][position.index] = letter;
+            }
+            Change::Swap(a, b) => {
+                let tmp = sides[a.side][a.index];
+                sides[a.side][a.index] = sides[b.side][b.index];
+                sides[b.side][b.index] = tmp;
+            }
+        }
+        sides.into_iter().map(|s| s.into_iter().collect()).collect()
+    }
+
+    /// A board with `sides` and the same rules as this one.
+    fn with_sides(&self, sides: &[String]) -> LetterBoxed {
+        let sides = sides.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+        LetterBoxed::load_board(&sides)
+            .with_rules(self.rules.clone())
+            .with_min_word_length(self.min_word_length)
+    }
+
+    /// The fewest words the board can be solved in, up to `max_depth`.
+    fn min_words(&self, words: &[&str], max_depth: usize) -> Option<usize> {
+        self.solve_iter(words, &[], max_depth)
+            .next()
+            .map(|s| s.words.len())
+    }
+}
@@ -0,0 +1,126 @@
+//! Fetching the daily puzzle from the New York Times.
+
+use std::fmt;
+
+use serde::Deserialize;
+
+use crate::{BoardError, BoardMetadata, LetterBoxed};
+
+/// The page the daily puzzle is published on.
+pub const PUZZLE_URL: &str = "https://www.nytimes.com/puzzles/letter-boxed";
+
+/// The marker preceding the puzzle data embedded in the page.
+const GAME_DATA_MARKER: &str = "window.gameData";
+
+/// A day's puzzle, as published by the New York Times.
+#[derive(Debug)]
+pub struct DailyPuzzle {
+    /// The board, ready to solve, with the puzzle's date and par as its
+    /// metadata.
+    pub board: LetterBoxed,
+    /// The number of words the puzzle should be solved in.
+    pub par: usize,
+    /// The date the puzzle was published for, e.g. `"2024-01-31"`.
+    pub date: String,
+    /// The words of the official solution.
+    pub solution: Vec<String>,
+    /// The words the official game accepts for this puzzle.
+    pub dictionary: Vec<String>,
+}
+
+/// The puzzle data embedded in the page.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GameData {
+    sides: Vec<String>,
+    par: usize,
+    print_date: String,
+    #[serde(default)]
+    our_solution: Vec<String>,
+    #[serde(default)]
+    dictionary: Vec<String>,
+}
+
+/// Errors which can occur when fetching the daily puzzle.
+#[derive(Debug)]
+pub enum NytError {
+    /// The page couldn't be downloaded.
+    Http(Box<ureq::Error>),
+    /// The page couldn't be read.
+    Io(std::io::Error),
+    /// The page doesn't contain any puzzle data.
+    MissingGameData,
+    /// The puzzle data couldn't be parsed.
+    Json(serde_json::Error),
+    /// The puzzle data doesn't describe a valid board.
+    Board(BoardError),
+}
+
+impl fmt::Display for NytError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NytError::Http(e) => write!(f, "couldn't fetch the puzzle: {e}"),
+            NytError::Io(e) => write!(f, "couldn't read the puzzle: {e}"),
+            NytError::MissingGameData => write!(f, "the page doesn't contain a puzzle"),
+            NytError::Json(e) => write!(f, "couldn't parse the puzzle: {e}"),
+            NytError::Board(e) => write!(f, "the puzzle isn't a valid board: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for NytError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NytError::Http(e) => Some(e),
+            NytError::Io(e) => Some(e),
+            NytError::MissingGameData => None,
+            NytError::Json(e) => Some(e),
+            NytError::Board(e) => Some(e),
+        }
+    }
+}
+
+/// Fetch today's puzzle from the New York Times.
+pub fn fetch_today() -> Result<DailyPuzzle, NytError> {
+    let page = ureq::get(PUZZLE_URL)
+        .call()
+        .map_err(|e| NytError::Http(Box::new(e)))?
+        .into_string()
+        .map_err(NytError::Io)?;
+    parse_page(&page)
+}
+
+/// Extract the puzzle from the HTML of the puzzle page, as fetched by
+/// [`fetch_today`].
+pub fn parse_page(html: &str) -> Result<DailyPuzzle, NytError> {
+    let start = html
+        .find(GAME_DATA_MARKER)
+        .ok_or(NytError::MissingGameData)?;
+    let rest = &html[start + GAME_DATA_MARKER.len()..];
+    let rest = rest
+        .trim_start()
+        .strip_prefix('=')
+        .ok_or(NytError::MissingGameData)?;
+
+    // The data is followed by the rest of the script, so only parse the
+    // first value.
+    let mut de = serde_json::Deserializer::from_str(rest);
+    let data = GameData::deserialize(&mut de).map_err(NytError::Json)?;
+
+    let sides = data.sides.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+    let board = LetterBoxed::try_load_board(&sides)
+        .map_err(NytError::Board)?
+        .with_metadata(BoardMetadata {
+            date: Some(data.print_date.clone()),
+            par: Some(data.par),
+            source: Some(PUZZLE_URL.to_string()),
+        });
+
+    Ok(DailyPuzzle {
+        board,
+        par: data.par,
+        date: data.print_date,
+        solution: data.our_solution,
+        dictionary: data.dictionary,
+    })
+}
@@ -0,0 +1,81 @@
+use crate::prelude::*;
+use crate::search::Context;
+use crate::{result_limit, LetterBoxed};
+
+/// A recommended first word, as suggested by [`LetterBoxed::opening_words`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Opener<'word> {
+    /// The word.
+    pub word: &'word str,
+    /// How many distinct letters of the board the word covers.
+    pub letters_covered: usize,
+    /// How many words finish the board when played straight after this one.
+    pub finishers: usize,
+    /// How many complete solutions (within the maximum depth) start with
+    /// this word.
+    pub completions: usize,
+}
+
+impl LetterBoxed {
+    /// Recommend opening words from the builtin word list. See
+    /// [`LetterBoxed::opening_words`].
+    #[cfg(feature = "std")]
+    pub fn opening_words_with_builtin_list(
+        &self,
+        max_depth: usize,
+        n: usize,
+    ) -> Vec<Opener<'static>> {
+        self.opening_words(crate::builtin_words(), max_depth, n)
+    }
+
+    /// Recommend the top `n` (or, if it is 0, all) words in `words` to open
+    /// with, looking ahead at how the game can continue rather than only at
+    /// how many letters each word covers: a long word with no way to finish
+    /// is worse than a shorter one with dozens of finishers.
+    ///
+    /// Openers are ranked by how many complete solutions of at most
+    /// `max_depth` words start with them, then by how many words finish the
+    /// board right after them, then by letters covered, and then
+    /// alphabetically. Words which can't start any solution are omitted.
+    pub fn opening_words<'word>(
+        &self,
+        words: &[&'word str],
+        max_depth: usize,
+        n: usize,
+    ) -> Vec<Opener<'word>> {
+        let ctx = Context::new(self, words, max_depth);
+
+        let mut openers = ctx
+            .initial_frontier(&[])
+            .iter()
+            .flat_map(|s| ctx.expand(s))
+            .filter_map(|state| {
+                let completions = ctx.count_complete(vec![state.clone()]);
+                if completions == 0 {
+                    return None;
+                }
+                let finishers = ctx
+                    .expand(&state)
+                    .iter()
+                    .filter(|s| ctx.is_complete(s))
+                    .count();
+                Some(Opener {
+                    word: words[state.path.last().unwrap()],
+                    letters_covered: state.letters_covered(),
+                    finishers,
+                    completions,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        openers.sort_by(|a, b| {
+            b.completions
+                .cmp(&a.completions)
+                .then(b.finishers.cmp(&a.finishers))
+                .then(b.letters_covered.cmp(&a.letters_covered))
+                .then(a.word.cmp(b.word))
+        });
+        openers.truncate(result_limit(n));
+        openers
+    }
+}
@@ -0,0 +1,298 @@
+use alloc::borrow::Cow;
+use alloc::sync::Arc;
+use core::sync::atomic::AtomicBool;
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+use crate::prelude::*;
+use crate::search::{Context, SolutionIter};
+use crate::{find_word, result_limit, LetterBoxed, Ranking, SearchStrategy, Solution, WordGraph};
+
+/// Settings for [`LetterBoxed::solve_with_options`], collected in one place
+/// rather than passed positionally.
+///
+/// ```
+/// use letter_boxed_solver::{LetterBoxed, Ranking, SolveOptions};
+///
+/// let board = LetterBoxed::load_board(&["ELZ", "IVA", "RYU", "CTH"]);
+/// let words = ["VEHICULAR", "RITZILY", "VEHICULARLY"];
+/// let options = SolveOptions::new()
+///     .max_depth(2)
+///     .excluded(&["VEHICULARLY"])
+///     .ranking(Ranking::FewestLetters);
+/// let solutions = board.solve_with_options(&words, &options).unwrap();
+/// assert_eq!(solutions[0].words, ["VEHICULAR", "RITZILY"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SolveOptions<'a> {
+    max_depth: usize,
+    max_results: usize,
+    prior_words: PriorWords<'a>,
+    min_word_length: Option<usize>,
+    excluded: Vec<&'a str>,
+    required: Option<&'a str>,
+    strategy: SearchStrategy,
+    dominance_pruning: bool,
+    state_dedup: bool,
+    beam_width: Option<usize>,
+    dedup: bool,
+    max_per_start_word: Option<usize>,
+    #[cfg(feature = "std")]
+    deadline: Option<Instant>,
+    cancellation: Option<Arc<AtomicBool>>,
+    ranking: Option<Ranking<'a>>,
+}
+
+/// The words already played, as given to [`SolveOptions`].
+#[derive(Debug, Clone)]
+enum PriorWords<'a> {
+    /// Words to look up in the word list.
+    Words(Vec<&'a str>),
+    /// Indices into the word list.
+    Indices(Vec<usize>),
+}
+
+impl Default for SolveOptions<'_> {
+    fn default() -> Self {
+        SolveOptions {
+            max_depth: 3,
+            max_results: 10,
+            prior_words: PriorWords::Indices(vec![]),
+            min_word_length: None,
+            excluded: vec![],
+            required: None,
+            strategy: SearchStrategy::default(),
+            dominance_pruning: false,
+            state_dedup: false,
+            beam_width: None,
+            dedup: false,
+            max_per_start_word: None,
+            #[cfg(feature = "std")]
+            deadline: None,
+            cancellation: None,
+            ranking: None,
+        }
+    }
+}
+
+impl<'a> SolveOptions<'a> {
+    /// Options for finding up to 10 solutions of at most three words, with
+    /// the board's own minimum word length and no other restrictions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the most words a solution may have, including prior words.
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Set how many solutions to return, or 0 for all of them.
+    pub fn max_results(mut self, max_results: usize) -> Self {
+        self.max_results = max_results;
+        self
+    }
+
+    /// Set the words which have already been played, in order. These are
+    /// matched against the word list ignoring case and Unicode normalization.
+    pub fn prior_words(mut self, prior_words: &[&'a str]) -> Self {
+        self.prior_words = PriorWords::Words(prior_words.to_vec());
+        self
+    }
+
+    /// Set the words which have already been played, in order, as indices
+    /// into the word list, as for [`LetterBoxed::solve`].
+    pub fn prior_words_indices(mut self, prior_words_indices: &[usize]) -> Self {
+        self.prior_words = PriorWords::Indices(prior_words_indices.to_vec());
+        self
+    }
+
+    /// Override the board's shortest playable word. See
+    /// [`LetterBoxed::with_min_word_length`].
+    pub fn min_word_length(mut self, min_word_length: usize) -> Self {
+        self.min_word_length = Some(min_word_length);
+        self
+    }
+
+    /// Never use any of the words in `exclude`. See
+    /// [`SolutionIter::with_excluded`].
+    pub fn excluded(mut self, exclude: &[&'a str]) -> Self {
+        self.excluded = exclude.to_vec();
+        self
+    }
+
+    /// Only return solutions which use `word` somewhere. See
+    /// [`SolutionIter::with_required`].
+    pub fn required(mut self, word: &'a str) -> Self {
+        self.required = Some(word);
+        self
+    }
+
+    /// Set the order in which the search explores the word graph.
+    pub fn strategy(mut self, strategy: SearchStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Skip states reached with more words than before. See
+    /// [`SolutionIter::with_dominance_pruning`].
+    pub fn dominance_pruning(mut self, prune: bool) -> Self {
+        self.dominance_pruning = prune;
+        self
+    }
+
+    /// Skip states reached with as many words as before. See
+    /// [`SolutionIter::with_state_dedup`].
+    pub fn state_dedup(mut self, dedup: bool) -> Self {
+        self.state_dedup = dedup;
+        self
+    }
+
+    /// Only keep the `width` most promising states at each level of a
+    /// breadth-first search. See [`SolutionIter::with_beam_width`].
+    pub fn beam_width(mut self, width: usize) -> Self {
+        self.beam_width = Some(width);
+        self
+    }
+
+    /// Skip solutions which use the same words as an earlier one. See
+    /// [`SolutionIter::with_dedup`].
+    pub fn dedup(mut self, dedup: bool) -> Self {
+        self.dedup = dedup;
+        self
+    }
+
+    /// Return at most `max` solutions starting with each word. See
+    /// [`SolutionIter::with_max_per_start_word`].
+    pub fn max_per_start_word(mut self, max: usize) -> Self {
+        self.max_per_start_word = Some(max);
+        self
+    }
+
+    /// Stop searching once `deadline` passes, returning whatever was found
+    /// by then. Note that `Instant` is not available on
+    /// `wasm32-unknown-unknown`.
+    #[cfg(feature = "std")]
+    pub fn deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Stop searching once `cancel` is set to `true`, returning whatever was
+    /// found by then. See [`SolutionIter::with_cancellation`].
+    pub fn cancellation(mut self, cancel: Arc<AtomicBool>) -> Self {
+        self.cancellation = Some(cancel);
+        self
+    }
+
+    /// Order the results by `ranking` rather than in the order the search
+    /// finds them. As with [`LetterBoxed::solve_ranked`], this has to find
+    /// every solution within the maximum depth before ranking them.
+    pub fn ranking(mut self, ranking: Ranking<'a>) -> Self {
+        self.ranking = Some(ranking);
+        self
+    }
+
+    /// Collect the results from `iter`, which was built from these options:
+    /// up to the maximum number, ranked if asked, or the best partial
+    /// solution if there are none.
+    pub(crate) fn collect<'word>(
+        &self,
+        iter: &mut SolutionIter<'_, 'word>,
+    ) -> Vec<Solution<'word>> {
+        let mut results = match &self.ranking {
+            Some(_) => iter.by_ref().collect::<Vec<_>>(),
+            None => iter.by_ref().take(result_limit(self.max_results)).collect(),
+        };
+        if results.is_empty() {
+            results.push(iter.best_partial());
+        }
+        if let Some(ranking) = &self.ranking {
+            ranking.sort(&mut results);
+            results.truncate(result_limit(self.max_results));
+        }
+        results
+    }
+}
+
+impl LetterBoxed {
+    /// Solve using the builtin word list. See
+    /// [`LetterBoxed::solve_with_options`].
+    #[cfg(feature = "std")]
+    pub fn solve_with_options_with_builtin_list(
+        &self,
+        options: &SolveOptions<'_>,
+    ) -> Option<Vec<Solution<'static>>> {
+        self.solve_with_options(crate::builtin_words(), options)
+    }
+
+    /// Solve using a provided word list with all of the settings in
+    /// `options`.
+    ///
+    /// As with [`LetterBoxed::solve`], if there are no complete solutions the
+    /// best partial solution is returned instead. Returns `None` if any of the
+    /// prior words is not in `words`.
+    pub fn solve_with_options<'word>(
+        &self,
+        words: &[&'word str],
+        options: &SolveOptions<'_>,
+    ) -> Option<Vec<Solution<'word>>> {
+        let mut iter = self.solve_iter_with_options(words, options)?;
+        Some(options.collect(&mut iter))
+    }
+
+    /// Lazily solve using a provided word list with the settings in
+    /// `options`, e.g. to also register a progress callback or read the
+    /// [statistics](SolutionIter::statistics) afterwards. The maximum number
+    /// of results and the ranking don't apply, since the iterator yields
+    /// solutions as it finds them.
+    ///
+    /// Returns `None` if any of the prior words is not in `words`.
+    pub fn solve_iter_with_options<'a, 'word>(
+        &'a self,
+        words: &'a [&'word str],
+        options: &SolveOptions<'_>,
+    ) -> Option<SolutionIter<'a, 'word>> {
+        let prior_words_indices = match &options.prior_words {
+            PriorWords::Words(prior_words) => Cow::Owned(
+                prior_words
+                    .iter()
+                    .map(|w| find_word(words, w))
+                    .collect::<Option<Vec<_>>>()?,
+            ),
+            PriorWords::Indices(indices) => Cow::Borrowed(indices),
+        };
+
+        let graph = match options.min_word_length {
+            Some(len) => WordGraph::with_min_word_length(self, words, len),
+            None => WordGraph::new(self, words),
+        };
+        let ctx = Context::with_graph(Cow::Owned(graph), options.max_depth);
+        let mut iter = SolutionIter::new(ctx, &prior_words_indices)
+            .with_strategy(options.strategy)
+            .with_dominance_pruning(options.dominance_pruning)
+            .with_state_dedup(options.state_dedup)
+            .with_dedup(options.dedup);
+        if !options.excluded.is_empty() {
+            iter = iter.with_excluded(&options.excluded);
+        }
+        if let Some(word) = options.required {
+            iter = iter.with_required(word);
+        }
+        if let Some(width) = options.beam_width {
+            iter = iter.with_beam_width(width);
+        }
+        if let Some(max) = options.max_per_start_word {
+            iter = iter.with_max_per_start_word(max);
+        }
+        #[cfg(feature = "std")]
+        if let Some(deadline) = options.deadline {
+            iter = iter.with_deadline(deadline);
+        }
+        if let Some(cancel) = &options.cancellation {
+            iter = iter.with_cancellation(cancel.clone());
+        }
+        Some(iter)
+    }
+}
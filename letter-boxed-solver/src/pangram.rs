@@ -0,0 +1,34 @@
+use crate::prelude::*;
+use crate::{LetterBoxed, WordGraph};
+
+impl<'word> WordGraph<'_, 'word> {
+    /// The playable words which use every letter on the board by themselves,
+    /// i.e. the one-word solutions, in the order of the original word list.
+    pub fn pangrams(&self) -> Vec<&'word str> {
+        let mut indices = self
+            .word_letters
+            .keys()
+            .copied()
+            .filter(|idx| self.is_pangram(*idx))
+            .collect::<Vec<_>>();
+        indices.sort_unstable();
+        indices.into_iter().map(|idx| self.words[idx]).collect()
+    }
+}
+
+impl LetterBoxed {
+    /// Find the words in the builtin word list which solve the board by
+    /// themselves. See [`LetterBoxed::pangrams`].
+    #[cfg(feature = "std")]
+    pub fn pangrams_with_builtin_list(&self) -> Vec<&'static str> {
+        self.pangrams(crate::builtin_words())
+    }
+
+    /// Find the words in `words` which solve the board by themselves.
+    ///
+    /// This only checks each word on its own, so it is much faster than
+    /// searching for one-word solutions.
+    pub fn pangrams<'word>(&self, words: &[&'word str]) -> Vec<&'word str> {
+        WordGraph::new(self, words).pangrams()
+    }
+}
@@ -0,0 +1,126 @@
+use core::cmp::Ordering;
+use core::fmt;
+
+use crate::prelude::*;
+use crate::{LetterBoxed, Violation};
+
+/// How a solution's word count compares to the puzzle's par.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ParRating {
+    UnderPar,
+    AtPar,
+    OverPar,
+}
+
+impl fmt::Display for ParRating {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParRating::UnderPar => write!(f, "under par"),
+            ParRating::AtPar => write!(f, "at par"),
+            ParRating::OverPar => write!(f, "over par"),
+        }
+    }
+}
+
+/// How a valid solution scores against the puzzle's par, as reported by
+/// [`LetterBoxed::rate_solution`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParReport {
+    /// The number of words in the solution.
+    pub words: usize,
+    /// The par the solution was rated against.
+    pub par: usize,
+    /// How `words` compares to `par`.
+    pub rating: ParRating,
+    /// The fewest words any solution with the dictionary needs, or `None` if
+    /// the dictionary has no solution at all of at most `words` words (e.g.
+    /// because the solution uses words which aren't in it).
+    pub optimal: Option<usize>,
+}
+
+impl ParReport {
+    /// Whether the solution uses as few words as possible.
+    pub fn is_optimal(&self) -> bool {
+        self.optimal.is_none_or(|optimal| self.words <= optimal)
+    }
+
+    /// A congratulation in the style of the New York Times app, e.g. "Nice!
+    /// You solved it in 4 words, right at par."
+    pub fn nyt_message(&self) -> String {
+        match self.rating {
+            ParRating::UnderPar => format!(
+                "Wow! You solved it in {} words, under par of {}.",
+                self.words, self.par
+            ),
+            ParRating::AtPar => {
+                format!("Nice! You solved it in {} words, right at par.", self.words)
+            }
+            ParRating::OverPar => format!(
+                "You solved it in {} words. Try to solve it in {}.",
+                self.words, self.par
+            ),
+        }
+    }
+}
+
+impl fmt::Display for ParReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} words, {} of {}", self.words, self.rating, self.par)?;
+        match self.optimal {
+            Some(optimal) if optimal < self.words => {
+                write!(f, " (it can be solved in {optimal})")
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl LetterBoxed {
+    /// Rate a solution against `par` using the builtin word list. See
+    /// [`LetterBoxed::rate_solution`].
+    #[cfg(feature = "std")]
+    pub fn rate_solution_with_builtin_list(
+        &self,
+        solution: &[&str],
+        par: usize,
+    ) -> Result<ParReport, Vec<Violation>> {
+        self.rate_solution(crate::builtin_words(), solution, par)
+    }
+
+    /// Rate a solution (in any case) against the puzzle's `par`, e.g. "try
+    /// to solve in 4 words", and find the fewest words it could have been
+    /// solved in with `words` for comparison.
+    ///
+    /// Returns every rule the solution breaks if it isn't valid and complete,
+    /// as in [`LetterBoxed::validate_detailed`]. The solution's words don't
+    /// have to be in `words`.
+    pub fn rate_solution(
+        &self,
+        words: &[&str],
+        solution: &[&str],
+        par: usize,
+    ) -> Result<ParReport, Vec<Violation>> {
+        let violations = self.validate_detailed(solution);
+        if !violations.is_empty() {
+            return Err(violations);
+        }
+
+        let rating = match solution.len().cmp(&par) {
+            Ordering::Less => ParRating::UnderPar,
+            Ordering::Equal => ParRating::AtPar,
+            Ordering::Greater => ParRating::OverPar,
+        };
+        // There's no need to look for solutions longer than this one.
+        let optimal = self
+            .solve_iter(words, &[], solution.len())
+            .next()
+            .map(|s| s.words.len());
+
+        Ok(ParReport {
+            words: solution.len(),
+            par,
+            rating,
+            optimal,
+        })
+    }
+}
@@ -0,0 +1,93 @@
+use alloc::borrow::Cow;
+use alloc::collections::BinaryHeap;
+use core::cmp::Reverse;
+
+use crate::prelude::*;
+use crate::search::Context;
+use crate::{result_limit, LetterBoxed, Solution, WordGraph};
+
+impl<'word> WordGraph<'_, 'word> {
+    /// Find the best partial solutions as in [`LetterBoxed::solve_partial`],
+    /// reusing this graph.
+    pub fn solve_partial(
+        &self,
+        prior_words_indices: &[usize],
+        max_depth: usize,
+        max_results: usize,
+    ) -> Vec<Solution<'word>> {
+        let ctx = Context::with_graph(Cow::Borrowed(self), max_depth);
+
+        // The best partial solutions so far, with the worst on top, ranked
+        // by letters covered, then fewest words, then the order they were
+        // found in.
+        let mut best = BinaryHeap::new();
+        let mut found = 0;
+
+        let mut stack = ctx.initial_frontier(prior_words_indices);
+        while let Some(state) = stack.pop() {
+            if ctx.is_complete(&state) {
+                continue;
+            }
+            if state.path.len() > prior_words_indices.len() {
+                best.push(Reverse((
+                    state.letters_covered(),
+                    Reverse(state.path.len()),
+                    Reverse(found),
+                    state.path.to_vec(),
+                )));
+                found += 1;
+                if best.len() > result_limit(max_results) {
+                    best.pop();
+                }
+            }
+            // Push in reverse so that ties are found in the same order as a
+            // breadth-first search would find them.
+            stack.extend(ctx.expand(&state).into_iter().rev());
+        }
+
+        let mut best = best.into_vec();
+        best.sort();
+        best.into_iter()
+            .map(|Reverse((letters_covered, _, _, path))| ctx.solution(&path, letters_covered))
+            .collect()
+    }
+}
+
+impl LetterBoxed {
+    /// Find the best partial solutions using the builtin word list. See
+    /// [`LetterBoxed::solve_partial`].
+    ///
+    /// This will crash if an element in `prior_words` is not in the builtin
+    /// word list.
+    #[cfg(feature = "std")]
+    pub fn solve_partial_with_builtin_list(
+        &self,
+        prior_words: &[&str],
+        max_depth: usize,
+        max_results: usize,
+    ) -> Vec<Solution<'static>> {
+        let words = crate::builtin_words();
+        let prior_words_indices = crate::builtin_indices(prior_words);
+        self.solve_partial(words, &prior_words_indices, max_depth, max_results)
+    }
+
+    /// Find up to `max_results` (or, if it is 0, all) partial solutions, i.e.
+    /// sequences of at most `max_depth` words (including the prior words)
+    /// which don't cover every letter, ranked by the most letters covered and
+    /// then the fewest words.
+    ///
+    /// Unlike [`LetterBoxed::solve`], which only falls back to a single
+    /// partial solution when there are no complete ones, this never returns
+    /// a complete solution. It has to explore every sequence of at most
+    /// `max_depth` words, so it is slower than finding complete solutions.
+    pub fn solve_partial<'word>(
+        &self,
+        words: &[&'word str],
+        prior_words_indices: &[usize],
+        max_depth: usize,
+        max_results: usize,
+    ) -> Vec<Solution<'word>> {
+        self.word_graph(words)
+            .solve_partial(prior_words_indices, max_depth, max_results)
+    }
+}
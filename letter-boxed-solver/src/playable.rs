@@ -0,0 +1,130 @@
+use alloc::collections::BTreeSet;
+use core::cmp::Reverse;
+
+use crate::prelude::*;
+use crate::{letters, normalize, result_limit, FrequencyTable, LetterBoxed, WordGraph};
+
+/// How to order playable words, as used by [`LetterBoxed::playable_words`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum WordOrder {
+    /// The order of the original word list.
+    #[default]
+    WordList,
+    /// Longest words first.
+    Length,
+    /// Words covering the most distinct letters first, then longest first.
+    Coverage,
+}
+
+impl WordOrder {
+    /// Sort `words` according to this order. Ties keep their original order.
+    pub fn sort(&self, words: &mut [&str]) {
+        match self {
+            WordOrder::WordList => {}
+            WordOrder::Length => words.sort_by_key(|w| core::cmp::Reverse(letters(w).count())),
+            WordOrder::Coverage => words.sort_by_cached_key(|w| {
+                let mut distinct = letters(w).collect::<Vec<_>>();
+                let len = distinct.len();
+                distinct.sort_unstable();
+                distinct.dedup();
+                core::cmp::Reverse((distinct.len(), len))
+            }),
+        }
+    }
+}
+
+impl<'word> WordGraph<'_, 'word> {
+    /// The playable words which start with `letter` (in any case), in the
+    /// order of the original word list, e.g. to browse the legal continuations
+    /// after playing a word ending in that letter.
+    pub fn playable_words_from(&self, letter: char) -> Vec<&'word str> {
+        let mut indices = self
+            .graph
+            .get(&normalize(letter))
+            .into_iter()
+            .flat_map(|options| options.values().flatten().copied())
+            .collect::<Vec<_>>();
+        indices.sort_unstable();
+        indices.into_iter().map(|i| self.words[i]).collect()
+    }
+}
+
+impl LetterBoxed {
+    /// Every word in the builtin word list which is playable on this board.
+    /// See [`LetterBoxed::playable_words`].
+    #[cfg(feature = "std")]
+    pub fn playable_words_with_builtin_list(&self, order: WordOrder) -> Vec<&'static str> {
+        self.playable_words(crate::builtin_words(), order)
+    }
+
+    /// Every word in `words` which is playable on this board, i.e. which could
+    /// be part of a solution, in the given order.
+    pub fn playable_words<'word>(&self, words: &[&'word str], order: WordOrder) -> Vec<&'word str> {
+        let mut playable = WordGraph::new(self, words)
+            .playable_words()
+            .collect::<Vec<_>>();
+        order.sort(&mut playable);
+        playable
+    }
+
+    /// Every word in the builtin word list which is playable on this board
+    /// and starts with `letter`. See [`LetterBoxed::playable_words_from`].
+    #[cfg(feature = "std")]
+    pub fn playable_words_from_with_builtin_list(
+        &self,
+        letter: char,
+        order: WordOrder,
+    ) -> Vec<&'static str> {
+        self.playable_words_from(crate::builtin_words(), letter, order)
+    }
+
+    /// Every word in `words` which is playable on this board and starts with
+    /// `letter` (in any case), in the given order. After playing a word, these
+    /// are the legal continuations from its last letter.
+    pub fn playable_words_from<'word>(
+        &self,
+        words: &[&'word str],
+        letter: char,
+        order: WordOrder,
+    ) -> Vec<&'word str> {
+        let mut playable = WordGraph::new(self, words).playable_words_from(letter);
+        order.sort(&mut playable);
+        playable
+    }
+
+    /// The best opening words in the builtin word list. See
+    /// [`LetterBoxed::best_coverage_words`].
+    #[cfg(feature = "std")]
+    pub fn best_coverage_words_with_builtin_list(
+        &self,
+        n: usize,
+        frequencies: Option<&FrequencyTable>,
+    ) -> Vec<(&'static str, usize)> {
+        self.best_coverage_words(crate::builtin_words(), n, frequencies)
+    }
+
+    /// The top `n` (or, if it is 0, all) playable words in `words`, with how
+    /// many distinct letters of the board each covers, most first. This is a
+    /// quick way to find a strong opening word.
+    ///
+    /// Ties are broken by the most common word first if `frequencies` is
+    /// given (with unranked words last), then by the longest word, and then
+    /// by the order of `words`.
+    pub fn best_coverage_words<'word>(
+        &self,
+        words: &[&'word str],
+        n: usize,
+        frequencies: Option<&FrequencyTable>,
+    ) -> Vec<(&'word str, usize)> {
+        let mut playable = WordGraph::new(self, words)
+            .playable_words()
+            .map(|w| (w, letters(w).collect::<BTreeSet<_>>().len()))
+            .collect::<Vec<_>>();
+        playable.sort_by_cached_key(|(w, covered)| {
+            let rank = frequencies.map(|f| f.rank(w).unwrap_or(usize::MAX));
+            (Reverse(*covered), rank, Reverse(letters(w).count()))
+        });
+        playable.truncate(result_limit(n));
+        playable
+    }
+}
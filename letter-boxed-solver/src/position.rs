@@ -0,0 +1,97 @@
+use alloc::collections::BTreeMap;
+
+use crate::prelude::*;
+use crate::{letters, normalize, LetterBoxed};
+
+/// Where a letter is on the board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position {
+    /// The index of the side, in the order the sides were loaded.
+    pub side: usize,
+    /// The index of the letter along its side.
+    pub index: usize,
+}
+
+impl LetterBoxed {
+    /// Every letter on the board, mapped to where it is. A letter is at more
+    /// than one position if the board repeats it.
+    ///
+    /// This is useful for drawing the board, or highlighting the letters a
+    /// word touches.
+    pub fn letter_positions(&self) -> BTreeMap<char, Vec<Position>> {
+        let mut positions = BTreeMap::<char, Vec<Position>>::new();
+        for (side, letters) in self.sides.iter().enumerate() {
+            for (index, c) in letters.chars().enumerate() {
+                positions
+                    .entry(c)
+                    .or_default()
+                    .push(Position { side, index });
+            }
+        }
+        positions
+    }
+
+    /// Where `letter` (in any case) is on the board, which is empty if it
+    /// isn't on the board at all.
+    pub fn positions_of(&self, letter: char) -> Vec<Position> {
+        let letter = normalize(letter);
+        self.letter_positions().remove(&letter).unwrap_or_default()
+    }
+
+    /// Trace `solution` on the board, returning the position of each letter
+    /// in the order they are drawn, e.g. to animate it. The letter shared by
+    /// the end of one word and the start of the next is only drawn once.
+    ///
+    /// If the board repeats a letter, a position is chosen which can be
+    /// reached from the letter before it and can reach the letter after it.
+    /// Returns `None` if the words don't chain together, or can't be traced
+    /// on the board at all. Words are case-insensitive.
+    pub fn trace(&self, solution: &[&str]) -> Option<Vec<Position>> {
+        let mut drawn = vec![];
+        for word in solution {
+            let mut word_letters = letters(word);
+            if let Some(last) = drawn.last() {
+                if word_letters.next()? != *last {
+                    return None;
+                }
+            }
+            drawn.extend(word_letters);
+        }
+
+        // For each letter drawn, the positions it could be at, along with
+        // the index of the position of the letter before it in the previous
+        // layer.
+        let positions = self.letter_positions();
+        let mut layers: Vec<Vec<(Position, usize)>> = vec![];
+        for c in &drawn {
+            let options = positions.get(c)?;
+            let layer = match layers.last() {
+                Some(prev) => options
+                    .iter()
+                    .filter_map(|p| {
+                        let from = prev
+                            .iter()
+                            .position(|(q, _)| self.rules().can_follow(*q, *p))?;
+                        Some((*p, from))
+                    })
+                    .collect::<Vec<_>>(),
+                None => options.iter().map(|p| (*p, 0)).collect(),
+            };
+            if layer.is_empty() {
+                return None;
+            }
+            layers.push(layer);
+        }
+
+        // Walk back from any position the last letter could be at.
+        let mut trace = vec![];
+        let mut idx = 0;
+        for layer in layers.iter().rev() {
+            let (position, from) = layer[idx];
+            trace.push(position);
+            idx = from;
+        }
+        trace.reverse();
+        Some(trace)
+    }
+}
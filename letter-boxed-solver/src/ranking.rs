@@ -0,0 +1,120 @@
+use core::fmt;
+
+use crate::prelude::*;
+use crate::{letters, result_limit, FrequencyTable, LetterBoxed, Solution};
+
+/// How to order solutions, as used by [`LetterBoxed::solve_ranked`]. Ties
+/// are broken alphabetically (comparing the first words, then the second
+/// words, and so on), so the order doesn't depend on how the search found
+/// the solutions.
+#[derive(Clone, Copy)]
+pub enum Ranking<'a> {
+    /// Fewest words first. This is the order the search finds solutions in.
+    FewestWords,
+    /// Alphabetically, comparing the first words, then the second words, and
+    /// so on.
+    Alphabetical,
+    /// Fewest total letters first, then fewest words.
+    FewestLetters,
+    /// Most common vocabulary first, i.e. solutions whose least common word
+    /// is as common as possible, then fewest words.
+    ///
+    /// The function returns the frequency rank of a word (lower is more
+    /// common), or `None` if it is unknown, in which case the word is treated
+    /// as less common than any ranked word.
+    CommonVocabulary(&'a dyn Fn(&str) -> Option<usize>),
+    /// Most common vocabulary first, as in [`Ranking::CommonVocabulary`],
+    /// using the ranks from a frequency table.
+    Frequency(&'a FrequencyTable),
+    /// Lowest score first, according to a custom scoring function.
+    Custom(&'a dyn Fn(&Solution<'_>) -> i64),
+}
+
+impl fmt::Debug for Ranking<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Ranking::FewestWords => write!(f, "FewestWords"),
+            Ranking::Alphabetical => write!(f, "Alphabetical"),
+            Ranking::FewestLetters => write!(f, "FewestLetters"),
+            // Functions can't be printed.
+            Ranking::CommonVocabulary(_) => write!(f, "CommonVocabulary(..)"),
+            Ranking::Frequency(table) => f.debug_tuple("Frequency").field(table).finish(),
+            Ranking::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+impl Ranking<'_> {
+    /// Sort `solutions` according to this ranking.
+    pub fn sort(&self, solutions: &mut [Solution<'_>]) {
+        // Sorting is stable, so sorting alphabetically first breaks ties.
+        solutions.sort_by(|a, b| a.words.cmp(&b.words));
+        match self {
+            Ranking::FewestWords => solutions.sort_by_key(|s| s.words.len()),
+            Ranking::Alphabetical => {}
+            Ranking::FewestLetters => solutions.sort_by_key(|s| {
+                let total = s.words.iter().map(|w| letters(w).count()).sum::<usize>();
+                (total, s.words.len())
+            }),
+            Ranking::CommonVocabulary(rank) => sort_by_rarest_word(solutions, rank),
+            Ranking::Frequency(table) => sort_by_rarest_word(solutions, |w| table.rank(w)),
+            Ranking::Custom(score) => solutions.sort_by_key(|s| score(s)),
+        }
+    }
+}
+
+/// Sort solutions so that those whose least common word is most common come
+/// first, then by fewest words. Unranked words are the least common.
+fn sort_by_rarest_word(solutions: &mut [Solution<'_>], rank: impl Fn(&str) -> Option<usize>) {
+    solutions.sort_by_key(|s| {
+        let rarest = s.words.iter().map(|w| rank(w).unwrap_or(usize::MAX)).max();
+        (rarest, s.words.len())
+    });
+}
+
+impl LetterBoxed {
+    /// Solve using the builtin word list, ordering the results. See
+    /// [`LetterBoxed::solve_ranked`].
+    ///
+    /// This will crash if an element in `prior_words` is not in the builtin
+    /// word list.
+    #[cfg(feature = "std")]
+    pub fn solve_ranked_with_builtin_list(
+        &self,
+        prior_words: &[&str],
+        max_depth: usize,
+        max_results: usize,
+        ranking: &Ranking<'_>,
+    ) -> Vec<Solution<'static>> {
+        let words = crate::builtin_words();
+        let prior_words_indices = crate::builtin_indices(prior_words);
+        self.solve_ranked(words, &prior_words_indices, max_depth, max_results, ranking)
+    }
+
+    /// Solve using a provided word list, returning the top `max_results` (or,
+    /// if it is 0, all) solutions of at most `max_depth` words according to
+    /// `ranking`.
+    ///
+    /// Unlike [`LetterBoxed::solve`], this has to find every solution within
+    /// `max_depth` before it can rank them, so it is slower for deep searches.
+    /// As with `solve`, if there are no complete solutions the best partial
+    /// solution is returned instead.
+    pub fn solve_ranked<'word>(
+        &self,
+        words: &[&'word str],
+        prior_words_indices: &[usize],
+        max_depth: usize,
+        max_results: usize,
+        ranking: &Ranking<'_>,
+    ) -> Vec<Solution<'word>> {
+        let mut iter = self.solve_iter(words, prior_words_indices, max_depth);
+        let mut results = iter.by_ref().collect::<Vec<_>>();
+        if results.is_empty() {
+            results.push(iter.best_partial());
+        }
+
+        ranking.sort(&mut results);
+        results.truncate(result_limit(max_results));
+        results
+    }
+}
@@ -0,0 +1,198 @@
+use core::fmt::Write;
+
+use crate::prelude::*;
+use crate::{LetterBoxed, Position};
+
+/// The width and height of a rendered board, in pixels.
+const SIZE: f64 = 300.0;
+/// The distance from the edge of the image to the corners of a square board.
+const MARGIN: f64 = 60.0;
+/// How far letters are drawn outside their side.
+const LABEL_OFFSET: f64 = 24.0;
+
+/// A side of a rendered board, from one end to the other, along with the
+/// direction pointing out of the board.
+struct Segment {
+    from: (f64, f64),
+    to: (f64, f64),
+    outward: (f64, f64),
+}
+
+impl LetterBoxed {
+    /// Render the board as an SVG image, with `solution` drawn as a line
+    /// through its letters like the New York Times share image. Pass no
+    /// words to render just the board.
+    ///
+    /// Square boards are drawn with their sides in the order they are
+    /// [displayed](core::fmt::Display): top, left, bottom and right. Boards
+    /// with three or more sides are drawn as regular polygons.
+    ///
+    /// Returns `None` if the solution can't be traced on the board, as in
+    /// [`LetterBoxed::trace`].
+    pub fn to_svg(&self, solution: &[&str]) -> Option<String> {
+        let path = if solution.is_empty() {
+            vec![]
+        } else {
+            self.trace(solution)?
+        };
+        let segments = self.segments();
+
+        let mut svg = String::new();
+        // Writing to a `String` never fails.
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {SIZE} {SIZE}" width="{SIZE}" height="{SIZE}">"#
+        );
+        let _ = writeln!(
+            svg,
+            r##"<rect width="{SIZE}" height="{SIZE}" fill="#fcbfa6"/>"##
+        );
+
+        let edges = segments
+            .iter()
+            .map(|s| {
+                format!(
+                    "{:.1},{:.1} {:.1},{:.1}",
+                    s.from.0, s.from.1, s.to.0, s.to.1
+                )
+            })
+            .collect::<Vec<_>>();
+        for edge in edges {
+            let _ = writeln!(
+                svg,
+                r#"<polyline points="{edge}" fill="none" stroke="black" stroke-width="3"/>"#
+            );
+        }
+
+        if !path.is_empty() {
+            let points = path
+                .iter()
+                .map(|p| {
+                    let (x, y) = letter_point(&segments[p.side], p, self.side_len(p.side));
+                    format!("{x:.1},{y:.1}")
+                })
+                .collect::<Vec<_>>();
+            let _ = writeln!(
+                svg,
+                r##"<polyline points="{}" fill="none" stroke="#e05c56" stroke-width="3" stroke-linejoin="round"/>"##,
+                points.join(" ")
+            );
+        }
+
+        for (side, letters) in self.sides.iter().enumerate() {
+            let segment = &segments[side];
+            let len = self.side_len(side);
+            for (index, letter) in letters.chars().enumerate() {
+                let position = Position { side, index };
+                let (x, y) = letter_point(segment, &position, len);
+                let fill = if path.first() == Some(&position) {
+                    "#e05c56"
+                } else {
+                    "white"
+                };
+                let _ = writeln!(
+                    svg,
+                    r#"<circle cx="{x:.1}" cy="{y:.1}" r="6" fill="{fill}" stroke="black" stroke-width="2"/>"#
+                );
+                let (lx, ly) = (
+                    x + segment.outward.0 * LABEL_OFFSET,
+                    y + segment.outward.1 * LABEL_OFFSET,
+                );
+                let _ = writeln!(
+                    svg,
+                    r#"<text x="{lx:.1}" y="{ly:.1}" font-family="sans-serif" font-size="22" font-weight="bold" text-anchor="middle" dominant-baseline="central">{}</text>"#,
+                    escape(letter)
+                );
+            }
+        }
+
+        svg.push_str("</svg>\n");
+        Some(svg)
+    }
+
+    /// The number of letters on the side at `side`.
+    fn side_len(&self, side: usize) -> usize {
+        self.sides[side].chars().count()
+    }
+
+    /// Where each side of the board is drawn.
+    fn segments(&self) -> Vec<Segment> {
+        let (lo, hi) = (MARGIN, SIZE - MARGIN);
+        let square = [
+            // Top, left to right.
+            Segment {
+                from: (lo, lo),
+                to: (hi, lo),
+                outward: (0.0, -1.0),
+            },
+            // Left, top to bottom.
+            Segment {
+                from: (lo, lo),
+                to: (lo, hi),
+                outward: (-1.0, 0.0),
+            },
+            // Bottom, left to right.
+            Segment {
+                from: (lo, hi),
+                to: (hi, hi),
+                outward: (0.0, 1.0),
+            },
+            // Right, top to bottom.
+            Segment {
+                from: (hi, lo),
+                to: (hi, hi),
+                outward: (1.0, 0.0),
+            },
+        ];
+
+        let n = self.sides.len();
+        match n {
+            4 => square.into_iter().collect(),
+            // Too few sides for a polygon, so draw the top and bottom.
+            0..=2 => square.into_iter().step_by(2).take(n).collect(),
+            _ => {
+                let center = SIZE / 2.0;
+                let radius = SIZE / 2.0 - MARGIN;
+                // Start at the top, going clockwise.
+                let vertex = |k: usize| {
+                    let angle =
+                        core::f64::consts::TAU * k as f64 / n as f64 - core::f64::consts::FRAC_PI_2;
+                    (center + radius * angle.cos(), center + radius * angle.sin())
+                };
+                (0..n)
+                    .map(|k| {
+                        let (from, to) = (vertex(k), vertex(k + 1));
+                        let mid = ((from.0 + to.0) / 2.0, (from.1 + to.1) / 2.0);
+                        let (dx, dy) = (mid.0 - center, mid.1 - center);
+                        let norm = (dx * dx + dy * dy).sqrt();
+                        Segment {
+                            from,
+                            to,
+                            outward: (dx / norm, dy / norm),
+                        }
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Where the letter at `position` is drawn, spacing the `len` letters of its
+/// side evenly along `segment`.
+fn letter_point(segment: &Segment, position: &Position, len: usize) -> (f64, f64) {
+    let t = (position.index + 1) as f64 / (len + 1) as f64;
+    (
+        segment.from.0 + (segment.to.0 - segment.from.0) * t,
+        segment.from.1 + (segment.to.1 - segment.from.1) * t,
+    )
+}
+
+/// Escape `letter` for use as SVG text.
+fn escape(letter: char) -> String {
+    match letter {
+        '<' => "&lt;".to_string(),
+        '>' => "&gt;".to_string(),
+        '&' => "&amp;".to_string(),
+        c => c.to_string(),
+    }
+}
@@ -0,0 +1,199 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+
+use crate::collections::HashSet;
+use crate::prelude::*;
+use crate::{letters, LetterBoxed, Position};
+
+/// Which letters may follow one another within a word.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Adjacency {
+    /// Consecutive letters must be on different sides of the board, as in the
+    /// official puzzle.
+    #[default]
+    DifferentSides,
+    /// Consecutive letters may be on the same side, but not at the same
+    /// position, so a letter may only follow itself if it is on the board
+    /// more than once.
+    DifferentPositions,
+}
+
+/// The rules a board is played under, for variants of the puzzle.
+///
+/// ```
+/// use letter_boxed_solver::{Adjacency, BoardRules, LetterBoxed};
+///
+/// let rules = BoardRules::new().with_adjacency(Adjacency::DifferentPositions);
+/// let board = LetterBoxed::load_board(&["ABC", "DEF"]).with_rules(rules);
+/// assert!(board.validate(&["CAB"]));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BoardRules {
+    adjacency: Adjacency,
+    each_letter_once: bool,
+    no_proper_nouns: bool,
+    repeated_words: bool,
+}
+
+impl BoardRules {
+    /// The rules of the official puzzle.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The rules of the New York Times app, as closely as they are known:
+    /// consecutive letters on different sides, letters reusable as often as
+    /// the words need, each word starting with the last letter of the one
+    /// before, and no proper nouns. Words must also have at least three
+    /// letters, which is the board's default
+    /// [minimum](LetterBoxed::with_min_word_length).
+    ///
+    /// The builtin word lists have no proper nouns to begin with, so these
+    /// only differ from [`BoardRules::new`] with a word list which has them.
+    pub fn nyt() -> Self {
+        Self::new().with_proper_nouns(false)
+    }
+
+    /// Set which letters may follow one another within a word.
+    pub fn with_adjacency(mut self, adjacency: Adjacency) -> Self {
+        self.adjacency = adjacency;
+        self
+    }
+
+    /// Which letters may follow one another within a word.
+    pub fn adjacency(&self) -> Adjacency {
+        self.adjacency
+    }
+
+    /// Only allow each letter on the board to be played once in an entire
+    /// solution, rather than as many times as the words need. The last letter
+    /// of each word is shared with the first letter of the next, so it only
+    /// counts once. A letter which is on the board more than once must be
+    /// played that many times.
+    pub fn with_each_letter_once(mut self, each_letter_once: bool) -> Self {
+        self.each_letter_once = each_letter_once;
+        self
+    }
+
+    /// Whether each letter on the board may only be played once.
+    pub fn each_letter_once(&self) -> bool {
+        self.each_letter_once
+    }
+
+    /// Set whether proper nouns in the word list may be played. A word list
+    /// flags proper nouns by capitalizing them, e.g. `Paris`, as
+    /// `/usr/share/dict/words` does; words in all capitals are never proper
+    /// nouns. Note that [`crate::Dictionary::clean`] capitalizes every word,
+    /// so use [`crate::Dictionary::clean_without_proper_nouns`] to drop them
+    /// from a cleaned list.
+    pub fn with_proper_nouns(mut self, allowed: bool) -> Self {
+        self.no_proper_nouns = !allowed;
+        self
+    }
+
+    /// Whether proper nouns in the word list may be played.
+    pub fn proper_nouns(&self) -> bool {
+        !self.no_proper_nouns
+    }
+
+    /// Set whether a solution may play the same word more than once, ignoring
+    /// case and Unicode normalization. This is forbidden by default. Playing
+    /// a word again never covers a new letter, so the search never does it,
+    /// but with this set, prior words and solutions being validated may
+    /// repeat words.
+    pub fn with_repeated_words(mut self, allowed: bool) -> Self {
+        self.repeated_words = allowed;
+        self
+    }
+
+    /// Whether a solution may play the same word more than once.
+    pub fn repeated_words(&self) -> bool {
+        self.repeated_words
+    }
+
+    /// Whether the letter at `to` may follow the letter at `from` within a
+    /// word.
+    pub(crate) fn can_follow(&self, from: Position, to: Position) -> bool {
+        match self.adjacency {
+            Adjacency::DifferentSides => from.side != to.side,
+            Adjacency::DifferentPositions => from != to,
+        }
+    }
+
+    /// The pairs of letters which may not follow one another within a word on
+    /// a board with the given sides.
+    pub(crate) fn nonadjacent(&self, sides: &[String]) -> HashSet<(char, char)> {
+        // Each letter could be at several positions on the board, if the
+        // board repeats it on more than one side.
+        let mut positions = BTreeMap::<char, BTreeSet<usize>>::new();
+        for (idx, side) in sides.iter().enumerate() {
+            for c in side.chars() {
+                positions.entry(c).or_default().insert(idx);
+            }
+        }
+
+        let mut nonadjacent = HashSet::new();
+        for (c, c_sides) in &positions {
+            match self.adjacency {
+                // Two letters may follow one another if any position of the
+                // first is on a different side from any position of the
+                // second, so they're only nonadjacent if both are on the same
+                // single side.
+                Adjacency::DifferentSides => {
+                    for (cc, cc_sides) in &positions {
+                        if c_sides.len() == 1 && c_sides == cc_sides {
+                            nonadjacent.insert((*c, *cc));
+                        }
+                    }
+                }
+                Adjacency::DifferentPositions => {
+                    let count = sides.iter().flat_map(|s| s.chars()).filter(|x| x == c);
+                    if count.count() == 1 {
+                        nonadjacent.insert((*c, *c));
+                    }
+                }
+            }
+        }
+        nonadjacent
+    }
+}
+
+impl LetterBoxed {
+    /// How many times each letter is on the board.
+    pub(crate) fn letter_counts(&self) -> BTreeMap<char, usize> {
+        let mut counts = BTreeMap::new();
+        for c in self.sides.iter().flat_map(|s| s.chars()) {
+            *counts.entry(c).or_default() += 1;
+        }
+        counts
+    }
+}
+
+/// Whether `word` is flagged as a proper noun by being capitalized, i.e.
+/// starting with an uppercase letter and having a lowercase one after it.
+pub(crate) fn is_proper_noun(word: &str) -> bool {
+    let mut chars = word.chars();
+    chars.next().is_some_and(|c| c.is_uppercase()) && chars.any(|c| c.is_lowercase())
+}
+
+/// The index of the first word before the one at `idx` in `words` which is
+/// the same word, ignoring case and Unicode normalization, if any.
+pub(crate) fn earlier_copy(words: &[&str], idx: usize) -> Option<usize> {
+    words[..idx]
+        .iter()
+        .position(|w| letters(w).eq(letters(words[idx])))
+}
+
+/// Play `letters` from the `remaining` letters of an each-letter-once game,
+/// returning whether there were enough of them.
+pub(crate) fn use_letters(
+    remaining: &mut BTreeMap<char, usize>,
+    letters: impl IntoIterator<Item = char>,
+) -> bool {
+    for c in letters {
+        match remaining.get_mut(&c) {
+            Some(n) if *n > 0 => *n -= 1,
+            _ => return false,
+        }
+    }
+    true
+}
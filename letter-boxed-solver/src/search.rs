@@ -0,0 +1,1395 @@
+use alloc::borrow::Cow;
+use alloc::collections::{BTreeMap, BTreeSet, BinaryHeap};
+use alloc::sync::Arc;
+use core::cmp::{Ordering, Reverse};
+use core::ops::Deref;
+use core::sync::atomic::{self, AtomicBool};
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+use crate::collections::hash_map::Entry;
+use crate::collections::HashMap;
+use crate::count::Counter;
+use crate::prelude::*;
+use crate::rules::{earlier_copy, use_letters};
+use crate::word_graph::WordGraph;
+use crate::{letters, LetterBoxed, Mask};
+
+/// A single solution found by the solver.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Solution<'word> {
+    /// The words making up the solution, in the order they are played. This
+    /// includes any prior words that the search started from.
+    pub words: Vec<&'word str>,
+    /// The index of each of `words` in the word list that was searched, e.g.
+    /// to look up metadata about them.
+    pub indices: Vec<usize>,
+    /// The number of distinct board letters covered by `words`.
+    pub letters_covered: usize,
+}
+
+impl Solution<'_> {
+    /// Copy the words, so that the solution no longer borrows the word list,
+    /// e.g. to cache it or send it to another thread.
+    pub fn into_owned(self) -> OwnedSolution {
+        OwnedSolution {
+            words: self.words.into_iter().map(str::to_string).collect(),
+            indices: self.indices,
+            letters_covered: self.letters_covered,
+        }
+    }
+}
+
+/// A [`Solution`] which owns its words, as returned by
+/// [`Solution::into_owned`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OwnedSolution {
+    /// The words making up the solution, in the order they are played.
+    pub words: Vec<String>,
+    /// The index of each of `words` in the word list that was searched.
+    pub indices: Vec<usize>,
+    /// The number of distinct board letters covered by `words`.
+    pub letters_covered: usize,
+}
+
+impl OwnedSolution {
+    /// Borrow the solution's words.
+    pub fn as_solution(&self) -> Solution<'_> {
+        Solution {
+            words: self.words.iter().map(String::as_str).collect(),
+            indices: self.indices.clone(),
+            letters_covered: self.letters_covered,
+        }
+    }
+}
+
+impl From<Solution<'_>> for OwnedSolution {
+    fn from(solution: Solution<'_>) -> Self {
+        solution.into_owned()
+    }
+}
+
+/// The indices of the words played to reach a search state, in order.
+///
+/// Each path points to the path it extends rather than copying it, so that
+/// expanding a state takes constant time and memory however many words it has,
+/// and states expanded from the same one share their words. The words are
+/// only copied out when a solution is yielded.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Path(Option<Arc<PathNode>>);
+
+#[derive(Debug)]
+struct PathNode {
+    /// The last word of the path.
+    idx: usize,
+    /// The number of words in the path, including this one.
+    len: usize,
+    /// The path before the last word.
+    parent: Path,
+}
+
+impl Path {
+    /// The path playing `indices` in order.
+    pub(crate) fn new(indices: &[usize]) -> Self {
+        indices
+            .iter()
+            .fold(Path::default(), |path, idx| path.push(*idx))
+    }
+
+    /// This path, followed by the word at `idx`.
+    pub(crate) fn push(&self, idx: usize) -> Self {
+        Path(Some(Arc::new(PathNode {
+            idx,
+            len: self.len() + 1,
+            parent: self.clone(),
+        })))
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.0.as_ref().map_or(0, |node| node.len)
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_none()
+    }
+
+    /// The index of the last word.
+    pub(crate) fn last(&self) -> Option<usize> {
+        self.0.as_ref().map(|node| node.idx)
+    }
+
+    /// The indices of the words, from the last to the first.
+    pub(crate) fn iter_rev(&self) -> impl Iterator<Item = usize> + '_ {
+        core::iter::successors(self.0.as_deref(), |node| node.parent.0.as_deref())
+            .map(|node| node.idx)
+    }
+
+    /// The indices of the words, in order.
+    pub(crate) fn to_vec(&self) -> Vec<usize> {
+        let mut indices = self.iter_rev().collect::<Vec<_>>();
+        indices.reverse();
+        indices
+    }
+}
+
+/// State for the word-search.
+#[derive(Debug, Clone)]
+pub(crate) struct State {
+    /// The current letter we are starting from
+    pub(crate) cur: char,
+    /// All the letters we've visited on this path
+    pub(crate) visited: Mask,
+    /// How many more times each letter may be played, if each letter may
+    /// only be used once.
+    pub(crate) remaining: Option<BTreeMap<char, usize>>,
+    pub(crate) path: Path,
+}
+
+impl State {
+    /// The number of distinct board letters this path covers.
+    pub(crate) fn letters_covered(&self) -> usize {
+        self.visited.len()
+    }
+}
+
+/// A sequence of words which might end a solution, for meet-in-the-middle
+/// search.
+#[derive(Debug, Clone)]
+pub(crate) struct Suffix {
+    /// All the letters the words use.
+    letters: Mask,
+    path: Vec<usize>,
+}
+
+/// Everything about a search which doesn't change while it runs.
+pub(crate) struct Context<'a, 'word> {
+    /// The playable words, which may be shared with other searches.
+    compiled: Cow<'a, WordGraph<'a, 'word>>,
+    /// If set, every solution must use one of these words.
+    required: Option<BTreeSet<usize>>,
+    pub(crate) max_depth: usize,
+}
+
+impl<'a, 'word> Deref for Context<'a, 'word> {
+    type Target = WordGraph<'a, 'word>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.compiled
+    }
+}
+
+impl<'a, 'word> Context<'a, 'word> {
+    pub(crate) fn new(board: &'a LetterBoxed, words: &'a [&'word str], max_depth: usize) -> Self {
+        Context::with_graph(Cow::Owned(WordGraph::new(board, words)), max_depth)
+    }
+
+    pub(crate) fn with_graph(compiled: Cow<'a, WordGraph<'a, 'word>>, max_depth: usize) -> Self {
+        Context {
+            compiled,
+            required: None,
+            max_depth,
+        }
+    }
+
+    /// Remove every word matching one of `exclude` from the graph.
+    pub(crate) fn exclude(&mut self, exclude: &[&str]) {
+        let excluded = self
+            .word_letters
+            .keys()
+            .copied()
+            .filter(|&i| {
+                exclude
+                    .iter()
+                    .any(|w| letters(w).eq(letters(self.words[i])))
+            })
+            .collect::<BTreeSet<_>>();
+
+        let compiled = self.compiled.to_mut();
+        for options in compiled.graph.values_mut() {
+            for word_indices in options.values_mut() {
+                word_indices.retain(|i| !excluded.contains(i));
+            }
+            options.retain(|_, word_indices| !word_indices.is_empty());
+        }
+        compiled.graph.retain(|_, options| !options.is_empty());
+        compiled.word_letters.retain(|i, _| !excluded.contains(i));
+        compiled.word_masks.retain(|i, _| !excluded.contains(i));
+    }
+
+    /// Require that every solution uses a word matching `word`.
+    pub(crate) fn require(&mut self, word: &str) {
+        let required = self
+            .word_letters
+            .keys()
+            .copied()
+            .filter(|&i| letters(word).eq(letters(self.words[i])))
+            .collect();
+        self.required = Some(required);
+    }
+
+    /// Whether `state` has used the required word, if there is one.
+    fn has_required(&self, state: &State) -> bool {
+        match &self.required {
+            Some(required) => state.path.iter_rev().any(|i| required.contains(&i)),
+            None => true,
+        }
+    }
+
+    /// The states to start searching from, given the words which have
+    /// already been played.
+    pub(crate) fn initial_frontier(&self, prior_words_indices: &[usize]) -> Vec<State> {
+        let mut frontier = vec![];
+
+        if prior_words_indices.is_empty() {
+            // Preload the frontier at each possible start location
+            for k in self.graph.keys() {
+                let mut remaining = self.initial_remaining();
+                if let Some(remaining) = &mut remaining {
+                    use_letters(remaining, [*k]);
+                }
+                frontier.push(State {
+                    cur: *k,
+                    visited: self.board.mask([*k]),
+                    remaining,
+                    path: Path::default(),
+                })
+            }
+        } else {
+            let last_c = letters(self.words[prior_words_indices[prior_words_indices.len() - 1]])
+                .last()
+                .unwrap();
+            // Words added by the search always cover a new letter, so can't
+            // repeat, but the prior words might.
+            if !self.board.rules().repeated_words() {
+                let prior_words = prior_words_indices
+                    .iter()
+                    .map(|idx| self.words[*idx])
+                    .collect::<Vec<_>>();
+                if (0..prior_words.len()).any(|i| earlier_copy(&prior_words, i).is_some()) {
+                    return frontier;
+                }
+            }
+
+            let mut visited = Mask::default();
+            let mut remaining = self.initial_remaining();
+
+            for (i, idx) in prior_words_indices.iter().enumerate() {
+                visited.union_with(&self.board.mask(letters(self.words[*idx])));
+                if let Some(remaining) = &mut remaining {
+                    // The first letter of each word after the first was
+                    // already played as the last letter of the one before.
+                    if !use_letters(remaining, letters(self.words[*idx]).skip(i.min(1))) {
+                        return frontier;
+                    }
+                }
+            }
+
+            frontier.push(State {
+                cur: last_c,
+                visited,
+                remaining,
+                path: Path::new(prior_words_indices),
+            })
+        }
+        frontier
+    }
+
+    /// The letters which may be played at the start of a search, if each
+    /// letter may only be used once.
+    fn initial_remaining(&self) -> Option<BTreeMap<char, usize>> {
+        self.board
+            .rules()
+            .each_letter_once()
+            .then(|| self.board.letter_counts())
+    }
+
+    /// Count the complete solutions reachable from `frontier`.
+    pub(crate) fn count_complete(&self, frontier: Vec<State>) -> usize {
+        self.count_by_length(frontier).into_iter().sum()
+    }
+
+    /// Count the complete solutions reachable from `frontier`, where the
+    /// `k`th entry is the number of solutions with `k` words in total.
+    pub(crate) fn count_by_length(&self, mut frontier: Vec<State>) -> Vec<usize> {
+        if let Some(mut counter) = Counter::new(self) {
+            return counter.count(&frontier);
+        }
+
+        // The counter can't track letters which may only be used once, so
+        // fall back to expanding every state.
+        let mut counts = vec![0; self.max_depth + 1];
+        while !frontier.is_empty() {
+            for s in frontier.iter().filter(|s| self.is_complete(s)) {
+                counts[s.path.len()] += 1;
+            }
+            frontier = self.expand_level(&frontier);
+        }
+        counts
+    }
+
+    pub(crate) fn is_complete(&self, state: &State) -> bool {
+        state.visited == Mask::full(self.board.letters.len())
+            && state
+                .remaining
+                .as_ref()
+                .is_none_or(|r| r.values().all(|n| *n == 0))
+    }
+
+    /// Whether `state` is a complete solution which satisfies every
+    /// constraint on the search.
+    fn is_solution(&self, state: &State) -> bool {
+        self.is_complete(state) && self.has_required(state)
+    }
+
+    /// Expand all the successors of a single state.
+    pub(crate) fn expand(&self, state: &State) -> Vec<State> {
+        let mut next_states = vec![];
+        if self.is_complete(state) || state.path.len() + 1 > self.max_depth {
+            return next_states;
+        }
+        // If this is the last word we have room for, it has to be the
+        // required one.
+        let must_require = state.path.len() + 1 == self.max_depth && !self.has_required(state);
+        if let Some(options) = self.graph.get(&state.cur) {
+            // Go through all the potential end-letters
+            for (next_letter, word_indices) in options {
+                // and all the paths to get there
+                for idx in word_indices {
+                    if must_require && !self.required.as_ref().is_some_and(|r| r.contains(idx)) {
+                        continue;
+                    }
+                    next_states.extend(self.advance(state, *next_letter, *idx));
+                }
+            }
+        }
+        next_states
+    }
+
+    /// Play the word at `idx`, which ends with `next_letter`, from `state`.
+    /// Returns `None` if the word doesn't add a new letter, or there aren't
+    /// enough letters left to play it.
+    fn advance(&self, state: &State, next_letter: char, idx: usize) -> Option<State> {
+        let w = &self.word_masks[&idx];
+        // only consider routes that add a new letter to the visited set
+        if w.is_subset(&state.visited) {
+            return None;
+        }
+        let mut remaining = state.remaining.clone();
+        if let Some(remaining) = &mut remaining {
+            if !use_letters(remaining, letters(self.words[idx]).skip(1)) {
+                return None;
+            }
+        }
+
+        Some(State {
+            cur: next_letter,
+            visited: state.visited.union(w),
+            remaining,
+            path: state.path.push(idx),
+        })
+    }
+
+    /// Play the words of `suffix` from `state`, which ends on its first
+    /// letter, returning the resulting state if it is a solution.
+    fn join(&self, state: &State, suffix: &Suffix) -> Option<State> {
+        let mut state = state.clone();
+        for idx in &suffix.path {
+            // A solution isn't extended, so the suffix can't start any later.
+            if self.is_complete(&state) {
+                return None;
+            }
+            let next_letter = letters(self.words[*idx]).last()?;
+            state = self.advance(&state, next_letter, *idx)?;
+        }
+        self.is_solution(&state).then_some(state)
+    }
+
+    /// The sequences of `len` words which could end a solution, keyed by
+    /// their first letter, given the sequences of `len - 1` words.
+    fn extend_suffixes(&self, shorter: &HashMap<char, Vec<Suffix>>) -> HashMap<char, Vec<Suffix>> {
+        let mut suffixes = HashMap::<char, Vec<Suffix>>::new();
+        for (first, options) in &self.graph {
+            for (last, word_indices) in options {
+                for idx in word_indices {
+                    let w = &self.word_masks[idx];
+                    let tails = shorter.get(last).map(|t| t.as_slice()).unwrap_or_default();
+                    for tail in tails {
+                        // The rest of the suffix has to add something to
+                        // this word, whatever comes before it.
+                        if tail.letters.is_subset(w) {
+                            continue;
+                        }
+                        let mut path = vec![*idx];
+                        path.extend(&tail.path);
+                        suffixes.entry(*first).or_default().push(Suffix {
+                            letters: w.union(&tail.letters),
+                            path,
+                        });
+                    }
+                }
+            }
+        }
+        suffixes
+    }
+
+    /// The single words which could end a solution, keyed by their first
+    /// letter.
+    fn single_word_suffixes(&self) -> HashMap<char, Vec<Suffix>> {
+        let mut suffixes = HashMap::<char, Vec<Suffix>>::new();
+        for (first, options) in &self.graph {
+            for idx in options.values().flatten() {
+                suffixes.entry(*first).or_default().push(Suffix {
+                    letters: self.word_masks[idx].clone(),
+                    path: vec![*idx],
+                });
+            }
+        }
+        suffixes
+    }
+
+    /// Expand every state in a level of the search.
+    ///
+    /// With the `rayon` feature enabled, this is done in parallel.
+    fn expand_level(&self, frontier: &[State]) -> Vec<State> {
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+            frontier
+                .par_iter()
+                .flat_map_iter(|s| self.expand(s))
+                .collect()
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            frontier.iter().flat_map(|s| self.expand(s)).collect()
+        }
+    }
+
+    pub(crate) fn solution(&self, path: &[usize], letters_covered: usize) -> Solution<'word> {
+        Solution {
+            words: path.iter().map(|idx| self.words[*idx]).collect(),
+            indices: path.to_vec(),
+            letters_covered,
+        }
+    }
+}
+
+/// How the solver explores the space of word sequences.
+///
+/// Every strategy yields solutions in order of increasing word count, so all
+/// solutions of minimal length come before any longer ones.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SearchStrategy {
+    /// Expand every path one word at a time. This is the fastest strategy,
+    /// but has to hold an entire level of the search in memory.
+    #[default]
+    BreadthFirst,
+    /// Run a depth-first search with a word limit, raising the limit by one
+    /// each time it is exhausted. This re-expands the shallower levels on
+    /// each pass, but only needs memory proportional to the depth.
+    IterativeDeepening,
+    /// Search forward from the start for the first half of each solution's
+    /// words, and separately for every sequence of words which could make up
+    /// the second half, joining the halves where they meet on the same letter
+    /// and cover the board between them. This explores far fewer states than
+    /// a breadth-first search on deep (four or more word) searches.
+    ///
+    /// Each word count is searched all at once, so a paused
+    /// [`crate::SolverSession`] may expand many more states than it asked
+    /// for, and dominance pruning only applies to the first halves.
+    MeetInTheMiddle,
+    /// Like [`SearchStrategy::BreadthFirst`], but within each word count,
+    /// check and expand the states with the fewest letters left to cover
+    /// first, so that high-coverage words are explored first and good
+    /// solutions (and partial solutions) surface early.
+    Greedy,
+}
+
+/// A state waiting in the queue of a greedy search.
+#[derive(Debug)]
+struct Queued {
+    /// The number of words, the number of letters left to cover, and the
+    /// order the state was queued in, which the queue pops smallest first.
+    priority: (usize, usize, usize),
+    state: State,
+}
+
+impl PartialEq for Queued {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl Eq for Queued {}
+
+impl PartialOrd for Queued {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Queued {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // `BinaryHeap` is a max-heap, so reverse the order.
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// A lazy iterator over the solutions to a board, created by
+/// [`LetterBoxed::solve_iter`].
+///
+/// By default, the search proceeds one level (i.e. one additional word) at a
+/// time, which is equivalent to a FIFO breadth-first search but lets us
+/// expand every state in a level independently. See [`SearchStrategy`] for
+/// alternatives.
+pub struct SolutionIter<'a, 'word> {
+    ctx: Context<'a, 'word>,
+    strategy: SearchStrategy,
+    /// The states the search started from.
+    initial: Vec<State>,
+    frontier: Vec<State>,
+    /// The index of the next state in `frontier` to check for completeness.
+    pos: usize,
+    /// The next level of the search, which is built up a chunk at a time.
+    next_frontier: Vec<State>,
+    /// The index of the next state in `frontier` to expand into
+    /// `next_frontier`.
+    expand_pos: usize,
+    /// The pending states of a depth-first pass, for iterative deepening.
+    stack: Vec<State>,
+    /// The pending states of a greedy search.
+    queue: BinaryHeap<Queued>,
+    /// The number of states ever added to `queue`.
+    queued: usize,
+    /// The states at each number of words past the start, for
+    /// meet-in-the-middle search.
+    levels: Vec<Vec<State>>,
+    /// The sequences of each number of words (starting from one) which could
+    /// end a solution, for meet-in-the-middle search.
+    suffixes: Vec<HashMap<char, Vec<Suffix>>>,
+    /// The maximum path length of the current depth-first pass, or the
+    /// number of words past the start of the next level of a
+    /// meet-in-the-middle search.
+    limit: usize,
+    /// Whether the current depth-first pass skipped any states because of
+    /// `limit`, i.e. whether a deeper pass could find anything new.
+    cut_off: bool,
+    /// The best (most letters covered, then fewest words) path seen so far.
+    best: (usize, Path),
+    #[cfg(feature = "std")]
+    deadline: Option<Instant>,
+    /// If set, stop (and finish) once this is set to `true`.
+    cancel: Option<Arc<AtomicBool>>,
+    /// Whether the search ended early, for whatever reason.
+    stopped: bool,
+    /// Board letters which no playable word (or prior word) covers, so that
+    /// no complete solution can exist.
+    dead_letters: BTreeSet<char>,
+    /// Whether the search ended early because the best partial solution
+    /// already covers every letter but the dead ones.
+    dead_end: bool,
+    /// If set, stop (but don't finish) once this many states are expanded.
+    pause_at: Option<usize>,
+    /// The fewest words used to reach each (current letter, visited letters,
+    /// remaining letters, used the required word) combination, if pruning
+    /// dominated states.
+    shallowest: Option<HashMap<StateKey, usize>>,
+    /// Whether to also prune states reached with as many words as before,
+    /// not just more.
+    dedup_states: bool,
+    /// The most states to keep at each level of a breadth-first search.
+    beam_width: Option<usize>,
+    /// The word sets of the solutions yielded so far, if deduplicating.
+    seen: Option<Vec<BTreeSet<&'word str>>>,
+    /// The most solutions to yield starting with each word after the prior
+    /// words, and how many have been yielded for each so far.
+    per_start_word: Option<(usize, HashMap<usize, usize>)>,
+    /// The number of states expanded so far.
+    expanded: usize,
+    /// The number of states skipped by dominance pruning.
+    pruned: usize,
+    /// The most states waiting to be checked or expanded at once.
+    peak_queue_len: usize,
+    /// The time spent searching, where it can be measured.
+    elapsed: Option<Duration>,
+    progress: Option<ProgressCallback<'a>>,
+}
+
+type ProgressCallback<'a> = Box<dyn FnMut(&Progress) + 'a>;
+
+/// A search state without its path: the current letter, the visited letters,
+/// the remaining letters, and whether the required word has been used.
+type StateKey = (char, Mask, Option<BTreeMap<char, usize>>, bool);
+
+/// A snapshot of how a search is going, passed to the callback registered
+/// with [`SolutionIter::with_progress`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Progress {
+    /// The number of search states expanded so far.
+    pub states_expanded: usize,
+    /// The number of states waiting to be checked or expanded.
+    pub queue_len: usize,
+    /// The number of words in the paths currently being expanded.
+    pub depth: usize,
+    /// The most letters covered by any path seen so far.
+    pub best_letters_covered: usize,
+}
+
+/// Statistics about what a search did, as returned by
+/// [`SolutionIter::statistics`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Statistics {
+    /// The number of search states expanded.
+    pub states_expanded: usize,
+    /// The number of states skipped by dominance pruning or discarded from
+    /// the beam. See [`SolutionIter::with_dominance_pruning`] and
+    /// [`SolutionIter::with_beam_width`].
+    pub states_pruned: usize,
+    /// The most states waiting to be checked or expanded at once.
+    pub peak_queue_len: usize,
+    /// The number of playable words on the board.
+    pub graph_words: usize,
+    /// The number of distinct (first letter, last letter) pairs among the
+    /// playable words.
+    pub graph_edges: usize,
+    /// The time spent searching so far. This is `None` on
+    /// `wasm32-unknown-unknown` and without the `std` feature, where time
+    /// can't be measured.
+    pub elapsed: Option<Duration>,
+}
+
+/// The number of states expanded between checks of the deadline.
+const CHUNK_SIZE: usize = 4096;
+
+impl<'a, 'word> SolutionIter<'a, 'word> {
+    pub(crate) fn new(ctx: Context<'a, 'word>, prior_words_indices: &[usize]) -> Self {
+        let initial = ctx.initial_frontier(prior_words_indices);
+        let dead_letters = Self::find_dead_letters(&ctx, &initial);
+
+        SolutionIter {
+            ctx,
+            strategy: SearchStrategy::BreadthFirst,
+            frontier: initial.clone(),
+            initial,
+            pos: 0,
+            next_frontier: vec![],
+            expand_pos: 0,
+            stack: vec![],
+            queue: BinaryHeap::new(),
+            queued: 0,
+            levels: vec![],
+            suffixes: vec![],
+            limit: 0,
+            cut_off: false,
+            best: (0, Path::default()),
+            #[cfg(feature = "std")]
+            deadline: None,
+            cancel: None,
+            stopped: false,
+            dead_letters,
+            dead_end: false,
+            pause_at: None,
+            shallowest: None,
+            dedup_states: false,
+            beam_width: None,
+            seen: None,
+            per_start_word: None,
+            expanded: 0,
+            pruned: 0,
+            peak_queue_len: 0,
+            elapsed: None,
+            progress: None,
+        }
+    }
+
+    /// Use `strategy` to explore the search space. This must be called before
+    /// the iterator is first advanced.
+    pub fn with_strategy(mut self, strategy: SearchStrategy) -> Self {
+        self.strategy = strategy;
+        self.restart();
+        self
+    }
+
+    /// Call `callback` periodically while searching, e.g. to drive a progress
+    /// bar. It is called after every few thousand states are expanded.
+    pub fn with_progress(mut self, callback: impl FnMut(&Progress) + 'a) -> Self {
+        self.progress = Some(Box::new(callback));
+        self
+    }
+
+    /// Never use any of the words in `exclude`, which are matched ignoring
+    /// case and Unicode normalization. Words which have already been played
+    /// are unaffected. This must be called before the iterator is first
+    /// advanced.
+    pub fn with_excluded(mut self, exclude: &[&str]) -> Self {
+        self.ctx.exclude(exclude);
+        let graph = &self.ctx.graph;
+        self.initial
+            .retain(|s| !s.path.is_empty() || graph.contains_key(&s.cur));
+        self.dead_letters = Self::find_dead_letters(&self.ctx, &self.initial);
+        self.restart();
+        self
+    }
+
+    /// Only yield solutions which use `word` somewhere, matched ignoring case
+    /// and Unicode normalization. A word which has already been played counts.
+    /// This must be called before the iterator is first advanced.
+    pub fn with_required(mut self, word: &str) -> Self {
+        self.ctx.require(word);
+        self
+    }
+
+    /// If `prune` is set, don't expand a state if the same letters were
+    /// already visited, ending on the same letter, with fewer words. This can
+    /// shrink deep searches dramatically.
+    ///
+    /// Every solution of minimal length is still found, but longer solutions
+    /// which merely extend a shorter route to the same state are skipped.
+    pub fn with_dominance_pruning(mut self, prune: bool) -> Self {
+        self.shallowest = prune.then(HashMap::new);
+        self
+    }
+
+    /// If `dedup` is set, don't expand a state if the same letters were
+    /// already visited, ending on the same letter, with at most as many
+    /// words, e.g. after playing the same words in another order. This
+    /// implies [`SolutionIter::with_dominance_pruning`], and saves far more
+    /// work on boards with many interchangeable words.
+    ///
+    /// The shortest solutions are still found, as is every solution which
+    /// ends in a distinct state, but only the first sequence of words to reach
+    /// each unfinished state is extended, so most other solutions are skipped.
+    pub fn with_state_dedup(mut self, dedup: bool) -> Self {
+        self.dedup_states = dedup;
+        if dedup {
+            self.shallowest.get_or_insert_with(HashMap::new);
+        }
+        self
+    }
+
+    /// Only keep the `width` states covering the most letters at each level
+    /// of a breadth-first search, discarding the rest. This trades
+    /// completeness for speed on huge word lists, where the full search
+    /// space is too large but any good solution will do.
+    ///
+    /// This only affects [`SearchStrategy::BreadthFirst`].
+    pub fn with_beam_width(mut self, width: usize) -> Self {
+        self.beam_width = Some(width);
+        self
+    }
+
+    /// If `dedup` is set, skip solutions which use the same set of words as
+    /// one already yielded (e.g. the same two words in the other order), or
+    /// which use every word of a shorter one already yielded.
+    pub fn with_dedup(mut self, dedup: bool) -> Self {
+        self.seen = dedup.then(Vec::new);
+        self
+    }
+
+    /// Yield at most `max` solutions starting with each word (after any
+    /// prior words), skipping further solutions which start the same way, so
+    /// that the first few results show a variety of openings.
+    pub fn with_max_per_start_word(mut self, max: usize) -> Self {
+        self.per_start_word = Some((max, HashMap::new()));
+        self
+    }
+
+    /// Stop searching once `deadline` has passed. The iterator then ends
+    /// early, and [`SolutionIter::best_partial`] holds the best partial
+    /// solution found so far.
+    ///
+    /// Note that `Instant` is not available on `wasm32-unknown-unknown`, or
+    /// without the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Stop searching once `cancel` is set to `true`, e.g. from another
+    /// thread when the user aborts a solve. As with
+    /// [`SolutionIter::with_deadline`], the iterator then ends early and
+    /// [`SolutionIter::best_partial`] holds the best partial solution found
+    /// so far.
+    ///
+    /// Unlike a deadline, this also works on `wasm32-unknown-unknown`.
+    pub fn with_cancellation(mut self, cancel: Arc<AtomicBool>) -> Self {
+        self.cancel = Some(cancel);
+        self
+    }
+
+    /// Whether the search ended early because the deadline passed or it was
+    /// cancelled.
+    pub fn timed_out(&self) -> bool {
+        self.stopped && !self.dead_end
+    }
+
+    /// The board letters which no playable word covers (and no prior word
+    /// already covered), so that the search can't find a complete solution.
+    ///
+    /// If there are any, the search stops as soon as it finds a partial
+    /// solution covering every other letter, rather than exploring every
+    /// sequence of words up to the maximum depth, and
+    /// [`SolutionIter::best_partial`] holds that solution.
+    pub fn dead_letters(&self) -> &BTreeSet<char> {
+        &self.dead_letters
+    }
+
+    /// Whether the search was cancelled. See
+    /// [`SolutionIter::with_cancellation`].
+    pub fn cancelled(&self) -> bool {
+        self.cancel
+            .as_ref()
+            .is_some_and(|c| c.load(atomic::Ordering::Relaxed))
+    }
+
+    /// The number of search states expanded so far.
+    pub fn states_expanded(&self) -> usize {
+        self.expanded
+    }
+
+    /// Statistics about the search so far.
+    pub fn statistics(&self) -> Statistics {
+        Statistics {
+            states_expanded: self.expanded,
+            states_pruned: self.pruned,
+            peak_queue_len: self.peak_queue_len,
+            graph_words: self.ctx.word_letters.len(),
+            graph_edges: self.ctx.graph.values().map(|options| options.len()).sum(),
+            elapsed: self.elapsed,
+        }
+    }
+
+    /// Pause the search after `n` more states are expanded, at which point
+    /// the iterator returns `None` without losing its place. Searching can be
+    /// resumed by calling this again.
+    pub(crate) fn pause_after(&mut self, n: usize) {
+        self.pause_at = Some(self.expanded.saturating_add(n));
+    }
+
+    /// Whether the search is paused, rather than finished.
+    pub(crate) fn is_paused(&self) -> bool {
+        self.pause_at.is_some_and(|p| self.expanded >= p)
+    }
+
+    /// The best partial solution seen so far, i.e. the one covering the most
+    /// letters with the fewest words. This is useful when the iterator runs
+    /// out without finding any complete solution.
+    pub fn best_partial(&self) -> Solution<'word> {
+        self.ctx.solution(&self.best.1.to_vec(), self.best.0)
+    }
+}
+
+impl<'word> SolutionIter<'_, 'word> {
+    /// Reset the search to start from `initial`.
+    fn restart(&mut self) {
+        match self.strategy {
+            SearchStrategy::BreadthFirst => {
+                self.frontier = self.initial.clone();
+                self.stack.clear();
+            }
+            SearchStrategy::IterativeDeepening => {
+                self.frontier.clear();
+                self.limit = self.initial.iter().map(|s| s.path.len()).min().unwrap_or(0);
+                self.stack = self.initial.iter().rev().cloned().collect();
+            }
+            SearchStrategy::MeetInTheMiddle => {
+                self.frontier.clear();
+                self.pos = 0;
+                self.limit = 0;
+                self.stack.clear();
+                self.levels = vec![self.initial.clone()];
+                self.suffixes.clear();
+                for state in &self.initial {
+                    Self::update_best(&mut self.best, state);
+                }
+            }
+            SearchStrategy::Greedy => {
+                self.frontier.clear();
+                self.stack.clear();
+                self.queue.clear();
+                for state in self.initial.clone() {
+                    self.enqueue(state);
+                }
+            }
+        }
+    }
+
+    /// Add `state` to the queue of a greedy search.
+    fn enqueue(&mut self, state: State) {
+        let left = self.ctx.board.letters.len() - state.letters_covered();
+        self.queue.push(Queued {
+            priority: (state.path.len(), left, self.queued),
+            state,
+        });
+        self.queued += 1;
+    }
+
+    /// Whether `state` should be pruned because the same state was reached
+    /// with fewer words (or, if `dedup` is set and `state` isn't complete, as
+    /// many words), recording it otherwise.
+    fn is_dominated(
+        shallowest: &mut Option<HashMap<StateKey, usize>>,
+        dedup: bool,
+        ctx: &Context<'_, '_>,
+        state: &State,
+    ) -> bool {
+        let Some(shallowest) = shallowest else {
+            return false;
+        };
+        let key = (
+            state.cur,
+            state.visited.clone(),
+            state.remaining.clone(),
+            ctx.has_required(state),
+        );
+        let words = state.path.len();
+        let depth = match shallowest.entry(key) {
+            Entry::Vacant(entry) => {
+                entry.insert(words);
+                return false;
+            }
+            Entry::Occupied(entry) => entry.into_mut(),
+        };
+        if *depth < words || (dedup && *depth == words && !ctx.is_complete(state)) {
+            trace_event!(trace, words, shallowest = *depth, "pruned dominated state");
+            return true;
+        }
+        *depth = words;
+        false
+    }
+
+    /// Keep track of the best-available solution, since we might not find one
+    /// with the given max_depth.
+    fn update_best(best: &mut (usize, Path), state: &State) {
+        let covered = state.letters_covered();
+        if covered > best.0 || (covered == best.0 && state.path.len() < best.1.len()) {
+            *best = (covered, state.path.clone());
+        }
+    }
+
+    /// The board letters which neither a playable word nor any of the
+    /// `initial` states covers.
+    fn find_dead_letters(ctx: &Context<'_, '_>, initial: &[State]) -> BTreeSet<char> {
+        let mut dead = ctx.dead_letters();
+        for state in initial {
+            dead.retain(|l| state.visited.is_disjoint(&ctx.board.mask([*l])));
+        }
+        dead
+    }
+
+    /// Check the deadline, cancellation and dead letters, recording whether
+    /// the search should stop.
+    fn check_deadline(&mut self) -> bool {
+        if self.cancelled() || self.past_deadline() {
+            self.stopped = true;
+        } else if !self.dead_letters.is_empty()
+            && self.best.0 + self.dead_letters.len() >= self.ctx.board.letters.len()
+        {
+            trace_event!(
+                debug,
+                dead_letters = self.dead_letters.len(),
+                "stopped at a partial solution covering every live letter"
+            );
+            self.stopped = true;
+            self.dead_end = true;
+        }
+        self.stopped
+    }
+
+    fn past_deadline(&self) -> bool {
+        #[cfg(feature = "std")]
+        {
+            self.deadline.is_some_and(|d| Instant::now() >= d)
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            false
+        }
+    }
+
+    fn report_progress(&mut self, queue_len: usize, depth: usize) {
+        if let Some(progress) = &mut self.progress {
+            progress(&Progress {
+                states_expanded: self.expanded,
+                queue_len,
+                depth,
+                best_letters_covered: self.best.0,
+            });
+        }
+    }
+
+    fn next_breadth_first(&mut self) -> Option<Solution<'word>> {
+        loop {
+            while let Some(state) = self.frontier.get(self.pos) {
+                self.pos += 1;
+                Self::update_best(&mut self.best, state);
+
+                // Check if we're done!
+                if self.ctx.is_solution(state) {
+                    return Some(
+                        self.ctx
+                            .solution(&state.path.to_vec(), state.letters_covered()),
+                    );
+                }
+            }
+
+            if self.frontier.is_empty() || self.stopped {
+                return None;
+            }
+
+            // Expand the level a chunk at a time, so that we can stop partway
+            // through if we run out of time.
+            while self.expand_pos < self.frontier.len() {
+                if self.check_deadline() || self.is_paused() {
+                    return None;
+                }
+                let mut end = (self.expand_pos + CHUNK_SIZE).min(self.frontier.len());
+                if let Some(pause_at) = self.pause_at {
+                    end = end.min(self.expand_pos + (pause_at - self.expanded));
+                }
+                let next = self.ctx.expand_level(&self.frontier[self.expand_pos..end]);
+                let (shallowest, ctx) = (&mut self.shallowest, &self.ctx);
+                let before = self.next_frontier.len() + next.len();
+                self.next_frontier.extend(
+                    next.into_iter()
+                        .filter(|s| !Self::is_dominated(shallowest, self.dedup_states, ctx, s)),
+                );
+                self.pruned += before - self.next_frontier.len();
+                self.expanded += end - self.expand_pos;
+                self.expand_pos = end;
+
+                let queue_len = self.frontier.len() - self.expand_pos + self.next_frontier.len();
+                self.peak_queue_len = self.peak_queue_len.max(queue_len);
+                let depth = self.frontier[end - 1].path.len();
+                self.report_progress(queue_len, depth);
+            }
+
+            self.frontier = core::mem::take(&mut self.next_frontier);
+            trace_event!(
+                debug,
+                states = self.frontier.len(),
+                expanded = self.expanded,
+                pruned = self.pruned,
+                "expanded level"
+            );
+            if let Some(width) = self.beam_width {
+                if self.frontier.len() > width {
+                    trace_event!(
+                        debug,
+                        states = self.frontier.len(),
+                        width,
+                        "pruned level to beam width"
+                    );
+                    // Sorting is stable, so ties stay in breadth-first order.
+                    self.frontier.sort_by_key(|s| Reverse(s.letters_covered()));
+                    self.pruned += self.frontier.len() - width;
+                    self.frontier.truncate(width);
+                }
+            }
+            self.pos = 0;
+            self.expand_pos = 0;
+        }
+    }
+
+    fn next_iterative_deepening(&mut self) -> Option<Solution<'word>> {
+        if self.stopped {
+            return None;
+        }
+        loop {
+            while let Some(state) = self.stack.pop() {
+                let expands = !self.ctx.is_complete(&state) && state.path.len() < self.limit;
+                // Check this before recording the state as seen, or it would
+                // be pruned as a repeat of itself when resumed.
+                if expands && self.is_paused() {
+                    // Put the state back so we pick up here when resumed.
+                    Self::update_best(&mut self.best, &state);
+                    self.stack.push(state);
+                    return None;
+                }
+                if Self::is_dominated(&mut self.shallowest, self.dedup_states, &self.ctx, &state) {
+                    self.pruned += 1;
+                    continue;
+                }
+                Self::update_best(&mut self.best, &state);
+
+                if self.ctx.is_complete(&state) {
+                    // Shorter solutions were already yielded by earlier passes.
+                    if state.path.len() == self.limit && self.ctx.has_required(&state) {
+                        return Some(
+                            self.ctx
+                                .solution(&state.path.to_vec(), state.letters_covered()),
+                        );
+                    }
+                } else if expands {
+                    if self.expanded.is_multiple_of(CHUNK_SIZE) {
+                        if self.check_deadline() {
+                            return None;
+                        }
+                        self.report_progress(self.stack.len(), state.path.len());
+                    }
+                    self.expanded += 1;
+                    // Push in reverse so that states are visited in the same
+                    // order as a breadth-first search would yield them.
+                    self.stack.extend(self.ctx.expand(&state).into_iter().rev());
+                    self.peak_queue_len = self.peak_queue_len.max(self.stack.len());
+                } else {
+                    self.cut_off = true;
+                }
+            }
+
+            if !self.cut_off || self.limit >= self.ctx.max_depth || self.stopped {
+                return None;
+            }
+            self.limit += 1;
+            self.cut_off = false;
+            if self.dedup_states {
+                // The new pass revisits every state of the last one with as
+                // many words, so those visits mustn't count as repeats.
+                if let Some(shallowest) = &mut self.shallowest {
+                    shallowest.clear();
+                }
+            }
+            trace_event!(
+                debug,
+                limit = self.limit,
+                expanded = self.expanded,
+                "deepening search"
+            );
+            self.stack = self.initial.iter().rev().cloned().collect();
+        }
+    }
+}
+
+impl<'word> SolutionIter<'_, 'word> {
+    fn next_greedy(&mut self) -> Option<Solution<'word>> {
+        if self.stopped {
+            return None;
+        }
+        while let Some(queued) = self.queue.pop() {
+            let state = &queued.state;
+            let is_solution = self.ctx.is_solution(state);
+            // Check this before recording the state as seen, or it would be
+            // pruned as a repeat of itself when resumed.
+            if !is_solution && self.is_paused() {
+                // Put the state back so we pick up here when resumed.
+                Self::update_best(&mut self.best, state);
+                self.queue.push(queued);
+                return None;
+            }
+            if Self::is_dominated(&mut self.shallowest, self.dedup_states, &self.ctx, state) {
+                self.pruned += 1;
+                continue;
+            }
+            Self::update_best(&mut self.best, state);
+
+            if is_solution {
+                return Some(
+                    self.ctx
+                        .solution(&state.path.to_vec(), state.letters_covered()),
+                );
+            }
+            if self.expanded.is_multiple_of(CHUNK_SIZE) {
+                if self.check_deadline() {
+                    return None;
+                }
+                self.report_progress(self.queue.len(), state.path.len());
+            }
+            self.expanded += 1;
+            for next in self.ctx.expand(state) {
+                self.enqueue(next);
+            }
+            self.peak_queue_len = self.peak_queue_len.max(self.queue.len());
+        }
+        None
+    }
+
+    fn next_meet_in_the_middle(&mut self) -> Option<Solution<'word>> {
+        loop {
+            // `frontier` holds the solutions found for the last level.
+            if let Some(state) = self.frontier.get(self.pos) {
+                self.pos += 1;
+                return Some(
+                    self.ctx
+                        .solution(&state.path.to_vec(), state.letters_covered()),
+                );
+            }
+
+            if self.stopped || self.is_paused() {
+                return None;
+            }
+            let start = self.initial.first()?.path.len();
+            if start + self.limit > self.ctx.max_depth {
+                return None;
+            }
+            self.frontier = self.meet_in_the_middle_level(self.limit)?;
+            trace_event!(
+                debug,
+                words = self.limit,
+                solutions = self.frontier.len(),
+                "met in the middle"
+            );
+            self.pos = 0;
+            self.limit += 1;
+        }
+    }
+
+    /// Find every solution with `words` more words than the start, or `None`
+    /// if the deadline passes first.
+    fn meet_in_the_middle_level(&mut self, words: usize) -> Option<Vec<State>> {
+        let forward = words.div_ceil(2);
+        let backward = words - forward;
+
+        while self.levels.len() <= forward {
+            let mut next = vec![];
+            for start in (0..self.levels[self.levels.len() - 1].len()).step_by(CHUNK_SIZE) {
+                if self.check_deadline() {
+                    return None;
+                }
+                let last = &self.levels[self.levels.len() - 1];
+                let chunk = &last[start..(start + CHUNK_SIZE).min(last.len())];
+                let expanded = self.ctx.expand_level(chunk);
+                let (shallowest, ctx) = (&mut self.shallowest, &self.ctx);
+                let before = next.len() + expanded.len();
+                next.extend(
+                    expanded
+                        .into_iter()
+                        .filter(|s| !Self::is_dominated(shallowest, self.dedup_states, ctx, s)),
+                );
+                self.pruned += before - next.len();
+                self.expanded += chunk.len();
+            }
+            for state in &next {
+                Self::update_best(&mut self.best, state);
+            }
+            self.levels.push(next);
+        }
+
+        if self.levels[forward].is_empty() {
+            return None;
+        }
+        if backward == 0 {
+            let ctx = &self.ctx;
+            return Some(
+                self.levels[forward]
+                    .iter()
+                    .filter(|s| ctx.is_solution(s))
+                    .cloned()
+                    .collect(),
+            );
+        }
+
+        while self.suffixes.len() < backward {
+            if self.check_deadline() {
+                return None;
+            }
+            let next = match self.suffixes.last() {
+                Some(shorter) => {
+                    self.expanded += shorter.values().map(Vec::len).sum::<usize>();
+                    self.ctx.extend_suffixes(shorter)
+                }
+                None => self.ctx.single_word_suffixes(),
+            };
+            self.suffixes.push(next);
+        }
+
+        let num_states = self.levels[forward].len();
+        let num_suffixes = self.suffixes[backward - 1]
+            .values()
+            .map(Vec::len)
+            .sum::<usize>();
+        self.peak_queue_len = self.peak_queue_len.max(num_states + num_suffixes);
+        self.report_progress(
+            num_states + num_suffixes,
+            self.initial[0].path.len() + words,
+        );
+
+        let full = Mask::full(self.ctx.board.letters.len());
+        let mut solutions = vec![];
+        for start in (0..num_states).step_by(CHUNK_SIZE) {
+            if self.check_deadline() {
+                return None;
+            }
+            let suffixes = &self.suffixes[backward - 1];
+            let end = (start + CHUNK_SIZE).min(num_states);
+            for state in &self.levels[forward][start..end] {
+                let Some(tails) = suffixes.get(&state.cur) else {
+                    continue;
+                };
+                for tail in tails {
+                    // Cheaply skip the suffixes which can't finish the board.
+                    if state.visited.union(&tail.letters) == full {
+                        solutions.extend(self.ctx.join(state, tail));
+                    }
+                }
+            }
+        }
+        Some(solutions)
+    }
+}
+
+impl<'word> Iterator for SolutionIter<'_, 'word> {
+    type Item = Solution<'word>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        #[cfg(all(
+            feature = "std",
+            not(all(target_arch = "wasm32", target_os = "unknown"))
+        ))]
+        {
+            let start = Instant::now();
+            let solution = self.next_solution();
+            *self.elapsed.get_or_insert_default() += start.elapsed();
+            solution
+        }
+        #[cfg(not(all(
+            feature = "std",
+            not(all(target_arch = "wasm32", target_os = "unknown"))
+        )))]
+        {
+            self.next_solution()
+        }
+    }
+}
+
+impl<'word> SolutionIter<'_, 'word> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "trace", skip_all, fields(strategy = ?self.strategy))
+    )]
+    fn next_solution(&mut self) -> Option<Solution<'word>> {
+        loop {
+            let solution = match self.strategy {
+                SearchStrategy::BreadthFirst => self.next_breadth_first(),
+                SearchStrategy::IterativeDeepening => self.next_iterative_deepening(),
+                SearchStrategy::MeetInTheMiddle => self.next_meet_in_the_middle(),
+                SearchStrategy::Greedy => self.next_greedy(),
+            }?;
+
+            if let Some(seen) = &mut self.seen {
+                // Solutions come in order of word count, so any solution which
+                // dominates this one has already been yielded.
+                let words = solution.words.iter().copied().collect::<BTreeSet<_>>();
+                if seen.iter().any(|s| s.is_subset(&words)) {
+                    continue;
+                }
+                seen.push(words);
+            }
+
+            if let Some((max, counts)) = &mut self.per_start_word {
+                // Every initial state has the prior words as its path.
+                let num_prior = self.initial.first().map_or(0, |s| s.path.len());
+                if let Some(start) = solution.indices.get(num_prior) {
+                    let count = counts.entry(*start).or_default();
+                    if *count >= *max {
+                        continue;
+                    }
+                    *count += 1;
+                }
+            }
+
+            return Some(solution);
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+use crate::prelude::*;
+use crate::{BoardError, BoardMetadata, LetterBoxed};
+
+/// A board with its metadata.
+#[derive(serde::Serialize)]
+struct WithMetadata<'a> {
+    sides: Vec<&'a str>,
+    #[serde(flatten)]
+    metadata: &'a BoardMetadata,
+}
+
+/// Boards are serialized as their list of sides, e.g.
+/// `["ELZ", "IVA", "RYU", "CTH"]`, or if they have any metadata, as an object
+/// such as `{"sides": ["ELZ", "IVA", "RYU", "CTH"], "par": 2}`. The minimum
+/// word length is not included.
+impl Serialize for LetterBoxed {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.metadata().is_empty() {
+            return serializer.collect_seq(self.sides());
+        }
+        WithMetadata {
+            sides: self.sides().collect(),
+            metadata: self.metadata(),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// The forms the sides of a board can be deserialized from.
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum Sides {
+    List(Vec<String>),
+    Compact(String),
+}
+
+impl Sides {
+    fn into_board(self) -> Result<LetterBoxed, BoardError> {
+        match self {
+            Sides::List(sides) => {
+                let sides = sides.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+                LetterBoxed::try_load_board(&sides)
+            }
+            Sides::Compact(s) => s.parse(),
+        }
+    }
+}
+
+/// The forms a board can be deserialized from.
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum Repr {
+    Sides(Sides),
+    WithMetadata {
+        sides: Sides,
+        #[serde(flatten)]
+        metadata: BoardMetadata,
+    },
+}
+
+/// Boards are deserialized from a list of sides, from a compact string such
+/// as `"ELZ-IVA-RYU-CTH"`, or from an object with either of those as its
+/// `sides` along with any of the fields of [`BoardMetadata`]. They are checked
+/// as in [`LetterBoxed::try_load_board`].
+impl<'de> Deserialize<'de> for LetterBoxed {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match Repr::deserialize(deserializer)? {
+            Repr::Sides(sides) => sides.into_board(),
+            Repr::WithMetadata { sides, metadata } => {
+                sides.into_board().map(|b| b.with_metadata(metadata))
+            }
+        }
+        .map_err(de::Error::custom)
+    }
+}
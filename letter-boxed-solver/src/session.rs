@@ -0,0 +1,137 @@
+use alloc::collections::BTreeSet;
+
+use crate::prelude::*;
+use crate::search::{Context, SolutionIter, Statistics};
+use crate::{LetterBoxed, Solution};
+
+/// A search which can be run a little at a time, e.g. to keep a UI
+/// responsive, or to show more results on request.
+///
+/// The session keeps its queue of pending states and its best partial
+/// solution between runs, so no work is repeated.
+pub struct SolverSession<'a, 'word> {
+    iter: SolutionIter<'a, 'word>,
+    solutions: Vec<Solution<'word>>,
+    finished: bool,
+}
+
+impl<'a, 'word> SolverSession<'a, 'word> {
+    /// Wrap a search which hasn't been advanced yet.
+    pub fn new(iter: SolutionIter<'a, 'word>) -> Self {
+        SolverSession {
+            iter,
+            solutions: vec![],
+            finished: false,
+        }
+    }
+
+    /// Continue the search until `max_expansions` more states have been
+    /// expanded, or the search finishes. Returns the solutions found during
+    /// this run.
+    pub fn run(&mut self, max_expansions: usize) -> &[Solution<'word>] {
+        let start = self.solutions.len();
+        if !self.finished {
+            self.iter.pause_after(max_expansions);
+            loop {
+                match self.iter.next() {
+                    Some(solution) => self.solutions.push(solution),
+                    None => {
+                        self.finished = !self.iter.is_paused();
+                        break;
+                    }
+                }
+            }
+        }
+        &self.solutions[start..]
+    }
+
+    /// Continue the search until `n` more solutions are found, or the search
+    /// finishes. Returns the solutions found during this run.
+    pub fn run_for_results(&mut self, n: usize) -> &[Solution<'word>] {
+        let start = self.solutions.len();
+        if !self.finished {
+            self.iter.pause_after(usize::MAX);
+            self.solutions.extend(self.iter.by_ref().take(n));
+            self.finished = self.solutions.len() - start < n;
+        }
+        &self.solutions[start..]
+    }
+
+    /// Fetch up to `limit` solutions starting from the `offset`th, e.g. to
+    /// show the next page of results, continuing the search only as far as
+    /// needed. Earlier pages are kept, so fetching them again is free.
+    ///
+    /// Returns fewer than `limit` solutions (possibly none) once the search
+    /// has finished.
+    pub fn page(&mut self, offset: usize, limit: usize) -> &[Solution<'word>] {
+        let end = offset.saturating_add(limit);
+        if self.solutions.len() < end {
+            self.run_for_results(end - self.solutions.len());
+        }
+        let end = end.min(self.solutions.len());
+        &self.solutions[offset.min(end)..end]
+    }
+
+    /// Whether the search has finished, i.e. no more solutions will be found.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Every solution found so far, in the order they were found.
+    pub fn solutions(&self) -> &[Solution<'word>] {
+        &self.solutions
+    }
+
+    /// The number of search states expanded so far.
+    pub fn states_expanded(&self) -> usize {
+        self.iter.states_expanded()
+    }
+
+    /// Statistics about the search so far. See [`SolutionIter::statistics`].
+    pub fn statistics(&self) -> Statistics {
+        self.iter.statistics()
+    }
+
+    /// The best partial solution seen so far. See
+    /// [`SolutionIter::best_partial`].
+    pub fn best_partial(&self) -> Solution<'word> {
+        self.iter.best_partial()
+    }
+
+    /// The letters which no playable word covers. See
+    /// [`SolutionIter::dead_letters`].
+    pub fn dead_letters(&self) -> &BTreeSet<char> {
+        self.iter.dead_letters()
+    }
+}
+
+impl LetterBoxed {
+    /// Start a resumable search using the builtin word list. See
+    /// [`LetterBoxed::session`].
+    ///
+    /// This will crash if an element in `prior_words` is not in the builtin
+    /// word list.
+    #[cfg(feature = "std")]
+    pub fn session_with_builtin_list(
+        &self,
+        prior_words: &[&str],
+        max_depth: usize,
+    ) -> SolverSession<'_, 'static> {
+        let words = crate::builtin_words();
+        let prior_words_indices = crate::builtin_indices(prior_words);
+        self.session(words, &prior_words_indices, max_depth)
+    }
+
+    /// Start a resumable search using a provided word list, where all
+    /// solutions will not exceed `max_depth` in length. Nothing is searched
+    /// until the session is run.
+    pub fn session<'a, 'word>(
+        &'a self,
+        words: &'a [&'word str],
+        prior_words_indices: &[usize],
+        max_depth: usize,
+    ) -> SolverSession<'a, 'word> {
+        let ctx = Context::new(self, words, max_depth);
+        SolverSession::new(SolutionIter::new(ctx, prior_words_indices))
+    }
+}
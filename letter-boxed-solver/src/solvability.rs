@@ -0,0 +1,158 @@
+use alloc::collections::BTreeSet;
+use core::fmt;
+
+use crate::prelude::*;
+use crate::search::Context;
+use crate::{LetterBoxed, WordGraph};
+
+/// An explanation of whether a board can be solved with a dictionary, as
+/// reported by [`LetterBoxed::solvability_report`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolvabilityReport {
+    /// Letters which don't appear in any playable word.
+    pub dead_letters: BTreeSet<char>,
+    /// Letters which appear in playable words, but which can't all be reached
+    /// by chaining words together from any single starting letter.
+    pub unreachable_letters: BTreeSet<char>,
+    /// The starting letter from which the most letters can be reached, if
+    /// any word is playable at all.
+    pub best_start: Option<char>,
+}
+
+impl SolvabilityReport {
+    /// Whether the board might be solvable, given enough words.
+    ///
+    /// This is a necessary condition rather than a guarantee: every letter
+    /// must be in some playable word, and every letter must be reachable by
+    /// chaining words from a single starting letter.
+    pub fn is_solvable(&self) -> bool {
+        self.best_start.is_some()
+            && self.dead_letters.is_empty()
+            && self.unreachable_letters.is_empty()
+    }
+}
+
+impl fmt::Display for SolvabilityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.best_start.is_none() {
+            return write!(f, "no words can be played on this board");
+        }
+        if self.is_solvable() {
+            return write!(f, "the board may be solvable");
+        }
+        if !self.dead_letters.is_empty() {
+            write!(f, "no playable word uses ")?;
+            for l in &self.dead_letters {
+                write!(f, "{l}")?;
+            }
+            if !self.unreachable_letters.is_empty() {
+                write!(f, "; ")?;
+            }
+        }
+        if !self.unreachable_letters.is_empty() {
+            write!(f, "no chain of words can reach all of ")?;
+            for l in &self.unreachable_letters {
+                write!(f, "{l}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl WordGraph<'_, '_> {
+    /// The board letters which don't appear in any playable word, as in
+    /// [`LetterBoxed::dead_letters`].
+    pub fn dead_letters(&self) -> BTreeSet<char> {
+        let playable_letters = self
+            .word_letters
+            .values()
+            .flatten()
+            .collect::<BTreeSet<_>>();
+        self.board
+            .letters
+            .iter()
+            .filter(|l| !playable_letters.contains(l))
+            .copied()
+            .collect()
+    }
+}
+
+impl LetterBoxed {
+    /// Find the letters which appear in no playable word of the builtin word
+    /// list. See [`LetterBoxed::dead_letters`].
+    #[cfg(feature = "std")]
+    pub fn dead_letters_with_builtin_list(&self) -> BTreeSet<char> {
+        self.dead_letters(crate::builtin_words())
+    }
+
+    /// Find the board letters which appear in no playable word of `words`,
+    /// either because no word uses them at all or because every word which
+    /// does breaks the board's rules. If there are any, the board can't be
+    /// solved with `words`, and [`LetterBoxed::solve`] returns the best
+    /// partial solution as soon as it covers every other letter.
+    pub fn dead_letters(&self, words: &[&str]) -> BTreeSet<char> {
+        self.word_graph(words).dead_letters()
+    }
+
+    /// Check whether the board might be solvable with the builtin word list.
+    /// See [`LetterBoxed::solvability_report`].
+    #[cfg(feature = "std")]
+    pub fn solvability_report_with_builtin_list(&self) -> SolvabilityReport {
+        self.solvability_report(crate::builtin_words())
+    }
+
+    /// Check whether the board might be solvable with `words`. See
+    /// [`SolvabilityReport::is_solvable`].
+    pub fn is_solvable(&self, words: &[&str]) -> bool {
+        self.solvability_report(words).is_solvable()
+    }
+
+    /// Explain whether the board might be solvable with `words`, without
+    /// running a search: letters which appear in no playable word, and
+    /// letters which can't be reached from a common starting letter because
+    /// the word graph is disconnected.
+    pub fn solvability_report(&self, words: &[&str]) -> SolvabilityReport {
+        let ctx = Context::new(self, words, usize::MAX);
+        let dead_letters = ctx.dead_letters();
+        let playable_letters = ctx
+            .word_letters
+            .values()
+            .flatten()
+            .copied()
+            .collect::<BTreeSet<_>>();
+
+        // For each starting letter, find all the letters covered by words
+        // which can be reached from it.
+        let mut best: Option<(char, BTreeSet<char>)> = None;
+        for start in ctx.graph.keys() {
+            let mut seen = BTreeSet::new();
+            let mut covered = BTreeSet::new();
+            let mut stack = vec![*start];
+            while let Some(cur) = stack.pop() {
+                if !seen.insert(cur) {
+                    continue;
+                }
+                for (next, word_indices) in ctx.graph.get(&cur).into_iter().flatten() {
+                    for idx in word_indices {
+                        covered.extend(&ctx.word_letters[idx]);
+                    }
+                    stack.push(*next);
+                }
+            }
+            if best.as_ref().is_none_or(|(_, b)| covered.len() > b.len()) {
+                best = Some((*start, covered));
+            }
+        }
+
+        let unreachable_letters = match &best {
+            Some((_, covered)) => playable_letters.difference(covered).copied().collect(),
+            None => BTreeSet::new(),
+        };
+
+        SolvabilityReport {
+            dead_letters,
+            unreachable_letters,
+            best_start: best.map(|(start, _)| start),
+        }
+    }
+}
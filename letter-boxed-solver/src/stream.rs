@@ -0,0 +1,120 @@
+//! Solving on a background thread as an asynchronous stream, for servers
+//! which mustn't block their executor.
+
+use alloc::sync::Arc;
+use core::pin::Pin;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::{Context, Poll, Waker};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::sync::Mutex;
+use std::thread;
+
+use futures_core::Stream;
+
+use crate::{LetterBoxed, Solution};
+
+/// How many solutions the search may get ahead of the consumer before it
+/// waits for them to be taken.
+const BUFFER: usize = 16;
+
+/// A [`Stream`] of solutions found on a background thread, as returned by
+/// [`LetterBoxed::solve_async`]. It works with any executor, e.g. tokio.
+///
+/// Dropping the stream cancels the search.
+#[derive(Debug)]
+pub struct SolutionStream {
+    receiver: Receiver<Solution<'static>>,
+    /// The task to wake when the search finds a solution or finishes.
+    waker: Arc<Mutex<Option<Waker>>>,
+    cancel: Arc<AtomicBool>,
+}
+
+impl Stream for SolutionStream {
+    type Item = Solution<'static>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // Register before checking, so a solution sent in between still
+        // wakes this task.
+        *self.waker.lock().unwrap() = Some(cx.waker().clone());
+        match self.receiver.try_recv() {
+            Ok(solution) => Poll::Ready(Some(solution)),
+            Err(TryRecvError::Empty) => Poll::Pending,
+            Err(TryRecvError::Disconnected) => Poll::Ready(None),
+        }
+    }
+}
+
+impl Drop for SolutionStream {
+    fn drop(&mut self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+}
+
+impl LetterBoxed {
+    /// Asynchronously solve using the builtin word list. See
+    /// [`LetterBoxed::solve_async`].
+    ///
+    /// This will crash if an element in `prior_words` is not in the builtin
+    /// word list.
+    pub fn solve_async_with_builtin_list(
+        &self,
+        prior_words: &[&str],
+        max_depth: usize,
+    ) -> SolutionStream {
+        let prior_words_indices = crate::builtin_indices(prior_words);
+        self.solve_async(crate::builtin_words(), &prior_words_indices, max_depth)
+    }
+
+    /// Solve using a provided word list on a background thread, yielding
+    /// solutions as a [`Stream`] in the order [`LetterBoxed::solve_iter`]
+    /// would, e.g. to serve them from an async web handler without blocking
+    /// the executor.
+    ///
+    /// The search stays a few solutions ahead of the consumer. It stops
+    /// once every solution within `max_depth` words has been found, or as
+    /// soon as the stream is dropped, so use `take` to limit the results.
+    ///
+    /// `words` must live for the rest of the program, as with
+    /// [`WordList::words`](crate::WordList::words), since the search may
+    /// outlive the caller.
+    pub fn solve_async(
+        &self,
+        words: &'static [&'static str],
+        prior_words_indices: &[usize],
+        max_depth: usize,
+    ) -> SolutionStream {
+        let (sender, receiver) = mpsc::sync_channel(BUFFER);
+        let waker = Arc::new(Mutex::new(None::<Waker>));
+        let cancel = Arc::new(AtomicBool::new(false));
+
+        let board = self.clone();
+        let prior_words_indices = prior_words_indices.to_vec();
+        let thread_waker = waker.clone();
+        let thread_cancel = cancel.clone();
+        thread::spawn(move || {
+            let wake = || {
+                if let Some(waker) = thread_waker.lock().unwrap().take() {
+                    waker.wake();
+                }
+            };
+            let iter = board
+                .solve_iter(words, &prior_words_indices, max_depth)
+                .with_cancellation(thread_cancel);
+            for solution in iter {
+                // This fails once the stream has been dropped.
+                if sender.send(solution).is_err() {
+                    return;
+                }
+                wake();
+            }
+            drop(sender);
+            wake();
+        });
+
+        SolutionStream {
+            receiver,
+            waker,
+            cancel,
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use crate::prelude::*;
+
+use crate::{LetterBoxed, Mask, WordGraph};
+
+impl<'word> WordGraph<'_, 'word> {
+    /// Find every two-word solution, in the order the search would find them.
+    ///
+    /// Rather than searching, this checks each playable word against the
+    /// words starting with its last letter using bitmasks of their letters,
+    /// which is much faster. Returns `None` if each letter may only be used
+    /// once (which the bitmasks can't express).
+    pub fn solve_two_words(&self) -> Option<Vec<(&'word str, &'word str)>> {
+        if self.board.rules().each_letter_once() {
+            return None;
+        }
+        let masks = &self.word_masks;
+        let full = Mask::full(self.board.letters.len());
+
+        let mut pairs = vec![];
+        // Visit first words in the same order as the search does, i.e. by
+        // first letter, then last letter, then position in the word list.
+        for options in self.graph.values() {
+            for (last, firsts) in options {
+                let Some(seconds) = self.graph.get(last) else {
+                    continue;
+                };
+                for first in firsts {
+                    // One-word solutions aren't extended.
+                    if masks[first] == full {
+                        continue;
+                    }
+                    for second in seconds.values().flatten() {
+                        if masks[first].union(&masks[second]) == full {
+                            pairs.push((self.words[*first], self.words[*second]));
+                        }
+                    }
+                }
+            }
+        }
+        Some(pairs)
+    }
+}
+
+impl LetterBoxed {
+    /// Find every two-word solution using the builtin word list. See
+    /// [`LetterBoxed::solve_two_words`].
+    #[cfg(feature = "std")]
+    pub fn solve_two_words_with_builtin_list(&self) -> Vec<(&'static str, &'static str)> {
+        self.solve_two_words(crate::builtin_words())
+    }
+
+    /// Find every two-word solution using a provided word list, in the order
+    /// [`LetterBoxed::solve_iter`] would find them.
+    ///
+    /// This is much faster than a general search, which also uses it when
+    /// possible.
+    pub fn solve_two_words<'word>(&self, words: &[&'word str]) -> Vec<(&'word str, &'word str)> {
+        let graph = WordGraph::new(self, words);
+        match graph.solve_two_words() {
+            Some(pairs) => pairs,
+            None => graph
+                .solve_iter(&[], 2)
+                .filter(|s| s.words.len() == 2)
+                .map(|s| (s.words[0], s.words[1]))
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,347 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+use core::fmt;
+
+use crate::prelude::*;
+use crate::rules::{earlier_copy, use_letters};
+use crate::{letters, LetterBoxed};
+
+/// A reason why a proposed solution is not valid on a board, as reported by
+/// [`LetterBoxed::validate_detailed`].
+///
+/// Word indices are zero-indexed positions in the proposed solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Violation {
+    /// The word at `word` doesn't start with the last letter of the word
+    /// before it.
+    BrokenChain {
+        /// The index of the second word of the pair.
+        word: usize,
+        /// The last letter of the previous word.
+        expected: char,
+        /// The first letter of this word, if it has one.
+        found: Option<char>,
+    },
+    /// Two consecutive letters of a word are on the same side of the board.
+    SameSide {
+        /// The index of the word.
+        word: usize,
+        /// The pair of consecutive letters.
+        letters: (char, char),
+        /// The side both letters are on.
+        side: usize,
+    },
+    /// A word is shorter than the board's minimum word length.
+    TooShort {
+        /// The index of the word.
+        word: usize,
+        /// The number of letters in the word.
+        length: usize,
+        /// The board's minimum word length.
+        minimum: usize,
+    },
+    /// A word uses a letter which isn't on the board at all.
+    NotOnBoard {
+        /// The index of the word.
+        word: usize,
+        /// The missing letter.
+        letter: char,
+    },
+    /// A word was already played earlier in the solution, which the
+    /// [rules](crate::BoardRules::with_repeated_words) forbid by default.
+    RepeatedWord {
+        /// The index of the repeated word.
+        word: usize,
+        /// The index of its first occurrence.
+        first: usize,
+    },
+    /// A word plays a letter which was already used up, when each letter may
+    /// only be used once. See [`crate::BoardRules::with_each_letter_once`].
+    LetterReused {
+        /// The index of the word.
+        word: usize,
+        /// The reused letter.
+        letter: char,
+    },
+    /// Some letters of the board are not used by any word.
+    Uncovered {
+        /// The unused letters.
+        letters: BTreeSet<char>,
+    },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::BrokenChain {
+                word,
+                expected,
+                found: Some(found),
+            } => write!(
+                f,
+                "word {} starts with {found}, but must start with {expected}",
+                word + 1
+            ),
+            Violation::BrokenChain {
+                word,
+                expected,
+                found: None,
+            } => write!(
+                f,
+                "word {} is empty, but must start with {expected}",
+                word + 1
+            ),
+            Violation::SameSide {
+                word,
+                letters: (a, b),
+                side,
+            } => write!(
+                f,
+                "word {} goes from {a} to {b}, which are both on side {}",
+                word + 1,
+                side + 1
+            ),
+            Violation::TooShort {
+                word,
+                length,
+                minimum,
+            } => write!(
+                f,
+                "word {} has {length} letters, but must have at least {minimum}",
+                word + 1
+            ),
+            Violation::NotOnBoard { word, letter } => {
+                write!(
+                    f,
+                    "word {} uses {letter}, which isn't on the board",
+                    word + 1
+                )
+            }
+            Violation::RepeatedWord { word, first } => write!(
+                f,
+                "word {} was already played as word {}",
+                word + 1,
+                first + 1
+            ),
+            Violation::LetterReused { word, letter } => write!(
+                f,
+                "word {} uses {letter} again, but each letter may only be used once",
+                word + 1
+            ),
+            Violation::Uncovered { letters } => {
+                write!(f, "letters not used: ")?;
+                for l in letters {
+                    write!(f, "{l}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The state of a game in progress, as reported by
+/// [`LetterBoxed::validate_prefix`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameState {
+    /// Every rule broken by the words played so far. Letters which haven't
+    /// been used yet aren't a violation of a game in progress, so this never
+    /// includes [`Violation::Uncovered`].
+    pub violations: Vec<Violation>,
+    /// The board letters used by the words played so far.
+    pub covered: BTreeSet<char>,
+    /// The board letters which still have to be played.
+    pub remaining: BTreeSet<char>,
+    /// The letter the next word must start with, i.e. the last letter of the
+    /// last word played, or `None` if no word has been played yet.
+    pub next_letter: Option<char>,
+}
+
+impl GameState {
+    /// Whether the words played so far break no rules.
+    pub fn is_valid(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Whether the words played so far are a valid and complete solution.
+    pub fn is_complete(&self) -> bool {
+        self.is_valid() && self.remaining.is_empty()
+    }
+}
+
+impl LetterBoxed {
+    /// Validate the words of a game in progress, which don't have to cover
+    /// every letter yet, e.g. to give feedback as each word is typed. See
+    /// [`LetterBoxed::validate_detailed`] to validate a finished solution.
+    ///
+    /// Words are case-insensitive.
+    pub fn validate_prefix(&self, words: &[&str]) -> GameState {
+        let mut remaining = BTreeSet::new();
+        let violations = self
+            .validate_detailed(words)
+            .into_iter()
+            .filter(|v| match v {
+                Violation::Uncovered { letters } => {
+                    remaining.clone_from(letters);
+                    false
+                }
+                _ => true,
+            })
+            .collect();
+        let covered = words
+            .iter()
+            .flat_map(|w| letters(w))
+            .filter(|c| self.letters.contains(c))
+            .collect();
+
+        GameState {
+            violations,
+            covered,
+            remaining,
+            next_letter: words.last().and_then(|w| letters(w).last()),
+        }
+    }
+
+    /// Validate a proposed solution on this board, returning every rule it
+    /// breaks. An empty result means the solution is valid and complete.
+    ///
+    /// Words are case-insensitive.
+    pub fn validate_detailed(&self, solution: &[&str]) -> Vec<Violation> {
+        let mut violations = vec![];
+
+        for (idx, window) in solution.windows(2).enumerate() {
+            if let Some(expected) = letters(window[0]).last() {
+                let found = letters(window[1]).next();
+                if found != Some(expected) {
+                    violations.push(Violation::BrokenChain {
+                        word: idx + 1,
+                        expected,
+                        found,
+                    });
+                }
+            }
+        }
+
+        let mut covered = BTreeSet::new();
+        for (idx, word) in solution.iter().enumerate() {
+            let word_letters = letters(word).collect::<Vec<_>>();
+
+            self.word_violations(idx, &word_letters, &mut violations);
+
+            if !self.rules().repeated_words() {
+                if let Some(first) = earlier_copy(solution, idx) {
+                    violations.push(Violation::RepeatedWord { word: idx, first });
+                }
+            }
+
+            covered.extend(word_letters);
+        }
+
+        let mut uncovered = self
+            .letters
+            .difference(&covered)
+            .copied()
+            .collect::<BTreeSet<_>>();
+        if self.rules().each_letter_once() {
+            let (reused, remaining) = self.reused_letters(solution);
+            violations.extend(
+                reused
+                    .into_iter()
+                    .map(|(word, letter)| Violation::LetterReused { word, letter }),
+            );
+            // A letter which is on the board more than once has to be played
+            // that many times.
+            uncovered.extend(
+                remaining
+                    .into_iter()
+                    .filter(|(_, n)| *n > 0)
+                    .map(|(c, _)| c),
+            );
+        }
+        if !uncovered.is_empty() {
+            violations.push(Violation::Uncovered { letters: uncovered });
+        }
+
+        violations
+    }
+
+    /// Check whether `word` can be traced on this board by itself, returning
+    /// the first rule it breaks if not: it may be too short, use a letter
+    /// which isn't on the board, or have two consecutive letters on the same
+    /// side. The word is case-insensitive, and is numbered 0 in the result.
+    pub fn check_word(&self, word: &str) -> Result<(), Violation> {
+        let word_letters = letters(word).collect::<Vec<_>>();
+        let mut violations = vec![];
+        self.word_violations(0, &word_letters, &mut violations);
+        match violations.into_iter().next() {
+            Some(v) => Err(v),
+            None => Ok(()),
+        }
+    }
+
+    /// Whether `word` can be traced on this board by itself. See
+    /// [`LetterBoxed::check_word`] for why not.
+    pub fn is_playable(&self, word: &str) -> bool {
+        self.check_word(word).is_ok()
+    }
+
+    /// Add every rule broken by the word at `idx` on its own to `violations`.
+    fn word_violations(&self, idx: usize, word_letters: &[char], violations: &mut Vec<Violation>) {
+        if word_letters.len() < self.min_word_length {
+            violations.push(Violation::TooShort {
+                word: idx,
+                length: word_letters.len(),
+                minimum: self.min_word_length,
+            });
+        }
+
+        let mut missing = BTreeSet::new();
+        for c in word_letters {
+            if !self.letters.contains(c) && missing.insert(*c) {
+                violations.push(Violation::NotOnBoard {
+                    word: idx,
+                    letter: *c,
+                });
+            }
+        }
+
+        for pair in word_letters.windows(2) {
+            if self.nonadjacent.contains(&(pair[0], pair[1])) {
+                if let Some(side) = self.side_of(pair[0]) {
+                    violations.push(Violation::SameSide {
+                        word: idx,
+                        letters: (pair[0], pair[1]),
+                        side,
+                    });
+                }
+            }
+        }
+    }
+
+    /// The letters which are played again after being used up, as the index
+    /// of the word and the letter, along with how many times each letter is
+    /// left to play.
+    pub(crate) fn reused_letters(
+        &self,
+        solution: &[&str],
+    ) -> (Vec<(usize, char)>, BTreeMap<char, usize>) {
+        let mut remaining = self.letter_counts();
+        let mut reused = vec![];
+        for (idx, word) in solution.iter().enumerate() {
+            // The first letter of each word after the first was already
+            // played as the last letter of the one before.
+            for c in letters(word).skip(idx.min(1)) {
+                if self.letters.contains(&c)
+                    && !use_letters(&mut remaining, [c])
+                    && !reused.contains(&(idx, c))
+                {
+                    reused.push((idx, c));
+                }
+            }
+        }
+        (reused, remaining)
+    }
+
+    /// The index of the side which `letter` is on, if any.
+    fn side_of(&self, letter: char) -> Option<usize> {
+        self.sides.iter().position(|s| s.contains(letter))
+    }
+}
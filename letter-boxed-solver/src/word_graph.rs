@@ -0,0 +1,361 @@
+use alloc::borrow::Cow;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::sync::Arc;
+use core::ops::Deref;
+
+use yoke::{Yoke, Yokeable};
+
+use crate::collections::HashMap;
+use crate::hint::hints;
+use crate::prelude::*;
+use crate::rules::is_proper_noun;
+use crate::search::{Context, SolutionIter};
+use crate::{
+    collect_results, find_word, letters, result_limit, Dictionary, Hint, LetterBoxed, Mask,
+};
+
+/// The graph maps from a start-letter to an end-letter, with each possible
+/// word that bridges them according to the board as a potential route.
+///
+/// In the example board
+///
+///   E L Z
+/// I       C
+/// V       T
+/// A       H
+///   R Y U
+///
+/// This would include an entry 'V' -> 'R' {..., "VEHICULAR", ...}
+pub(crate) type Graph = BTreeMap<char, BTreeMap<char, BTreeSet<usize>>>;
+
+/// A board and a word list compiled together, keeping only the words which
+/// can be played on the board.
+///
+/// Compiling the word list is a large part of the cost of a search, so this
+/// can be built once and reused for many searches on the same board, e.g. as
+/// a player enters words in an interactive UI.
+#[derive(Debug, Clone)]
+pub struct WordGraph<'a, 'word> {
+    pub(crate) board: Cow<'a, LetterBoxed>,
+    pub(crate) words: Words<'a, 'word>,
+    pub(crate) graph: Graph,
+    /// The distinct letters of each word in `graph`, keyed by word index.
+    pub(crate) word_letters: HashMap<usize, BTreeSet<char>>,
+    /// The letters of each word in `graph` as a [`Mask`], keyed by word index.
+    pub(crate) word_masks: HashMap<usize, Mask>,
+    /// The shortest word added to the graph, usually the board's.
+    min_word_length: usize,
+}
+
+/// The word list of a [`WordGraph`], which it usually borrows but may own
+/// the slice of, as in an [`OwnedWordGraph`] compiled from a dictionary.
+///
+/// Unlike a `Cow`, this is covariant in both lifetimes, which
+/// [`OwnedWordGraph`] relies on.
+#[derive(Debug, Clone)]
+pub(crate) enum Words<'a, 'word> {
+    Borrowed(&'a [&'word str]),
+    Owned(Vec<&'word str>),
+}
+
+impl<'word> Deref for Words<'_, 'word> {
+    type Target = [&'word str];
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Words::Borrowed(words) => words,
+            Words::Owned(words) => words,
+        }
+    }
+}
+
+impl<'a, 'word> WordGraph<'a, 'word> {
+    /// Compile `words` for `board`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(words = words.len()))
+    )]
+    pub fn new(board: &'a LetterBoxed, words: &'a [&'word str]) -> Self {
+        WordGraph::with_min_word_length(board, words, board.min_word_length)
+    }
+
+    /// Compile `words` for `board`, taking ownership of the board so that the
+    /// graph only borrows the word list, e.g. to keep a graph of the builtin
+    /// word list without borrowing anything. See also [`OwnedWordGraph`].
+    pub fn with_owned_board(board: LetterBoxed, words: &'a [&'word str]) -> Self {
+        let min_word_length = board.min_word_length;
+        WordGraph::compile(Cow::Owned(board), Words::Borrowed(words), min_word_length)
+    }
+
+    /// Compile `words` for `board`, but with a different shortest playable
+    /// word than the board's own.
+    pub(crate) fn with_min_word_length(
+        board: &'a LetterBoxed,
+        words: &'a [&'word str],
+        min_word_length: usize,
+    ) -> Self {
+        WordGraph::compile(
+            Cow::Borrowed(board),
+            Words::Borrowed(words),
+            min_word_length,
+        )
+    }
+
+    fn compile(
+        board: Cow<'a, LetterBoxed>,
+        words: Words<'a, 'word>,
+        min_word_length: usize,
+    ) -> Self {
+        let counts = board.letter_counts();
+        let mut graph = WordGraph {
+            board,
+            words,
+            graph: Graph::new(),
+            word_letters: HashMap::new(),
+            word_masks: HashMap::new(),
+            min_word_length,
+        };
+        for i in 0..graph.words.len() {
+            let w = graph.words[i];
+            graph.add_word(i, &letters(w).collect::<Vec<_>>(), &counts);
+        }
+        trace_event!(
+            debug,
+            playable = graph.word_letters.len(),
+            "compiled word graph"
+        );
+        graph
+    }
+
+    /// A graph for `board` without any words yet, to [add](Self::add_word)
+    /// them to.
+    pub(crate) fn empty(board: &'a LetterBoxed, words: &'a [&'word str]) -> Self {
+        WordGraph {
+            min_word_length: board.min_word_length,
+            board: Cow::Borrowed(board),
+            words: Words::Borrowed(words),
+            graph: Graph::new(),
+            word_letters: HashMap::new(),
+            word_masks: HashMap::new(),
+        }
+    }
+
+    /// Add the word at index `i` of the word list, whose normalized letters
+    /// are `w`, if it can be played on the board. `counts` are the board's
+    /// [letter counts](LetterBoxed::letter_counts).
+    pub(crate) fn add_word(&mut self, i: usize, w: &[char], counts: &BTreeMap<char, usize>) {
+        let board = &self.board;
+        if !board.rules().proper_nouns() && is_proper_noun(self.words[i]) {
+            return;
+        }
+        // Eliminate words that are too short, and those which contain
+        // letters not on the board at all
+        if w.len() < self.min_word_length || w.iter().any(|c| !board.letters.contains(c)) {
+            return;
+        }
+
+        // If each letter may only be used once, a word can't use a
+        // letter more times than it's on the board.
+        if board.rules().each_letter_once()
+            && counts
+                .iter()
+                .any(|(c, n)| w.iter().filter(|x| *x == c).count() > *n)
+        {
+            return;
+        }
+
+        let mut c_iter = w.iter().copied();
+        let first_char = c_iter.next().unwrap();
+        let mut current_char = first_char;
+
+        // Check that adjacent characters are not in the known-nonadjacent set.
+        for c in c_iter {
+            if board.nonadjacent.contains(&(current_char, c)) {
+                return;
+            }
+            current_char = c;
+        }
+
+        let options = self.graph.entry(first_char).or_default();
+        options.entry(current_char).or_default().insert(i);
+        self.word_letters.insert(i, w.iter().copied().collect());
+        self.word_masks.insert(i, board.mask(w.iter().copied()));
+    }
+
+    /// The board this graph was compiled for.
+    pub fn board(&self) -> &LetterBoxed {
+        &self.board
+    }
+
+    /// The word list this graph was compiled from, including the words which
+    /// can't be played on the board.
+    pub fn words(&self) -> &[&'word str] {
+        &self.words
+    }
+
+    /// Look up the indices of `words` in the word list this graph was
+    /// compiled from, ignoring case and Unicode normalization, e.g. to pass
+    /// prior words to [`WordGraph::solve`]. Returns `None` if any of them is
+    /// not in the list.
+    pub fn indices(&self, words: &[&str]) -> Option<Vec<usize>> {
+        words.iter().map(|w| find_word(&self.words, w)).collect()
+    }
+
+    /// The words which can be played on the board, in the order of the
+    /// original word list.
+    pub fn playable_words(&self) -> impl Iterator<Item = &'word str> + '_ {
+        let mut indices = self.word_letters.keys().copied().collect::<Vec<_>>();
+        indices.sort_unstable();
+        indices.into_iter().map(|i| self.words[i])
+    }
+
+    /// Solve as in [`LetterBoxed::solve`], reusing this graph.
+    ///
+    /// `prior_words_indices` are indices into the word list the graph was
+    /// compiled from.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self, prior_words_indices))
+    )]
+    pub fn solve(
+        &self,
+        prior_words_indices: &[usize],
+        max_depth: usize,
+        max_results: usize,
+    ) -> Vec<(Vec<&'word str>, usize)> {
+        // If there are enough solutions with at most two words (or those are
+        // all the solutions there could be), we can find them without
+        // searching.
+        if prior_words_indices.is_empty() && max_depth >= 2 {
+            if let Some(pairs) = self.solve_two_words() {
+                let num_letters = self.board.letters.len();
+                let limit = result_limit(max_results);
+                let short = self
+                    .pangrams_in_search_order()
+                    .into_iter()
+                    .map(|w| vec![w])
+                    .chain(pairs.into_iter().map(|(a, b)| vec![a, b]))
+                    .map(|words| (words, num_letters))
+                    .take(limit)
+                    .collect::<Vec<_>>();
+                if short.len() == limit || (max_depth == 2 && !short.is_empty()) {
+                    return short;
+                }
+            }
+        }
+
+        collect_results(
+            &mut self.solve_iter(prior_words_indices, max_depth),
+            max_results,
+        )
+    }
+
+    /// The one-word solutions, in the order the search would find them.
+    fn pangrams_in_search_order(&self) -> Vec<&'word str> {
+        self.graph
+            .values()
+            .flat_map(|options| options.values().flatten())
+            .filter(|idx| self.is_pangram(**idx))
+            .map(|idx| self.words[*idx])
+            .collect()
+    }
+
+    /// Whether the word at `idx` solves the board by itself.
+    pub(crate) fn is_pangram(&self, idx: usize) -> bool {
+        if self.word_letters[&idx].len() != self.board.letters.len() {
+            return false;
+        }
+        // Words never use a letter more often than it's on the board if each
+        // letter may only be used once, so it's enough to check the length.
+        !self.board.rules().each_letter_once()
+            || letters(self.words[idx]).count()
+                == self.board.letter_counts().values().sum::<usize>()
+    }
+
+    /// Lazily solve as in [`LetterBoxed::solve_iter`], reusing this graph.
+    pub fn solve_iter(
+        &self,
+        prior_words_indices: &[usize],
+        max_depth: usize,
+    ) -> SolutionIter<'_, 'word> {
+        let ctx = Context::with_graph(Cow::Borrowed(self), max_depth);
+        SolutionIter::new(ctx, prior_words_indices)
+    }
+
+    /// Lazily solve as in [`WordGraph::solve_iter`], but taking ownership of
+    /// the graph, so that the search only borrows what the graph does.
+    pub fn into_solve_iter(
+        self,
+        prior_words_indices: &[usize],
+        max_depth: usize,
+    ) -> SolutionIter<'a, 'word> {
+        let ctx = Context::with_graph(Cow::Owned(self), max_depth);
+        SolutionIter::new(ctx, prior_words_indices)
+    }
+
+    /// Suggest the next word to play as in [`LetterBoxed::hint`], reusing
+    /// this graph.
+    pub fn hint(&self, prior_words_indices: &[usize], max_depth: usize) -> Vec<Hint<'word>> {
+        let ctx = Context::with_graph(Cow::Borrowed(self), max_depth);
+        hints(&ctx, prior_words_indices)
+    }
+}
+
+/// A [`WordGraph`] which owns its board and word list, so that it can be
+/// kept without borrowing anything, e.g. between calls from JavaScript.
+#[derive(Debug, Clone)]
+pub struct OwnedWordGraph {
+    /// The graph, borrowing the words of a dictionary if it was compiled from
+    /// one, or only `'static` words otherwise.
+    graph: Yoke<Compiled<'static>, Option<Arc<[String]>>>,
+}
+
+/// A [`WordGraph`] with a single lifetime, so that it can borrow the words
+/// an [`OwnedWordGraph`] owns.
+#[derive(Debug, Clone, Yokeable)]
+struct Compiled<'a>(WordGraph<'a, 'a>);
+
+impl OwnedWordGraph {
+    /// Compile `words` for `board`, where the words live for the rest of the
+    /// program, as with [`WordList::words`](crate::WordList::words).
+    pub fn new(board: LetterBoxed, words: &'static [&'static str]) -> Self {
+        OwnedWordGraph {
+            graph: Yoke::new_owned(Compiled(WordGraph::with_owned_board(board, words))),
+        }
+    }
+
+    /// Compile the words of `dictionary` for `board`.
+    pub fn from_dictionary(board: LetterBoxed, dictionary: Dictionary) -> Self {
+        let words: Arc<[String]> = dictionary.into_words().into();
+        let graph = Yoke::attach_to_cart(words, |words| {
+            let words = words.iter().map(String::as_str).collect::<Vec<_>>();
+            let min_word_length = board.min_word_length;
+            Compiled(WordGraph::compile(
+                Cow::Owned(board),
+                Words::Owned(words),
+                min_word_length,
+            ))
+        });
+        OwnedWordGraph {
+            graph: graph.wrap_cart_in_option(),
+        }
+    }
+
+    /// The board this graph was compiled for.
+    pub fn board(&self) -> &LetterBoxed {
+        self.graph().board()
+    }
+
+    /// The compiled graph, e.g. to solve the board.
+    pub fn graph(&self) -> &WordGraph<'_, '_> {
+        &self.graph.get().0
+    }
+}
+
+impl LetterBoxed {
+    /// Compile `words` for this board, so that it can be reused for many
+    /// searches. See [`WordGraph`].
+    pub fn word_graph<'a, 'word>(&'a self, words: &'a [&'word str]) -> WordGraph<'a, 'word> {
+        WordGraph::new(self, words)
+    }
+}
@@ -0,0 +1,94 @@
+use std::sync::OnceLock;
+
+use fst::{Set, Streamer};
+
+use crate::find_word;
+
+/// The word lists embedded in the crate.
+///
+/// [`WordList::Standard`] is always available and is what the
+/// `*_with_builtin_list` methods use. Other lists are compiled in by enabling
+/// their cargo features, and can be passed to any method which takes a word
+/// list via [`WordList::words`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WordList {
+    /// The standard list of ~84,000 words. This is stored compressed as an
+    /// FST, and only decompressed the first time it is used.
+    #[default]
+    Standard,
+    /// The ~15,000 most common words of the standard list, ordered from most
+    /// to least common. Enabled by the `common-words` feature.
+    #[cfg(feature = "common-words")]
+    Common,
+}
+
+impl WordList {
+    /// All of the lists which were compiled in.
+    pub const ALL: &'static [WordList] = &[
+        WordList::Standard,
+        #[cfg(feature = "common-words")]
+        WordList::Common,
+    ];
+
+    /// A short name for the list, e.g. for a command-line flag.
+    pub fn name(self) -> &'static str {
+        match self {
+            WordList::Standard => "standard",
+            #[cfg(feature = "common-words")]
+            WordList::Common => "common",
+        }
+    }
+
+    /// Look up a list by its [`WordList::name`].
+    pub fn from_name(name: &str) -> Option<WordList> {
+        WordList::ALL.iter().copied().find(|l| l.name() == name)
+    }
+
+    /// The words in the list.
+    pub fn words(self) -> &'static [&'static str] {
+        match self {
+            WordList::Standard => {
+                static TEXT: OnceLock<String> = OnceLock::new();
+                static LIST: OnceLock<Vec<&'static str>> = OnceLock::new();
+                LIST.get_or_init(|| split(TEXT.get_or_init(|| decompress(STANDARD))))
+            }
+            #[cfg(feature = "common-words")]
+            WordList::Common => {
+                static LIST: OnceLock<Vec<&'static str>> = OnceLock::new();
+                LIST.get_or_init(|| split(COMMON))
+            }
+        }
+    }
+
+    /// Look up the indices of `words` in the list, ignoring case, e.g. to
+    /// pass prior words to [`crate::LetterBoxed::solve`]. Returns `None` if
+    /// any of them is not in the list.
+    pub fn indices(self, words: &[&str]) -> Option<Vec<usize>> {
+        let list = self.words();
+        words.iter().map(|w| find_word(list, w)).collect()
+    }
+}
+
+fn split(text: &'static str) -> Vec<&'static str> {
+    text.lines().map(|w| w.trim()).collect()
+}
+
+/// Stream the words out of an FST into newline-separated text, in sorted
+/// order.
+fn decompress(bytes: &'static [u8]) -> String {
+    let set = Set::new(bytes).expect("builtin word FST is valid");
+    let mut text = String::with_capacity(set.len() * 9);
+    let mut stream = set.stream();
+    while let Some(word) = stream.next() {
+        text.push_str(std::str::from_utf8(word).expect("builtin words are UTF-8"));
+        text.push('\n');
+    }
+    text
+}
+
+/// The standard word list, compressed by the build script.
+const STANDARD: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/words.fst"));
+
+#[cfg(feature = "common-words")]
+const COMMON: &str = include_str!("frequencies.txt");